@@ -0,0 +1,150 @@
+use num_traits::{Bounded, Num};
+
+use crate::bounds::saturating_sub;
+
+/// How something is placed along a single axis of a container.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Placed at the top or left edge.
+    #[default]
+    Start,
+    /// Placed in the middle.
+    Center,
+    /// Placed at the bottom or right edge.
+    End,
+    /// Resized to fill the whole axis.
+    Stretch,
+}
+
+impl Align {
+    /// Places something of `length` inside the range starting at `start` with `available` length,
+    /// returning the new start and length.
+    ///
+    /// When centering with integers, any leftover unit is put after, so the result is rounded
+    /// towards the start. This is the case even when `length` is larger than `available`.
+    ///
+    /// A `length` larger than `available` goes past the start when placed at the end or center.
+    /// For unsigned types, positions below zero are saturated to zero instead of underflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(Align::Start.place(10, 0, 100), (0, 10));
+    /// assert_eq!(Align::End.place(10, 0, 100), (90, 10));
+    /// assert_eq!(Align::Center.place(5, 0, 10), (2, 5));
+    /// assert_eq!(Align::Center.place(5, 0, 2), (-2, 5));
+    /// assert_eq!(Align::Stretch.place(10, 0, 100), (0, 100));
+    ///
+    /// assert_eq!(Align::End.place(10u32, 0, 5), (0, 10));
+    /// assert_eq!(Align::Center.place(10u32, 0, 5), (0, 10));
+    /// ```
+    pub fn place<T>(self, length: T, start: T, available: T) -> (T, T)
+    where
+        T: Num + Copy + PartialOrd + Bounded,
+    {
+        match self {
+            Align::Start => (start, length),
+            Align::End => (saturating_sub(start + available, length), length),
+            Align::Stretch => (start, available),
+            Align::Center => {
+                let two = T::one() + T::one();
+
+                if length <= available {
+                    (start + (available - length) / two, length)
+                } else {
+                    let overflow = length - available;
+                    let half = overflow / two;
+
+                    // Round the overflow up, so the position is rounded towards the start
+                    let half = if half + half == overflow {
+                        half
+                    } else {
+                        half + T::one()
+                    };
+
+                    (saturating_sub(start, half), length)
+                }
+            }
+        }
+    }
+}
+
+/// How something is placed inside a two-dimensional container.
+///
+/// The nine anchors are available as constants, and
+/// any other combination can be created with [`new()`](crate::Align2D::new).
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let container = bounds!(0, 0, 100, 50);
+///
+/// assert_eq!(size!(10, 10).align_within(container, Align2D::CENTER), bounds!(45, 20, 10, 10));
+/// assert_eq!(size!(10, 10).align_within(container, Align2D::BOTTOM_RIGHT), bounds!(90, 40, 10, 10));
+///
+/// let header = Align2D::new(Align::Stretch, Align::Start);
+/// assert_eq!(size!(10, 10).align_within(container, header), bounds!(0, 0, 100, 10));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Align2D {
+    pub horizontal: Align,
+    pub vertical: Align,
+}
+
+impl Align2D {
+    pub const TOP_LEFT: Self = Self::new(Align::Start, Align::Start);
+    pub const TOP: Self = Self::new(Align::Center, Align::Start);
+    pub const TOP_RIGHT: Self = Self::new(Align::End, Align::Start);
+    pub const LEFT: Self = Self::new(Align::Start, Align::Center);
+    pub const CENTER: Self = Self::new(Align::Center, Align::Center);
+    pub const RIGHT: Self = Self::new(Align::End, Align::Center);
+    pub const BOTTOM_LEFT: Self = Self::new(Align::Start, Align::End);
+    pub const BOTTOM: Self = Self::new(Align::Center, Align::End);
+    pub const BOTTOM_RIGHT: Self = Self::new(Align::End, Align::End);
+
+    /// Fills the whole container.
+    pub const STRETCH: Self = Self::new(Align::Stretch, Align::Stretch);
+
+    /// Creates a new [Align2D] from the alignment on each axis.
+    pub const fn new(horizontal: Align, vertical: Align) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn unsigned_overflow_saturates() {
+        assert_eq!(Align::End.place(10u32, 0, 5), (0, 10));
+        assert_eq!(Align::End.place(10u32, 2, 5), (0, 10));
+        assert_eq!(Align::End.place(10u32, 8, 5), (3, 10));
+        assert_eq!(Align::Center.place(10u32, 0, 5), (0, 10));
+        assert_eq!(Align::Center.place(10u32, 1, 5), (0, 10));
+        assert_eq!(Align::Center.place(10u32, 10, 5), (7, 10));
+    }
+
+    #[test]
+    fn signed_overflow_goes_past_start() {
+        assert_eq!(Align::End.place(10, 0, 5), (-5, 10));
+        assert_eq!(Align::Center.place(10, 0, 5), (-3, 10));
+        assert_eq!(Align::Center.place(10.0, 0.0, 5.0), (-2.5, 10.0));
+    }
+
+    #[test]
+    fn unsigned_cover() {
+        let placed = size!(4000u32, 3000).fit_within(
+            bounds!(0u32, 0, 200, 200),
+            FitMode::Cover,
+            Align2D::CENTER,
+        );
+
+        assert_eq!(placed, bounds!(0, 0, 266, 200));
+    }
+}
@@ -0,0 +1,252 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use num_traits::{Float, FloatConst};
+
+/// A strongly-typed angle, stored in radians.
+///
+/// Arithmetic on angles does not wrap on its own, so that
+/// multiple revolutions can be represented. Use [`normalized()`](crate::Angle::normalized),
+/// [`signed()`](crate::Angle::signed) or the `wrapping_` methods when a wrapped angle is needed.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let quarter = Angle::degrees(90.0);
+/// let half = quarter + quarter;
+///
+/// assert_eq!(half, Angle::radians(std::f64::consts::PI));
+/// assert_eq!(half.to_degrees(), 180.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Angle<T> {
+    pub radians: T,
+}
+
+impl<T> Angle<T>
+where
+    T: Float + FloatConst,
+{
+    /// Creates a new [Angle] from radians.
+    pub fn radians(radians: T) -> Self {
+        Self { radians }
+    }
+
+    /// Creates a new [Angle] from degrees.
+    pub fn degrees(degrees: T) -> Self {
+        Self::radians(degrees.to_radians())
+    }
+
+    /// Returns an angle of zero.
+    pub fn zero() -> Self {
+        Self::radians(T::zero())
+    }
+
+    /// Returns a quarter turn, which is 90 degrees.
+    pub fn frac_pi_2() -> Self {
+        Self::radians(T::FRAC_PI_2())
+    }
+
+    /// Returns a half turn, which is 180 degrees.
+    pub fn pi() -> Self {
+        Self::radians(T::PI())
+    }
+
+    /// Returns a full turn, which is 360 degrees.
+    pub fn two_pi() -> Self {
+        Self::radians(T::TAU())
+    }
+
+    /// Returns the angle in degrees.
+    pub fn to_degrees(self) -> T {
+        self.radians.to_degrees()
+    }
+
+    /// Returns the sine and cosine of the angle.
+    pub fn sin_cos(self) -> (T, T) {
+        self.radians.sin_cos()
+    }
+
+    /// Returns the equivalent angle in the range `[0, 2π)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(Angle::degrees(-90.0).normalized(), Angle::degrees(270.0));
+    /// assert_eq!(Angle::degrees(360.0).normalized(), Angle::zero());
+    /// ```
+    pub fn normalized(self) -> Self {
+        let tau = T::TAU();
+        let radians = self.radians % tau;
+        let radians = if radians < T::zero() {
+            radians + tau
+        } else {
+            radians
+        };
+
+        // Adding a tiny negative remainder can round up to a full turn
+        if radians >= tau {
+            Self::zero()
+        } else {
+            Self::radians(radians)
+        }
+    }
+
+    /// Returns the equivalent angle in the range `(-π, π]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(Angle::degrees(270.0).signed(), Angle::degrees(-90.0));
+    /// assert_eq!(Angle::degrees(-180.0).signed(), Angle::degrees(180.0));
+    /// ```
+    pub fn signed(self) -> Self {
+        let normalized = self.normalized();
+
+        if normalized.radians > T::PI() {
+            Self::radians(normalized.radians - T::TAU())
+        } else {
+            normalized
+        }
+    }
+
+    /// Adds `rhs` to `self`, wrapping the result into the range `[0, 2π)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let angle = Angle::degrees(270.0).wrapping_add(Angle::degrees(180.0));
+    ///
+    /// assert_eq!(angle, Angle::degrees(90.0));
+    /// ```
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        (self + rhs).normalized()
+    }
+
+    /// Subtracts `rhs` from `self`, wrapping the result into the range `[0, 2π)`.
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        (self - rhs).normalized()
+    }
+
+    /// Returns the smallest signed angle needed to turn from `self` to `to`,
+    /// which is in the range `(-π, π]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = Angle::degrees(350.0f64);
+    /// let b = Angle::degrees(10.0);
+    ///
+    /// assert!((a.shortest_to(b).to_degrees() - 20.0).abs() < 1e-10);
+    /// ```
+    pub fn shortest_to(self, to: Self) -> Self {
+        (to - self).signed()
+    }
+
+    /// Linearly interpolates between `self` and `to` along the shortest arc,
+    /// where a `t` of `0` is `self` and a `t` of `1` is `to`.
+    ///
+    /// The result is not normalized, so it stays continuous with `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = Angle::degrees(300.0f64);
+    /// let b = Angle::degrees(60.0);
+    ///
+    /// // Goes through 0 degrees instead of 180 degrees
+    /// let halfway = a.lerp(b, 0.5).signed();
+    /// assert!(halfway.to_degrees().abs() < 1e-10);
+    /// ```
+    pub fn lerp(self, to: Self, t: T) -> Self {
+        self + self.shortest_to(to) * t
+    }
+}
+
+impl<T> Add for Angle<T>
+where
+    T: Float,
+{
+    type Output = Angle<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Angle {
+            radians: self.radians + rhs.radians,
+        }
+    }
+}
+
+impl<T> AddAssign for Angle<T>
+where
+    T: Float,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        self.radians = self.radians + rhs.radians;
+    }
+}
+
+impl<T> Sub for Angle<T>
+where
+    T: Float,
+{
+    type Output = Angle<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Angle {
+            radians: self.radians - rhs.radians,
+        }
+    }
+}
+
+impl<T> SubAssign for Angle<T>
+where
+    T: Float,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        self.radians = self.radians - rhs.radians;
+    }
+}
+
+impl<T> Neg for Angle<T>
+where
+    T: Float,
+{
+    type Output = Angle<T>;
+
+    fn neg(self) -> Self::Output {
+        Angle {
+            radians: -self.radians,
+        }
+    }
+}
+
+impl<T> Mul<T> for Angle<T>
+where
+    T: Float,
+{
+    type Output = Angle<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Angle {
+            radians: self.radians * rhs,
+        }
+    }
+}
+
+impl<T> Div<T> for Angle<T>
+where
+    T: Float,
+{
+    type Output = Angle<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        Angle {
+            radians: self.radians / rhs,
+        }
+    }
+}
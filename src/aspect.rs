@@ -0,0 +1,178 @@
+use num_traits::{AsPrimitive, Num};
+
+use crate::{widen::wide_mul, Size2D, Widen};
+
+/// The proportion between the width and height of something.
+///
+/// The ratio is stored as its two sides instead of a single number,
+/// so that integer sizes can be resized without losing precision.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let widescreen = AspectRatio::new(16, 9);
+///
+/// assert_eq!(widescreen.size_for_width(1920), size!(1920, 1080));
+/// assert_eq!(widescreen.size_for_height(720), size!(1280, 720));
+/// assert_eq!(widescreen, size!(1920, 1080).aspect_ratio());
+/// assert_eq!(widescreen.ratio(), 16.0 / 9.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AspectRatio<T> {
+    pub width: T,
+    pub height: T,
+}
+
+impl<T> AspectRatio<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Creates a new [AspectRatio] of `width` to `height`.
+    pub fn new(width: T, height: T) -> Self {
+        Self { width, height }
+    }
+
+    /// Returns the ratio as a single number, which is the width divided by the height.
+    ///
+    /// This is always a [f64], so that integer ratios are not rounded.
+    pub fn ratio(&self) -> f64
+    where
+        T: AsPrimitive<f64>,
+    {
+        self.width.as_() / self.height.as_()
+    }
+
+    /// Returns `true` if the ratio is wider than `other`.
+    pub fn is_wider_than(&self, other: Self) -> bool
+    where
+        T: Widen,
+    {
+        wide_mul(self.width, other.height) > wide_mul(other.width, self.height)
+    }
+
+    /// Returns a size with this ratio and the given `width`.
+    ///
+    /// For integers, the height is rounded down.
+    ///
+    /// # Panics
+    /// For integers, if the width of the ratio is zero.
+    pub fn size_for_width<U>(&self, width: T) -> Size2D<T, U>
+    where
+        T: Widen,
+    {
+        let height = wide_mul(width, self.height) / self.width.widen();
+        Size2D::new(width, T::narrow(height))
+    }
+
+    /// Returns a size with this ratio and the given `height`.
+    ///
+    /// For integers, the width is rounded down.
+    ///
+    /// # Panics
+    /// For integers, if the height of the ratio is zero.
+    pub fn size_for_height<U>(&self, height: T) -> Size2D<T, U>
+    where
+        T: Widen,
+    {
+        let width = wide_mul(height, self.width) / self.height.widen();
+        Size2D::new(T::narrow(width), height)
+    }
+}
+
+/// Two ratios are equal when they describe the same proportion,
+/// so `16:9` is equal to `32:18`.
+///
+/// A ratio of `0:0` has no proportion, so it is only equal to itself.
+impl<T> PartialEq for AspectRatio<T>
+where
+    T: Num + Copy + Widen,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_empty() || other.is_empty() {
+            return self.is_empty() && other.is_empty();
+        }
+
+        wide_mul(self.width, other.height) == wide_mul(other.width, self.height)
+    }
+}
+
+impl<T> AspectRatio<T>
+where
+    T: Num + Copy,
+{
+    fn is_empty(&self) -> bool {
+        self.width.is_zero() && self.height.is_zero()
+    }
+}
+
+/// How a size is resized to fit inside a container,
+/// following the semantics of `object-fit` in CSS.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FitMode {
+    /// Scaled to be as large as possible while staying inside the container,
+    /// keeping the aspect ratio.
+    #[default]
+    Contain,
+    /// Scaled to be as small as possible while covering the whole container,
+    /// keeping the aspect ratio.
+    Cover,
+    /// Resized to the size of the container, ignoring the aspect ratio.
+    Fill,
+    /// Not resized at all.
+    None,
+    /// The smallest of [`FitMode::None`] and [`FitMode::Contain`],
+    /// so it is only ever scaled down.
+    ScaleDown,
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn integer_ratio() {
+        assert_eq!(AspectRatio::new(16, 9).ratio(), 16.0 / 9.0);
+        assert_eq!(AspectRatio::new(3u32, 2).ratio(), 1.5);
+    }
+
+    #[test]
+    fn empty_ratio_is_only_equal_to_itself() {
+        let empty = AspectRatio::new(0, 0);
+
+        assert_eq!(empty, AspectRatio::new(0, 0));
+        assert_ne!(empty, AspectRatio::new(16, 9));
+        assert_ne!(AspectRatio::new(4, 3), empty);
+        assert_ne!(AspectRatio::new(16, 9), AspectRatio::new(4, 3));
+
+        // Ratios with a single zero side still have a proportion
+        assert_eq!(AspectRatio::new(0, 9), AspectRatio::new(0, 3));
+        assert_ne!(AspectRatio::new(0, 9), AspectRatio::new(9, 0));
+    }
+
+    #[test]
+    fn large_integer_sides() {
+        let wide = AspectRatio::new(70000u32, 1);
+        let tall = AspectRatio::new(1, 70000u32);
+
+        assert!(wide.is_wider_than(tall));
+        assert!(!tall.is_wider_than(wide));
+        assert_eq!(AspectRatio::new(70000u32, 70000), AspectRatio::new(1, 1));
+
+        let photo = AspectRatio::new(80000u32, 60000);
+        assert_eq!(
+            photo.size_for_width::<UnknownUnit>(100000),
+            size!(100000, 75000)
+        );
+        assert_eq!(
+            photo.size_for_height::<UnknownUnit>(90000),
+            size!(120000, 90000)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn integer_size_for_zero_width() {
+        AspectRatio::new(0, 9).size_for_width::<UnknownUnit>(1920);
+    }
+}
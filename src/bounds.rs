@@ -1,17 +1,34 @@
-use std::ops::{Add, Mul, Sub};
+use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    ops::{Add, Mul, Sub},
+};
 
-use num_traits::Num;
+use num_traits::{Bounded, Num};
 
-use crate::{Offset2D, Point2D, Size2D, ToPoint2D, ToSize2D};
+use crate::{
+    Align2D, FitMode, Insets2D, Offset2D, Offset3D, Point2D, Point3D, Size2D, Size3D, ToOffset2D,
+    ToPoint2D, ToPoint3D, ToSize2D, ToSize3D, UnknownUnit, Widen,
+};
 
 /// A two-dimensional bounding box.
-#[derive(Default, Debug, PartialEq, Clone, Copy, Hash)]
-pub struct Bounds2D<T> {
-    position: Point2D<T>,
-    size: Size2D<T>,
+///
+/// # Edges
+/// Bounds are half-open, which means the top and left edges are inside the bounds,
+/// while the bottom and right edges are not. This is the same for integer and float types.
+///
+/// For integers this matches how cells or pixels are addressed, as a bounds of width `4`
+/// at `x = 0` covers the columns `0`, `1`, `2` and `3`. For floats it means that two bounds
+/// sharing an edge do not overlap, and a point on that edge belongs to exactly one of them.
+///
+/// Bounds with a width or height of zero are empty, so they contain no points
+/// and never intersect anything.
+pub struct Bounds2D<T, U = UnknownUnit> {
+    position: Point2D<T, U>,
+    size: Size2D<T, U>,
 }
 
-impl<T> Bounds2D<T>
+impl<T, U> Bounds2D<T, U>
 where
     T: Copy,
 {
@@ -25,7 +42,7 @@ where
     /// let bounds = Bounds2D::new(20, 50, 80, 90);
     ///
     /// // Prefer doing this instead
-    /// let bounds = bounds!(20, 50, 80, 90);
+    /// assert_eq!(bounds, bounds!(20, 50, 80, 90));
     /// ```
     pub fn new(x: T, y: T, width: T, height: T) -> Self {
         let position = Point2D::new(x, y);
@@ -45,10 +62,10 @@ where
     /// #
     /// // This is acceptable, but...
     /// let bounds = Bounds2D::splat(8);
+    /// assert_eq!(bounds, bounds!(8, 8, 8, 8));
     ///
     /// // ...this is the preferred way
     /// let bounds = bounds!(8; 2);
-    ///
     /// assert_eq!(bounds, bounds!(8, 8, 8, 8));
     /// ```
     pub fn splat(value: T) -> Self {
@@ -72,8 +89,8 @@ where
     /// ```
     pub fn from_position_and_size<P, S>(position: P, size: S) -> Self
     where
-        P: ToPoint2D<T>,
-        S: ToSize2D<T>,
+        P: ToPoint2D<T, U>,
+        S: ToSize2D<T, U>,
     {
         let position = position.to_vector();
         let size = size.to_size();
@@ -82,12 +99,12 @@ where
     }
 
     /// Creates a new [Bounds2D] with the specified position.
-    pub fn with_position<P: ToPoint2D<T>>(&self, point: P) -> Bounds2D<T> {
+    pub fn with_position<P: ToPoint2D<T, U>>(&self, point: P) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(point, self.size)
     }
 
     /// Creates a new [Bounds2D] with the specified size.
-    pub fn with_size<S: ToSize2D<T>>(&self, size: S) -> Bounds2D<T> {
+    pub fn with_size<S: ToSize2D<T, U>>(&self, size: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, size)
     }
 
@@ -128,142 +145,846 @@ where
         self.size.area()
     }
 
-    pub fn size(&self) -> Size2D<T> {
+    pub fn size(&self) -> Size2D<T, U> {
         self.size
     }
 
-    pub fn position(&self) -> Point2D<T> {
+    pub fn position(&self) -> Point2D<T, U> {
         self.position
     }
 }
 
-impl<T> Bounds2D<T>
+impl<T, U> Bounds2D<T, U>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Creates a new [Bounds2D] spanning from the `min` corner to the `max` corner.
+    ///
+    /// The `max` corner must not be smaller than `min` on either axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = Bounds2D::from_corners(point!(10, 20), (50, 80));
+    ///
+    /// assert_eq!(bounds, bounds!(10, 20, 40, 60));
+    /// ```
+    pub fn from_corners<A, B>(min: A, max: B) -> Self
+    where
+        A: ToPoint2D<T, U>,
+        B: ToPoint2D<T, U>,
+    {
+        let min = min.to_vector();
+        let max = max.to_vector();
+
+        Self::new(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+
+    /// Returns `true` if the bounds have no area.
+    pub fn is_empty(&self) -> bool {
+        !(self.size.width > T::zero() && self.size.height > T::zero())
+    }
+
+    /// Returns the point in the middle of the bounds.
+    ///
+    /// For integers the result is rounded towards the top left.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(bounds!(10, 10, 20, 20).center(), point!(20, 20));
+    /// assert_eq!(bounds!(0, 0, 5, 5).center(), point!(2, 2));
+    /// ```
+    pub fn center(&self) -> Point2D<T, U> {
+        let two = T::one() + T::one();
+
+        Point2D::new(
+            self.left() + self.width() / two,
+            self.top() + self.height() / two,
+        )
+    }
+
+    /// Returns `true` if `point` is inside the bounds.
+    ///
+    /// Points on the top and left edges are inside,
+    /// while points on the bottom and right edges are not.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(0, 0, 10, 10);
+    ///
+    /// assert!(bounds.contains_point((0, 0)));
+    /// assert!(bounds.contains_point((9, 9)));
+    /// assert!(!bounds.contains_point((10, 5)));
+    /// ```
+    pub fn contains_point<P: ToPoint2D<T, U>>(&self, point: P) -> bool {
+        let point = point.to_vector();
+
+        point.x >= self.left()
+            && point.x < self.right()
+            && point.y >= self.top()
+            && point.y < self.bottom()
+    }
+
+    /// Returns `true` if every point in `bounds` is also inside `self`.
+    ///
+    /// Empty bounds have no points, so they are contained by any bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let outer = bounds!(0, 0, 10, 10);
+    ///
+    /// assert!(outer.contains_bounds(bounds!(0, 0, 10, 10)));
+    /// assert!(outer.contains_bounds(bounds!(2, 2, 4, 4)));
+    /// assert!(!outer.contains_bounds(bounds!(5, 5, 10, 10)));
+    /// ```
+    pub fn contains_bounds<B: IntoBounds2D<T, U>>(&self, bounds: B) -> bool {
+        let bounds = bounds.to_bounds();
+
+        bounds.is_empty()
+            || (bounds.left() >= self.left()
+                && bounds.right() <= self.right()
+                && bounds.top() >= self.top()
+                && bounds.bottom() <= self.bottom())
+    }
+
+    /// Returns `true` if `self` and `bounds` share any points.
+    ///
+    /// Bounds that only touch along an edge do not intersect.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(0.0, 0.0, 10.0, 10.0);
+    ///
+    /// assert!(bounds.intersects(bounds!(5.0, 5.0, 10.0, 10.0)));
+    /// assert!(!bounds.intersects(bounds!(10.0, 0.0, 10.0, 10.0)));
+    /// ```
+    pub fn intersects<B: IntoBounds2D<T, U>>(&self, bounds: B) -> bool {
+        self.intersection(bounds).is_some()
+    }
+
+    /// Returns the area shared by `self` and `bounds`,
+    /// or [`None`] if they do not intersect.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = bounds!(0, 0, 10, 10);
+    /// let b = bounds!(5, 8, 10, 10);
+    ///
+    /// assert_eq!(a.intersection(b), Some(bounds!(5, 8, 5, 2)));
+    /// assert_eq!(a.intersection((10, 0, 5, 5)), None);
+    /// ```
+    pub fn intersection<B: IntoBounds2D<T, U>>(&self, bounds: B) -> Option<Self> {
+        let bounds = bounds.to_bounds();
+
+        let left = partial_max(self.left(), bounds.left());
+        let top = partial_max(self.top(), bounds.top());
+        let right = partial_min(self.right(), bounds.right());
+        let bottom = partial_min(self.bottom(), bounds.bottom());
+
+        if left < right && top < bottom {
+            Some(Self::from_corners((left, top), (right, bottom)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest bounds that contains both `self` and `bounds`.
+    ///
+    /// Empty bounds have no points to contain, so they are ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = bounds!(0, 0, 10, 10);
+    /// let b = bounds!(20, 5, 10, 10);
+    ///
+    /// assert_eq!(a.union(b), bounds!(0, 0, 30, 15));
+    /// assert_eq!(a.union(bounds!(50, 50, 0, 0)), a);
+    /// ```
+    pub fn union<B: IntoBounds2D<T, U>>(&self, bounds: B) -> Self {
+        let bounds = bounds.to_bounds();
+
+        if bounds.is_empty() {
+            return *self;
+        }
+
+        if self.is_empty() {
+            return bounds;
+        }
+
+        Self::from_corners(
+            (
+                partial_min(self.left(), bounds.left()),
+                partial_min(self.top(), bounds.top()),
+            ),
+            (
+                partial_max(self.right(), bounds.right()),
+                partial_max(self.bottom(), bounds.bottom()),
+            ),
+        )
+    }
+
+    /// Returns the point inside or on the edges of the bounds that is closest to `point`.
+    ///
+    /// The point is clamped to the closed range between the edges, so a point clamped
+    /// to the bottom or right edge is not inside according to [`contains_point()`](crate::Bounds2D::contains_point).
+    /// For integers, clamp to bounds shrunk by one to always get a point inside.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(0, 0, 10, 10);
+    ///
+    /// assert_eq!(bounds.clamp_point((-5, 5)), point!(0, 5));
+    /// assert_eq!(bounds.clamp_point((20, 20)), point!(10, 10));
+    /// ```
+    pub fn clamp_point<P: ToPoint2D<T, U>>(&self, point: P) -> Point2D<T, U> {
+        let point = point.to_vector();
+
+        Point2D::new(
+            partial_min(partial_max(point.x, self.left()), self.right()),
+            partial_min(partial_max(point.y, self.top()), self.bottom()),
+        )
+    }
+
+    /// Returns `self` placed inside `bounds` at `anchor`, and then moved by `offset`.
+    ///
+    /// Only the size of `self` is used, unless the anchor stretches it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let window = bounds!(0, 0, 800, 600);
+    /// let toast = bounds!(0, 0, 200, 50);
+    ///
+    /// // Pin to the bottom right, with a margin of 8
+    /// let pinned = toast.align_to(window, Align2D::BOTTOM_RIGHT, (-8, -8));
+    /// assert_eq!(pinned, bounds!(592, 542, 200, 50));
+    /// ```
+    pub fn align_to<B, O>(&self, bounds: B, anchor: Align2D, offset: O) -> Self
+    where
+        B: IntoBounds2D<T, U>,
+        O: ToOffset2D<T, U>,
+        T: Bounded,
+    {
+        let aligned = self.size.align_within(bounds, anchor);
+        aligned.with_position(aligned.position + offset)
+    }
+
+    /// Returns `self` resized to fit inside `bounds` according to `mode`,
+    /// and then placed inside it according to `align`.
+    ///
+    /// See [`Size2D::fit_within()`](crate::Size2D::fit_within) for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let image = bounds!(0, 0, 300, 100);
+    /// let placed = image.fit_to(bounds!(10, 10, 100, 100), FitMode::Cover, Align2D::CENTER);
+    ///
+    /// assert_eq!(placed, bounds!(-90, 10, 300, 100));
+    /// ```
+    pub fn fit_to<B: IntoBounds2D<T, U>>(&self, bounds: B, mode: FitMode, align: Align2D) -> Self
+    where
+        T: Bounded + Widen,
+    {
+        self.size.fit_within(bounds, mode, align)
+    }
+
+    /// Returns `self` with each edge moved inwards by `insets`.
+    ///
+    /// The size never goes below zero, so insets larger than
+    /// the bounds result in empty bounds instead of underflowing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(10u32, 10, 20, 20);
+    ///
+    /// assert_eq!(bounds.inset(insets!(1, 2, 3, 4)), bounds!(14, 11, 14, 16));
+    /// assert_eq!(bounds.inset(insets!(15)), bounds!(25, 25, 0, 0));
+    /// ```
+    pub fn inset(&self, insets: Insets2D<T, U>) -> Self
+    where
+        T: Bounded,
+    {
+        Self::new(
+            self.left() + insets.left,
+            self.top() + insets.top,
+            partial_max(saturating_sub(self.width(), insets.horizontal()), T::zero()),
+            partial_max(saturating_sub(self.height(), insets.vertical()), T::zero()),
+        )
+    }
+
+    /// Returns `self` with each edge moved outwards by `insets`.
+    /// This is the opposite of [`inset()`](crate::Bounds2D::inset).
+    ///
+    /// For unsigned types, the position is saturated to zero instead of underflowing,
+    /// while the right and bottom edges still move outwards by the full insets.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(10, 10, 20, 20);
+    ///
+    /// assert_eq!(bounds.outset(insets!(5, 0)), bounds!(10, 5, 20, 30));
+    /// assert_eq!(bounds!(1u32, 0, 5, 5).outset(insets!(2)), bounds!(0, 0, 8, 7));
+    /// ```
+    pub fn outset(&self, insets: Insets2D<T, U>) -> Self
+    where
+        T: Bounded,
+    {
+        let left = saturating_sub(self.left(), insets.left);
+        let top = saturating_sub(self.top(), insets.top);
+
+        Self::new(
+            left,
+            top,
+            partial_max(self.right() + insets.right - left, T::zero()),
+            partial_max(self.bottom() + insets.bottom - top, T::zero()),
+        )
+    }
+
+    /// Returns the parts of `self` that are not covered by `bounds`,
+    /// as up to four non-overlapping rectangles.
+    ///
+    /// The rectangles above and below `bounds` span the full width of `self`,
+    /// while the ones to the left and right only span the height of the overlap.
+    /// Empty rectangles are never returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(0, 0, 10, 10);
+    /// let pieces: Vec<_> = bounds.subtract(bounds!(2, 2, 4, 4)).collect();
+    ///
+    /// assert_eq!(
+    ///     pieces,
+    ///     vec![
+    ///         bounds!(0, 0, 10, 2),
+    ///         bounds!(0, 6, 10, 4),
+    ///         bounds!(0, 2, 2, 4),
+    ///         bounds!(6, 2, 4, 4),
+    ///     ]
+    /// );
+    ///
+    /// // Subtracting bounds that do not intersect leaves `self` as is
+    /// let pieces: Vec<_> = bounds.subtract(bounds!(20, 20, 5, 5)).collect();
+    /// assert_eq!(pieces, vec![bounds]);
+    /// ```
+    pub fn subtract<B: IntoBounds2D<T, U>>(&self, bounds: B) -> impl Iterator<Item = Self> {
+        let pieces = match self.intersection(bounds) {
+            Some(overlap) => [
+                Self::from_corners((self.left(), self.top()), (self.right(), overlap.top())),
+                Self::from_corners(
+                    (self.left(), overlap.bottom()),
+                    (self.right(), self.bottom()),
+                ),
+                Self::from_corners(
+                    (self.left(), overlap.top()),
+                    (overlap.left(), overlap.bottom()),
+                ),
+                Self::from_corners(
+                    (overlap.right(), overlap.top()),
+                    (self.right(), overlap.bottom()),
+                ),
+            ]
+            .map(Some),
+            None => [Some(*self), None, None, None],
+        };
+
+        pieces
+            .into_iter()
+            .flatten()
+            .filter(|piece| !piece.is_empty())
+    }
+}
+
+impl<T, U> Bounds2D<T, U>
 where
     T: Num + Copy + Ord,
 {
     /// See [`Size2D::grow()`](crate::Size2D::grow) for more information.
-    pub fn grow<S: ToSize2D<T>>(&self, size: S) -> Bounds2D<T> {
+    pub fn grow<S: ToSize2D<T, U>>(&self, size: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, self.size.grow(size))
     }
 
     /// See [`Size2D::shrink()`](crate::Size2D::shrink) for more information.
-    pub fn shrink<S: ToSize2D<T>>(&self, size: S) -> Bounds2D<T> {
+    pub fn shrink<S: ToSize2D<T, U>>(&self, size: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, self.size.shrink(size))
     }
 
     /// See [`Size2D::constrain()`](crate::Size2D::constrain) for more information.
-    pub fn constrain<S: ToSize2D<T>>(&self, min: S, max: S) -> Bounds2D<T> {
+    pub fn constrain<S: ToSize2D<T, U>>(&self, min: S, max: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, self.size.constrain(min, max))
     }
 
     /// See [`Size2D::max_area()`](crate::Size2D::max_area) for more information.
-    pub fn max_area<S: ToSize2D<T>>(&self, size: S) -> Bounds2D<T> {
+    pub fn max_area<S: ToSize2D<T, U>>(&self, size: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, self.size.max_area(size))
     }
 
     /// See [`Size2D::min_area()`](crate::Size2D::min_area) for more information.
-    pub fn min_area<S: ToSize2D<T>>(&self, size: S) -> Bounds2D<T> {
+    pub fn min_area<S: ToSize2D<T, U>>(&self, size: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, self.size.min_area(size))
     }
 
     /// See [`Size2D::clamp_area()`](crate::Size2D::clamp_area) for more information.
-    pub fn clamp_area<S: ToSize2D<T>>(&self, min: S, max: S) -> Bounds2D<T> {
+    pub fn clamp_area<S: ToSize2D<T, U>>(&self, min: S, max: S) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(self.position, self.size.clamp_area(min, max))
     }
 }
 
-impl<T> Add<Offset2D<T>> for Bounds2D<T>
+impl<T, U> Add<Offset2D<T, U>> for Bounds2D<T, U>
 where
     T: Num + Copy,
 {
-    type Output = Bounds2D<T>;
+    type Output = Bounds2D<T, U>;
 
-    fn add(self, rhs: Offset2D<T>) -> Self::Output {
+    fn add(self, rhs: Offset2D<T, U>) -> Self::Output {
         Bounds2D::from_position_and_size(self.position + rhs, self.size)
     }
 }
 
-impl<T> Add<Size2D<T>> for Bounds2D<T>
+impl<T, U> Add<Size2D<T, U>> for Bounds2D<T, U>
 where
     T: Num + Copy,
 {
-    type Output = Bounds2D<T>;
+    type Output = Bounds2D<T, U>;
 
-    fn add(self, rhs: Size2D<T>) -> Self::Output {
+    fn add(self, rhs: Size2D<T, U>) -> Self::Output {
         Bounds2D::from_position_and_size(self.position, self.size + rhs)
     }
 }
 
-impl<T> Sub<Offset2D<T>> for Bounds2D<T>
+impl<T, U> Sub<Offset2D<T, U>> for Bounds2D<T, U>
 where
     T: Num + Copy,
 {
-    type Output = Bounds2D<T>;
+    type Output = Bounds2D<T, U>;
 
-    fn sub(self, rhs: Offset2D<T>) -> Self::Output {
+    fn sub(self, rhs: Offset2D<T, U>) -> Self::Output {
         Bounds2D::from_position_and_size(self.position - rhs, self.size)
     }
 }
 
-impl<T> Sub<Size2D<T>> for Bounds2D<T>
+impl<T, U> Sub<Size2D<T, U>> for Bounds2D<T, U>
 where
     T: Num + Copy,
 {
-    type Output = Bounds2D<T>;
+    type Output = Bounds2D<T, U>;
 
-    fn sub(self, rhs: Size2D<T>) -> Self::Output {
+    fn sub(self, rhs: Size2D<T, U>) -> Self::Output {
         Bounds2D::from_position_and_size(self.position, self.size - rhs)
     }
 }
 
-impl<T> From<Bounds2D<T>> for [T; 4] {
-    fn from(bounds: Bounds2D<T>) -> Self {
-        [
-            bounds.position.x,
-            bounds.position.y,
-            bounds.size.width,
-            bounds.size.height,
-        ]
+impl<T, U> From<Bounds2D<T, U>> for [T; 4] {
+    fn from(bounds: Bounds2D<T, U>) -> Self {
+        let [x, y] = bounds.position.to_array();
+        [x, y, bounds.size.width, bounds.size.height]
     }
 }
 
-impl<T> From<Bounds2D<T>> for (T, T, T, T) {
-    fn from(bounds: Bounds2D<T>) -> Self {
-        (
-            bounds.position.x,
-            bounds.position.y,
-            bounds.size.width,
-            bounds.size.height,
-        )
+impl<T, U> From<Bounds2D<T, U>> for (T, T, T, T) {
+    fn from(bounds: Bounds2D<T, U>) -> Self {
+        let [x, y] = bounds.position.to_array();
+        (x, y, bounds.size.width, bounds.size.height)
     }
 }
 
-pub trait IntoBounds2D<T> {
-    fn to_bounds(self) -> Bounds2D<T>;
+pub trait IntoBounds2D<T, U = UnknownUnit> {
+    fn to_bounds(self) -> Bounds2D<T, U>;
 }
 
-impl<T> IntoBounds2D<T> for Bounds2D<T> {
-    fn to_bounds(self) -> Bounds2D<T> {
+impl<T, U> IntoBounds2D<T, U> for Bounds2D<T, U> {
+    fn to_bounds(self) -> Bounds2D<T, U> {
         self
     }
 }
 
-impl<T> IntoBounds2D<T> for (T, T, T, T)
+impl<T, U> IntoBounds2D<T, U> for (T, T, T, T)
 where
     T: Num + Copy,
 {
-    fn to_bounds(self) -> Bounds2D<T> {
+    fn to_bounds(self) -> Bounds2D<T, U> {
         let (x, y, width, height) = self;
         Bounds2D::new(x, y, width, height)
     }
 }
 
-impl<T> IntoBounds2D<T> for [T; 4]
+impl<T, U> IntoBounds2D<T, U> for [T; 4]
 where
     T: Num + Copy,
 {
-    fn to_bounds(self) -> Bounds2D<T> {
+    fn to_bounds(self) -> Bounds2D<T, U> {
         let [x, y, width, height] = self;
         Bounds2D::new(x, y, width, height)
     }
 }
+
+// These are implemented by hand, so that the unit marker
+// is not required to implement the traits as well.
+impl<T, U> Clone for Bounds2D<T, U>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            position: self.position.clone(),
+            size: self.size.clone(),
+        }
+    }
+}
+
+impl<T, U> Copy for Bounds2D<T, U> where T: Copy {}
+
+impl<T, U> PartialEq for Bounds2D<T, U>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.position == other.position && self.size == other.size
+    }
+}
+
+impl<T, U> Eq for Bounds2D<T, U> where T: Eq {}
+
+impl<T, U> Hash for Bounds2D<T, U>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.position.hash(state);
+        self.size.hash(state);
+    }
+}
+
+impl<T, U> Default for Bounds2D<T, U>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            position: Point2D::default(),
+            size: Size2D::default(),
+        }
+    }
+}
+
+impl<T, U> Debug for Bounds2D<T, U>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Bounds2D")
+            .field("position", &self.position)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// A three-dimensional, axis-aligned bounding box.
+#[derive(Default, Debug, PartialEq, Clone, Copy, Hash)]
+pub struct Bounds3D<T> {
+    position: Point3D<T>,
+    size: Size3D<T>,
+}
+
+impl<T> Bounds3D<T>
+where
+    T: Copy,
+{
+    /// Creates a new [Bounds3D]. In most cases you should use
+    /// the `bounds!()` macro instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = Bounds3D::new(20, 50, 10, 80, 90, 30);
+    ///
+    /// // Prefer doing this instead
+    /// let bounds = bounds!(20, 50, 10, 80, 90, 30);
+    /// ```
+    pub fn new(x: T, y: T, z: T, width: T, height: T, depth: T) -> Self {
+        let position = Point3D::new(x, y, z);
+        let size = Size3D::new(width, height, depth);
+
+        Self { position, size }
+    }
+
+    /// Returns a new [Bounds3D] where all components are set to `value`.
+    ///
+    /// Prefer using the splat syntax with the macro instead of
+    /// calling this directly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = bounds!(8; 3);
+    ///
+    /// assert_eq!(bounds, bounds!(8, 8, 8, 8, 8, 8));
+    /// ```
+    pub fn splat(value: T) -> Self {
+        Self::from_position_and_size(Point3D::splat(value), Size3D::cube(value))
+    }
+
+    /// Creates a new [Bounds3D] from a position and size.
+    ///
+    /// If you already have a [Size3D] or a [Point3D],
+    /// you should use the `.with_` method instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = Bounds3D::from_position_and_size(point!(20, 40, 0), size!(10; 3));
+    ///
+    /// // Prefer doing this instead
+    /// let bounds = point!(20, 40, 0).with_size(size!(10; 3));
+    /// ```
+    pub fn from_position_and_size<P, S>(position: P, size: S) -> Self
+    where
+        P: ToPoint3D<T>,
+        S: ToSize3D<T>,
+    {
+        let position = position.to_vector();
+        let size = size.to_size();
+
+        Self { position, size }
+    }
+
+    /// Creates a new [Bounds3D] with the specified position.
+    pub fn with_position<P: ToPoint3D<T>>(&self, point: P) -> Bounds3D<T> {
+        Bounds3D::from_position_and_size(point, self.size)
+    }
+
+    /// Creates a new [Bounds3D] with the specified size.
+    pub fn with_size<S: ToSize3D<T>>(&self, size: S) -> Bounds3D<T> {
+        Bounds3D::from_position_and_size(self.position, size)
+    }
+
+    pub fn width(&self) -> T {
+        self.size.width
+    }
+
+    pub fn height(&self) -> T {
+        self.size.height
+    }
+
+    pub fn depth(&self) -> T {
+        self.size.depth
+    }
+
+    pub fn top(&self) -> T {
+        self.position.y
+    }
+
+    pub fn left(&self) -> T {
+        self.position.x
+    }
+
+    /// Returns the near edge on the Z axis.
+    pub fn front(&self) -> T {
+        self.position.z
+    }
+
+    pub fn right(&self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.position.x + self.size.width
+    }
+
+    pub fn bottom(&self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.position.y + self.size.height
+    }
+
+    /// Returns the far edge on the Z axis.
+    pub fn back(&self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.position.z + self.size.depth
+    }
+
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.size.volume()
+    }
+
+    pub fn size(&self) -> Size3D<T> {
+        self.size
+    }
+
+    pub fn position(&self) -> Point3D<T> {
+        self.position
+    }
+}
+
+impl<T> Bounds3D<T>
+where
+    T: Num + Copy + Ord,
+{
+    /// See [`Size3D::grow()`](crate::Size3D::grow) for more information.
+    pub fn grow<S: ToSize3D<T>>(&self, size: S) -> Bounds3D<T> {
+        Bounds3D::from_position_and_size(self.position, self.size.grow(size))
+    }
+
+    /// See [`Size3D::shrink()`](crate::Size3D::shrink) for more information.
+    pub fn shrink<S: ToSize3D<T>>(&self, size: S) -> Bounds3D<T> {
+        Bounds3D::from_position_and_size(self.position, self.size.shrink(size))
+    }
+
+    /// See [`Size3D::constrain()`](crate::Size3D::constrain) for more information.
+    pub fn constrain<S: ToSize3D<T>>(&self, min: S, max: S) -> Bounds3D<T> {
+        Bounds3D::from_position_and_size(self.position, self.size.constrain(min, max))
+    }
+}
+
+impl<T> Add<Offset3D<T>> for Bounds3D<T>
+where
+    T: Num + Copy,
+{
+    type Output = Bounds3D<T>;
+
+    fn add(self, rhs: Offset3D<T>) -> Self::Output {
+        Bounds3D::from_position_and_size(self.position + rhs, self.size)
+    }
+}
+
+impl<T> Add<Size3D<T>> for Bounds3D<T>
+where
+    T: Num + Copy,
+{
+    type Output = Bounds3D<T>;
+
+    fn add(self, rhs: Size3D<T>) -> Self::Output {
+        Bounds3D::from_position_and_size(self.position, self.size + rhs)
+    }
+}
+
+impl<T> Sub<Offset3D<T>> for Bounds3D<T>
+where
+    T: Num + Copy,
+{
+    type Output = Bounds3D<T>;
+
+    fn sub(self, rhs: Offset3D<T>) -> Self::Output {
+        Bounds3D::from_position_and_size(self.position - rhs, self.size)
+    }
+}
+
+impl<T> Sub<Size3D<T>> for Bounds3D<T>
+where
+    T: Num + Copy,
+{
+    type Output = Bounds3D<T>;
+
+    fn sub(self, rhs: Size3D<T>) -> Self::Output {
+        Bounds3D::from_position_and_size(self.position, self.size - rhs)
+    }
+}
+
+impl<T> From<Bounds3D<T>> for [T; 6] {
+    fn from(bounds: Bounds3D<T>) -> Self {
+        let [x, y, z] = bounds.position.to_array();
+        let Size3D {
+            width,
+            height,
+            depth,
+        } = bounds.size;
+
+        [x, y, z, width, height, depth]
+    }
+}
+
+impl<T> From<Bounds3D<T>> for (T, T, T, T, T, T) {
+    fn from(bounds: Bounds3D<T>) -> Self {
+        let [x, y, z, width, height, depth] = bounds.into();
+        (x, y, z, width, height, depth)
+    }
+}
+
+pub trait IntoBounds3D<T> {
+    fn to_bounds(self) -> Bounds3D<T>;
+}
+
+impl<T> IntoBounds3D<T> for Bounds3D<T> {
+    fn to_bounds(self) -> Bounds3D<T> {
+        self
+    }
+}
+
+impl<T> IntoBounds3D<T> for (T, T, T, T, T, T)
+where
+    T: Num + Copy,
+{
+    fn to_bounds(self) -> Bounds3D<T> {
+        let (x, y, z, width, height, depth) = self;
+        Bounds3D::new(x, y, z, width, height, depth)
+    }
+}
+
+impl<T> IntoBounds3D<T> for [T; 6]
+where
+    T: Num + Copy,
+{
+    fn to_bounds(self) -> Bounds3D<T> {
+        let [x, y, z, width, height, depth] = self;
+        Bounds3D::new(x, y, z, width, height, depth)
+    }
+}
+
+pub(crate) fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+pub(crate) fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// Returns `a - b`, saturated to the smallest value of `T` if that is zero.
+pub(crate) fn saturating_sub<T>(a: T, b: T) -> T
+where
+    T: Num + PartialOrd + Bounded,
+{
+    if b <= a || T::min_value() < T::zero() {
+        a - b
+    } else {
+        T::min_value()
+    }
+}
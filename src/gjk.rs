@@ -0,0 +1,762 @@
+use std::cmp::Ordering;
+
+use num_traits::{Float, FloatConst};
+
+use crate::{
+    Bounds2D, Capsule2D, Circle2D, Ellipse2D, Offset2D, Overlaps, Penetrates, Penetration, Point2D,
+    Polygon2D, RotatedRect2D, Vector,
+};
+
+/// The most steps taken by [`gjk_distance()`] and [`epa_penetration()`]
+/// before settling for the closest answer found.
+const MAX_ITERATIONS: usize = 128;
+
+/// A convex shape described by its furthest point in any direction.
+///
+/// This is all [`gjk_distance()`] and [`epa_penetration()`] need to know about a shape,
+/// so any two shapes implementing it can be tested against each other.
+pub trait SupportMap<T> {
+    /// Returns the point of the shape furthest along `direction`,
+    /// which does not need to be of unit length.
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T>;
+}
+
+/// The shape made by sweeping `a` over every point of `b`,
+/// such as a rectangle with rounded corners from a [Bounds2D] and a [Circle2D].
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let rounded = MinkowskiSum::new(bounds!(0.0, 0.0, 10.0, 10.0), Circle2D::new((0.0, 0.0), 2.0));
+///
+/// assert_eq!(rounded.support(offset!(1.0, 0.0)).x, 12.0);
+/// assert_eq!(rounded.support(offset!(0.0, -1.0)).y, -2.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct MinkowskiSum<A, B> {
+    pub a: A,
+    pub b: B,
+}
+
+impl<A, B> MinkowskiSum<A, B> {
+    /// Creates a new [MinkowskiSum] of `a` and `b`.
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+}
+
+/// The closest points between two shapes that do not overlap.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Separation<T> {
+    /// The distance between the shapes.
+    pub distance: T,
+    /// The point on the first shape closest to the second.
+    pub a: Point2D<T>,
+    /// The point on the second shape closest to the first.
+    pub b: Point2D<T>,
+}
+
+/// Returns the distance and closest points between two convex shapes using the GJK
+/// algorithm, or [`None`] if they overlap. Shapes that only touch are at a distance of zero.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let circle = Circle2D::new((0.0, 0.0), 1.0f64);
+/// let bounds = bounds!(3.0, -1.0, 2.0, 2.0);
+///
+/// let separation = gjk_distance(&circle, &bounds).unwrap();
+///
+/// assert!((separation.distance - 2.0).abs() < 1e-9);
+/// assert!(separation.a.euclidean_distance((1.0, 0.0)) < 1e-6);
+/// assert!(separation.b.euclidean_distance((3.0, 0.0)) < 1e-6);
+///
+/// assert!(gjk_distance(&circle, &bounds!(0.5, 0.5, 2.0, 2.0)).is_none());
+/// ```
+pub fn gjk_distance<T, A, B>(a: &A, b: &B) -> Option<Separation<T>>
+where
+    T: Float,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(separation) => Some(separation),
+        Gjk::Overlapping(_) => None,
+    }
+}
+
+/// Returns how far two convex shapes overlap using the EPA algorithm,
+/// or [`None`] if they do not overlap. Shapes that only touch do not overlap.
+///
+/// Like with [`separating_axis_test()`](crate::separating_axis_test), moving `b`
+/// by [`Penetration::translation()`] separates the shapes.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let circle = Circle2D::new((0.0, 0.0), 1.0f64);
+/// let bounds = bounds!(0.5, -1.0, 2.0, 2.0);
+///
+/// let penetration = epa_penetration(&circle, &bounds).unwrap();
+///
+/// assert!(penetration.normal.euclidean_distance((1.0, 0.0)) < 1e-6);
+/// assert!((penetration.depth - 0.5).abs() < 1e-6);
+/// ```
+pub fn epa_penetration<T, A, B>(a: &A, b: &B) -> Option<Penetration<T>>
+where
+    T: Float,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    match gjk(a, b) {
+        Gjk::Separated(_) => None,
+        Gjk::Overlapping(polygon) => epa(a, b, polygon),
+    }
+}
+
+/// A point of the Minkowski difference `a - b`, along with the points of the shapes it came from.
+#[derive(Debug, Clone, Copy)]
+struct Vertex<T> {
+    point: Offset2D<T>,
+    a: Point2D<T>,
+    b: Point2D<T>,
+}
+
+enum Gjk<T> {
+    Separated(Separation<T>),
+    /// The shapes overlap, and the polygon of vertices is around the origin.
+    Overlapping(Vec<Vertex<T>>),
+}
+
+/// Returns the point of the Minkowski difference `a - b` furthest along `direction`.
+fn support<T, A, B>(a: &A, b: &B, direction: Offset2D<T>) -> Vertex<T>
+where
+    T: Float,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let (a, b) = (a.support(direction), b.support(direction * -T::one()));
+
+    Vertex {
+        point: b.offset(a),
+        a,
+        b,
+    }
+}
+
+/// Finds the point of the Minkowski difference `a - b` closest to the origin,
+/// which is the offset between the closest points of the shapes.
+fn gjk<T, A, B>(a: &A, b: &B) -> Gjk<T>
+where
+    T: Float,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let tolerance = tolerance::<T>();
+    let mut simplex = vec![support(a, b, Offset2D::new(T::one(), T::zero()))];
+    let mut separation = None;
+
+    for _ in 0..MAX_ITERATIONS {
+        let Some(weights) = closest_to_origin(&mut simplex) else {
+            return Gjk::Overlapping(counter_clockwise(simplex));
+        };
+
+        let closest = Separation {
+            distance: T::zero(),
+            a: weighted(simplex.iter().map(|vertex| vertex.a), &weights),
+            b: weighted(simplex.iter().map(|vertex| vertex.b), &weights),
+        };
+
+        let offset = closest.b.offset(closest.a);
+        let length = offset.length_squared();
+
+        if length == T::zero() {
+            return touching(a, b, simplex, closest);
+        }
+
+        let next = support(a, b, offset * -T::one());
+        separation = Some(Separation {
+            distance: length.sqrt(),
+            ..closest
+        });
+
+        // Nothing is closer to the origin than the current point
+        if length - next.point.dot(offset) <= tolerance * length {
+            break;
+        }
+
+        simplex.push(next);
+    }
+
+    Gjk::Separated(separation.expect("There must be a closest point"))
+}
+
+/// Decides whether shapes with the origin on the edge of their Minkowski difference only touch,
+/// returning the polygon around the origin if they overlap after all.
+fn touching<T, A, B>(a: &A, b: &B, simplex: Vec<Vertex<T>>, closest: Separation<T>) -> Gjk<T>
+where
+    T: Float,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    // Every vertex is the furthest point in some direction,
+    // so the origin being a vertex puts it on the edge
+    let [start, end] = simplex[..] else {
+        return Gjk::Separated(closest);
+    };
+
+    // The origin is on a line through the difference,
+    // so it is inside if there is more on both sides of the line
+    let normal = (end.point - start.point).perpendicular();
+    let sides = [normal, normal * -T::one()].map(|normal| support(a, b, normal));
+
+    if sides
+        .iter()
+        .zip([normal, normal * -T::one()])
+        .any(|(side, normal)| side.point.dot(normal) <= T::zero())
+    {
+        return Gjk::Separated(closest);
+    }
+
+    Gjk::Overlapping(vec![start, sides[1], end, sides[0]])
+}
+
+/// Reduces `simplex` to the vertices needed for its point closest to the origin,
+/// returning the weight of each vertex in that point, or [`None`] if it contains the origin.
+fn closest_to_origin<T: Float>(simplex: &mut Vec<Vertex<T>>) -> Option<Vec<T>> {
+    match simplex[..] {
+        [_] => Some(vec![T::one()]),
+        [start, end] => {
+            let direction = end.point - start.point;
+            let length = direction.length_squared();
+
+            let t = if length > T::zero() {
+                (start.point.dot(direction) * -T::one() / length)
+                    .max(T::zero())
+                    .min(T::one())
+            } else {
+                T::zero()
+            };
+
+            if t == T::zero() {
+                simplex.truncate(1);
+                Some(vec![T::one()])
+            } else if t == T::one() {
+                simplex.remove(0);
+                Some(vec![T::one()])
+            } else {
+                Some(vec![T::one() - t, t])
+            }
+        }
+        [first, second, third] => {
+            let points = [first.point, second.point, third.point];
+            let area = |a: Offset2D<T>, b: Offset2D<T>| a.cross(b);
+
+            let whole = area(points[1] - points[0], points[2] - points[0]);
+            let sides = [0, 1, 2].map(|i| area(points[i], points[(i + 1) % 3]));
+
+            // The origin is strictly inside when it is on the same side of every edge
+            if whole != T::zero() && sides.iter().all(|&side| side * whole > T::zero()) {
+                return None;
+            }
+
+            let (edge, weights) = (0..3)
+                .map(|i| {
+                    let mut edge = vec![simplex[i], simplex[(i + 1) % 3]];
+                    let weights = closest_to_origin(&mut edge).expect("An edge has no inside");
+                    (edge, weights)
+                })
+                .min_by(|(a, a_weights), (b, b_weights)| {
+                    let length = |edge: &[Vertex<T>], weights: &[T]| {
+                        weighted_offset(edge.iter().map(|vertex| vertex.point), weights)
+                            .length_squared()
+                    };
+
+                    length(a, a_weights)
+                        .partial_cmp(&length(b, b_weights))
+                        .unwrap_or(Ordering::Equal)
+                })
+                .expect("A triangle has edges");
+
+            *simplex = edge;
+            Some(weights)
+        }
+        _ => unreachable!("A simplex in two dimensions has at most three vertices"),
+    }
+}
+
+/// Expands the polygon around the origin towards the edge of the Minkowski difference,
+/// until the edge closest to the origin is found.
+fn epa<T, A, B>(a: &A, b: &B, mut polygon: Vec<Vertex<T>>) -> Option<Penetration<T>>
+where
+    T: Float,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let tolerance = tolerance::<T>();
+    let mut closest = None;
+
+    for _ in 0..MAX_ITERATIONS {
+        let len = polygon.len();
+
+        // The polygon goes around counter-clockwise, so the outside of every edge is on its right
+        let Some((index, normal, distance)) = (0..len)
+            .filter_map(|i| {
+                let (start, end) = (polygon[i].point, polygon[(i + 1) % len].point);
+                let normal = (end - start).perpendicular().try_normalize()? * -T::one();
+
+                Some((i, normal, start.dot(normal)))
+            })
+            .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap_or(Ordering::Equal))
+        else {
+            break;
+        };
+
+        closest = Some(Penetration {
+            normal,
+            depth: distance,
+        });
+
+        let next = support(a, b, normal);
+
+        if next.point.dot(normal) - distance <= tolerance * distance {
+            break;
+        }
+
+        polygon.insert(index + 1, next);
+    }
+
+    closest.filter(|penetration| penetration.depth > T::zero())
+}
+
+/// Returns how close the answer needs to be before stopping, relative to its size.
+fn tolerance<T: Float>() -> T {
+    let two = T::one() + T::one();
+    T::epsilon() * two.powi(10)
+}
+
+/// Orders the vertices of a triangle to go around counter-clockwise.
+fn counter_clockwise<T: Float>(mut triangle: Vec<Vertex<T>>) -> Vec<Vertex<T>> {
+    let [a, b, c] = [0, 1, 2].map(|i| triangle[i].point);
+
+    if (b - a).cross(c - a) < T::zero() {
+        triangle.swap(1, 2);
+    }
+
+    triangle
+}
+
+fn weighted<T, I>(points: I, weights: &[T]) -> Point2D<T>
+where
+    T: Float,
+    I: Iterator<Item = Point2D<T>>,
+{
+    let (x, y) = points
+        .zip(weights)
+        .fold((T::zero(), T::zero()), |(x, y), (point, &weight)| {
+            (x + point.x * weight, y + point.y * weight)
+        });
+
+    Point2D::new(x, y)
+}
+
+fn weighted_offset<T, I>(offsets: I, weights: &[T]) -> Offset2D<T>
+where
+    T: Float,
+    I: Iterator<Item = Offset2D<T>>,
+{
+    let (x, y) = offsets
+        .zip(weights)
+        .fold((T::zero(), T::zero()), |(x, y), (offset, &weight)| {
+            (x + offset.x * weight, y + offset.y * weight)
+        });
+
+    Offset2D::new(x, y)
+}
+
+/// Returns the point of `points` furthest along `direction`.
+fn furthest<T, I>(points: I, direction: Offset2D<T>) -> Point2D<T>
+where
+    T: Float,
+    I: IntoIterator<Item = Point2D<T>>,
+{
+    let along = |point: &Point2D<T>| point.x * direction.x + point.y * direction.y;
+
+    points
+        .into_iter()
+        .max_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal))
+        .unwrap_or(Point2D::new(T::zero(), T::zero()))
+}
+
+/// Returns the penetration of two convex shapes that have no closed form for it.
+fn convex<T, A, B>(a: &A, b: &B) -> Option<Penetration<T>>
+where
+    T: Float,
+    A: Overlaps<B> + SupportMap<T>,
+    B: SupportMap<T>,
+{
+    if a.overlaps(b) {
+        epa_penetration(a, b)
+    } else {
+        None
+    }
+}
+
+impl<T, S> SupportMap<T> for &S
+where
+    S: SupportMap<T> + ?Sized,
+{
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        (**self).support(direction)
+    }
+}
+
+impl<T, A, B> SupportMap<T> for MinkowskiSum<A, B>
+where
+    T: Float,
+    A: SupportMap<T>,
+    B: SupportMap<T>,
+{
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        let (a, b) = (self.a.support(direction), self.b.support(direction));
+        Point2D::new(a.x + b.x, a.y + b.y)
+    }
+}
+
+impl<T: Float> SupportMap<T> for Bounds2D<T> {
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        Point2D::new(
+            if direction.x < T::zero() {
+                self.left()
+            } else {
+                self.right()
+            },
+            if direction.y < T::zero() {
+                self.top()
+            } else {
+                self.bottom()
+            },
+        )
+    }
+}
+
+/// The polygon is expected to be convex, otherwise its dents are filled in.
+impl<T: Float> SupportMap<T> for Polygon2D<T> {
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        furthest(self.vertices().iter().copied(), direction)
+    }
+}
+
+impl<T: Float + FloatConst> SupportMap<T> for RotatedRect2D<T> {
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        furthest(self.corners(), direction)
+    }
+}
+
+impl<T: Float + FloatConst> SupportMap<T> for Circle2D<T> {
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        match direction.try_normalize() {
+            Some(direction) => self.center + direction * self.radius,
+            None => self.center,
+        }
+    }
+}
+
+impl<T: Float + FloatConst> SupportMap<T> for Ellipse2D<T> {
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        let (x, y) = (
+            direction.x * self.radii.width * self.radii.width,
+            direction.y * self.radii.height * self.radii.height,
+        );
+
+        // The point where the normal of the edge is `direction`
+        let scale = (direction.x * x + direction.y * y).sqrt();
+
+        if scale > T::zero() {
+            Point2D::new(self.center.x + x / scale, self.center.y + y / scale)
+        } else {
+            self.center
+        }
+    }
+}
+
+impl<T: Float + FloatConst> SupportMap<T> for Capsule2D<T> {
+    fn support(&self, direction: Offset2D<T>) -> Point2D<T> {
+        let end = furthest([self.segment.start, self.segment.end], direction);
+
+        match direction.try_normalize() {
+            Some(direction) => end + direction * self.radius,
+            None => end,
+        }
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T> for Ellipse2D<T> {
+    fn penetration(&self, other: &Self) -> Option<Penetration<T>> {
+        if let Some(capsule) = self.flattened() {
+            return capsule.penetration(other);
+        }
+
+        if let Some(capsule) = other.flattened() {
+            return self.penetration(&capsule);
+        }
+
+        convex(self, other)
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Bounds2D<T>> for Ellipse2D<T> {
+    fn penetration(&self, other: &Bounds2D<T>) -> Option<Penetration<T>> {
+        match self.flattened() {
+            Some(capsule) => capsule.penetration(other),
+            None => convex(self, other),
+        }
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Capsule2D<T>> for Ellipse2D<T> {
+    fn penetration(&self, other: &Capsule2D<T>) -> Option<Penetration<T>> {
+        match self.flattened() {
+            Some(capsule) => capsule.penetration(other),
+            None => convex(self, other),
+        }
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Ellipse2D<T>> for Bounds2D<T> {
+    fn penetration(&self, other: &Ellipse2D<T>) -> Option<Penetration<T>> {
+        other.penetration(self).map(Penetration::reverse)
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Ellipse2D<T>> for Capsule2D<T> {
+    fn penetration(&self, other: &Ellipse2D<T>) -> Option<Penetration<T>> {
+        other.penetration(self).map(Penetration::reverse)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    fn random() -> impl FnMut(f64) -> f64 {
+        let mut random = Random::new(25);
+        move |max| random.float(max)
+    }
+
+    #[test]
+    fn matches_polygons() {
+        let mut random = random();
+
+        for _ in 0..500 {
+            let a = convex_hull((0..6).map(|_| (random(10.0), random(10.0))));
+            let b = convex_hull((0..6).map(|_| (random(10.0) + 6.0, random(10.0))));
+
+            match separating_axis_test(&a, &b) {
+                Some(expected) => {
+                    let penetration = epa_penetration(&a, &b).unwrap();
+
+                    assert!(gjk_distance(&a, &b).is_none());
+                    assert!((penetration.depth - expected.depth).abs() < 1e-6);
+                }
+                None => {
+                    let separation = gjk_distance(&a, &b).unwrap();
+                    let expected = a
+                        .edges()
+                        .flat_map(|a| b.edges().map(move |b| a.closest_points(b)))
+                        .map(|(a, b)| a.euclidean_distance(b))
+                        .fold(f64::INFINITY, f64::min);
+
+                    assert!(epa_penetration(&a, &b).is_none());
+                    assert!((separation.distance - expected).abs() < 1e-6);
+                    assert!(
+                        (separation.a.euclidean_distance(separation.b) - expected).abs() < 1e-6
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn matches_circles() {
+        let mut random = random();
+
+        for _ in 0..500 {
+            let a = Circle2D::new((random(10.0), random(10.0)), random(3.0) + 0.5);
+            let b = Circle2D::new((random(10.0), random(10.0)), random(3.0) + 0.5);
+
+            match a.penetration(&b) {
+                Some(expected) => {
+                    let penetration = epa_penetration(&a, &b).unwrap();
+
+                    assert!((penetration.depth - expected.depth).abs() < 1e-6);
+                    assert!(penetration.normal.euclidean_distance(expected.normal) < 1e-6);
+                }
+                None => {
+                    let expected = a.center.euclidean_distance(b.center) - a.radius - b.radius;
+                    let separation = gjk_distance(&a, &b).unwrap();
+
+                    assert!((separation.distance - expected).abs() < 1e-6);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn mixed_shapes() {
+        let bounds = bounds!(0.0, 0.0, 10.0, 10.0);
+        let rounded = MinkowskiSum::new(bounds, Circle2D::new((0.0, 0.0), 2.0));
+
+        let capsule = Capsule2D::new((15.0, 15.0), (20.0, 20.0), 1.0);
+        let distance = gjk_distance(&rounded, &capsule).unwrap().distance;
+
+        assert!((distance - (50.0f64.sqrt() - 3.0)).abs() < 1e-6);
+
+        let ellipse = Ellipse2D::new((14.0, 5.0), size!(3.0, 1.0));
+        let penetration = epa_penetration(&rounded, &ellipse).unwrap();
+
+        assert!(penetration.normal.euclidean_distance((1.0, 0.0)) < 1e-6);
+        assert!((penetration.depth - 1.0).abs() < 1e-6);
+
+        let rect = RotatedRect2D::new((5.0, 16.0), size!(2.0, 2.0), Angle::degrees(45.0));
+        let distance = gjk_distance(&bounds, &rect).unwrap().distance;
+
+        assert!((distance - (6.0 - 2.0f64.sqrt())).abs() < 1e-6);
+    }
+
+    #[test]
+    fn touching_and_identical() {
+        let a = bounds!(0.0, 0.0, 2.0, 2.0);
+        let b = bounds!(2.0, 1.0, 2.0, 2.0);
+
+        assert_eq!(gjk_distance(&a, &b).unwrap().distance, 0.0);
+        assert!(epa_penetration(&a, &b).is_none());
+
+        // The difference of two equal squares is centered on the origin
+        let penetration = epa_penetration(&a, &a).unwrap();
+        assert_eq!(penetration.depth, 2.0);
+    }
+
+    #[test]
+    fn points_and_segments() {
+        let bounds = bounds!(0.0, 0.0, 2.0, 2.0);
+
+        let point = Circle2D::new((5.0, 5.0), 0.0);
+        let distance = gjk_distance(&bounds, &point).unwrap().distance;
+        assert!((distance - 18.0f64.sqrt()).abs() < 1e-9);
+
+        let segment = Capsule2D::new((3.0, -1.0), (3.0, 4.0), 0.0);
+        let separation = gjk_distance(&bounds, &segment).unwrap();
+        assert!((separation.distance - 1.0).abs() < 1e-9);
+        assert!((separation.b.x - 3.0).abs() < 1e-9);
+
+        let inside = Circle2D::new((1.0, 0.5), 0.0);
+        let penetration = epa_penetration(&bounds, &inside).unwrap();
+
+        assert!(gjk_distance(&bounds, &inside).is_none());
+        assert!(penetration.normal.euclidean_distance((0.0, -1.0)) < 1e-9);
+        assert!((penetration.depth - 0.5).abs() < 1e-9);
+    }
+
+    /// Returns how far `a` and `b` overlap along `normal`.
+    fn depth_along<A, B>(a: &A, b: &B, normal: Offset2D<f64>) -> f64
+    where
+        A: SupportMap<f64>,
+        B: SupportMap<f64>,
+    {
+        let along = |point: Point2D<f64>| point.x * normal.x + point.y * normal.y;
+        along(a.support(normal)) - along(b.support(normal * -1.0))
+    }
+
+    /// Checks the penetration of `a` with the shape `b` returns when not moved.
+    fn check_separates<A, B, F>(a: &A, b: F)
+    where
+        A: Penetrates<f64, B> + SupportMap<f64>,
+        B: SupportMap<f64>,
+        F: Fn(Offset2D<f64>) -> B,
+    {
+        let moved = b;
+        let b = &moved(offset!(0.0, 0.0));
+        let penetration = a.penetration(b).unwrap();
+
+        assert!(a.overlaps(b));
+        assert!((depth_along(a, b, penetration.normal) - penetration.depth).abs() < 1e-6);
+
+        // No other direction separates the shapes sooner
+        for step in 0..3600 {
+            let (sin, cos) = Angle::degrees(step as f64 / 10.0).sin_cos();
+            let depth = depth_along(a, b, offset!(cos, sin));
+
+            assert!(depth > penetration.depth - 1e-6, "{penetration:?} {step}");
+        }
+
+        let moved = moved(penetration.translation() + penetration.normal * 1e-6);
+        assert!(!a.overlaps(&moved));
+    }
+
+    #[test]
+    fn ellipse_and_capsule() {
+        let ellipse = Ellipse2D::new((0.0, 0.0), size!(6.0, 2.0));
+
+        // Without a radius, a segment only overlaps if it goes inside
+        assert!(ellipse.overlaps(&Capsule2D::new((-10.0, 1.0), (10.0, 1.0), 0.0)));
+        assert!(ellipse.overlaps(&Capsule2D::new((-1.0, -1.0), (1.0, 1.0), 0.0)));
+        assert!(ellipse.overlaps(&Capsule2D::new((5.0, -9.0), (5.0, 9.0), 0.0)));
+        assert!(!ellipse.overlaps(&Capsule2D::new((-10.0, 2.0), (10.0, 2.0), 0.0)));
+        assert!(!ellipse.overlaps(&Capsule2D::new((-10.0, 3.0), (10.0, 3.0), 0.0)));
+
+        assert!(ellipse.overlaps(&Capsule2D::new((-10.0, 2.0), (10.0, 2.0), 0.5)));
+        assert!(ellipse.overlaps(&Capsule2D::new((-10.0, 3.0), (10.0, 3.0), 1.5)));
+        assert!(!ellipse.overlaps(&Capsule2D::new((-10.0, 3.0), (10.0, 3.0), 0.5)));
+        assert!(ellipse.overlaps(&Capsule2D::new((8.0, 0.0), (9.0, 0.0), 2.5)));
+        assert!(!ellipse.overlaps(&Capsule2D::new((8.0, 0.0), (9.0, 0.0), 1.5)));
+    }
+
+    #[test]
+    fn ellipse_penetration() {
+        let ellipse = Ellipse2D::new((0.0, 0.0), size!(6.0, 2.0));
+
+        let capsule = |start: Point2D<f64>, end: Point2D<f64>| {
+            move |offset| Capsule2D::new(start + offset, end + offset, 1.0)
+        };
+
+        check_separates(&ellipse, |offset| bounds!(4.0, -1.0, 5.0, 5.0) + offset);
+        check_separates(&ellipse, |offset| bounds!(-1.0, 1.0, 2.0, 4.0) + offset);
+        check_separates(&ellipse, |offset| bounds!(-2.0, -1.0, 1.0, 1.0) + offset);
+
+        check_separates(&ellipse, capsule(point!(5.0, -4.0), point!(7.0, 4.0)));
+        check_separates(&ellipse, capsule(point!(-8.0, 2.5), point!(8.0, 2.5)));
+
+        check_separates(&ellipse, |offset| {
+            Ellipse2D::new(point!(7.0, 1.0) + offset, size!(2.0, 3.0))
+        });
+        check_separates(&ellipse, |offset| {
+            Ellipse2D::new(point!(1.0, 1.0) + offset, size!(1.0, 1.0))
+        });
+
+        assert!(ellipse.penetration(&bounds!(6.0, 0.0, 2.0, 2.0)).is_none());
+        assert!(ellipse
+            .penetration(&Ellipse2D::new((9.0, 0.0), size!(3.0, 1.0)))
+            .is_none());
+
+        // The reversed pairs find the same penetration from the other side
+        let bounds = bounds!(4.0, -1.0, 5.0, 5.0);
+        let forward = ellipse.penetration(&bounds).unwrap();
+        let reverse = bounds.penetration(&ellipse).unwrap();
+
+        assert_eq!(reverse.depth, forward.depth);
+        assert_eq!(reverse.normal, forward.normal * -1.0);
+    }
+
+    #[test]
+    fn flat_ellipse_penetration() {
+        let ellipse = Ellipse2D::new((0.0, 0.0), size!(0.0f64, 5.0));
+        let penetration = ellipse.penetration(&bounds!(-1.0, -1.0, 2.0, 2.0)).unwrap();
+
+        assert!(!penetration.depth.is_nan());
+    }
+}
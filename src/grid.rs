@@ -1,9 +1,11 @@
 use std::{
     fmt::{Debug, Display},
-    ops::{IndexMut, Range},
+    ops::{IndexMut, Range, Sub},
 };
 
-use crate::{IntoBounds2D, Size2D, ToPoint2D};
+use num_traits::AsPrimitive;
+
+use crate::{IntoBounds2D, Metric, Point2D, Size2D, ToPoint2D};
 
 fn index_at<P: ToPoint2D<usize>>(point: P, grid_width: usize, chunk_size: usize) -> usize {
     let (x, y) = point.to_vector().into();
@@ -196,6 +198,76 @@ where
     pub fn values(&self) -> &[T::Item] {
         self.arr.values()
     }
+
+    /// Returns the position of every cell within `radius` of `position`, as measured by `metric`.
+    /// This includes the cell at `position` itself.
+    ///
+    /// The radius can be of any type the cell positions can be cast to,
+    /// which allows for using float metrics like [`Euclidean`](crate::Euclidean).
+    pub fn cells_in_range<P, D, M>(
+        &self,
+        position: P,
+        radius: D,
+        metric: M,
+    ) -> impl Iterator<Item = Point2D<usize>>
+    where
+        P: ToPoint2D<usize>,
+        D: Copy + PartialOrd + Sub<Output = D> + AsPrimitive<usize>,
+        usize: AsPrimitive<D>,
+        M: Metric<D>,
+    {
+        let center = position.to_vector();
+        let size = self.size();
+
+        // No cell further away than the radius on a single axis can be in range,
+        // so only the square around the center has to be checked.
+        let reach: usize = radius.as_();
+
+        let start_x = center.x.saturating_sub(reach);
+        let start_y = center.y.saturating_sub(reach);
+        let end_x = center
+            .x
+            .saturating_add(reach)
+            .saturating_add(1)
+            .min(size.width);
+        let end_y = center
+            .y
+            .saturating_add(reach)
+            .saturating_add(1)
+            .min(size.height);
+
+        let center_cast: Point2D<D> = center.cast();
+
+        (start_y..end_y)
+            .flat_map(move |y| (start_x..end_x).map(move |x| Point2D::new(x, y)))
+            .filter(move |cell| {
+                let cell: Point2D<D> = cell.cast();
+                center_cast.distance_with(&metric, cell) <= radius
+            })
+    }
+
+    /// Returns the position of every cell within `radius` of `position`, as measured by `metric`,
+    /// excluding the cell at `position` itself.
+    ///
+    /// For example, a [`Chebyshev`](crate::Chebyshev) radius of `1` is the Moore neighbourhood,
+    /// and a [`Manhattan`](crate::Manhattan) radius of `1` is the von Neumann neighbourhood.
+    pub fn neighbours<P, D, M>(
+        &self,
+        position: P,
+        radius: D,
+        metric: M,
+    ) -> impl Iterator<Item = Point2D<usize>>
+    where
+        P: ToPoint2D<usize>,
+        D: Copy + PartialOrd + Sub<Output = D> + AsPrimitive<usize>,
+        usize: AsPrimitive<D>,
+        M: Metric<D>,
+    {
+        let center = position.to_vector();
+
+        self.cells_in_range(center, radius, metric)
+            .filter(move |cell| *cell != center)
+    }
 }
 
 impl<T> Display for Grid2D<T>
@@ -211,12 +283,13 @@ where
             .chunks_exact(self.chunk_size)
             .map(|chunk| {
                 chunk.iter().fold(String::new(), |acc, s| {
-                    (!acc.is_empty()).then(|| acc.clone() + ", ").unwrap_or(acc) + &s.to_string()
+                    let acc = if acc.is_empty() { acc } else { acc + ", " };
+                    acc + &s.to_string()
                 }) + " | "
             })
             .collect();
 
-        let rows: String = (&values)
+        let rows: String = values
             .chunks_exact(self.width)
             .map(|chunk| format!("| {}\n", chunk.iter().fold(String::new(), |acc, s| acc + s)))
             .collect();
@@ -238,7 +311,7 @@ where
     T::Item: Display,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        <Grid2D<T> as Display>::fmt(&self, f)
+        <Grid2D<T> as Display>::fmt(self, f)
     }
 }
 
@@ -299,6 +372,39 @@ mod test {
         assert_eq!(grid.values(), expected_result);
     }
 
+    #[test]
+    fn neighbours() {
+        let grid = Grid2D::new([0; 5 * 5], 5, 1);
+
+        let moore: Vec<_> = grid.neighbours((2, 2), 1, Chebyshev).collect();
+        let von_neumann: Vec<_> = grid.neighbours((2, 2), 1, Manhattan).collect();
+
+        assert_eq!(moore.len(), 8);
+        assert_eq!(
+            von_neumann,
+            [point!(2, 1), point!(1, 2), point!(3, 2), point!(2, 3)]
+        );
+
+        // Cells outside of the grid are never included
+        let corner: Vec<_> = grid.neighbours((0, 0), 1, Chebyshev).collect();
+        assert_eq!(corner, [point!(1, 0), point!(0, 1), point!(1, 1)]);
+    }
+
+    #[test]
+    fn cells_in_range() {
+        let grid = Grid2D::new([0; 7 * 7], 7, 1);
+
+        let circle = grid.cells_in_range((3, 3), 2.0, Euclidean);
+        let squared = grid.cells_in_range((3, 3), 4, SquaredEuclidean);
+
+        assert_eq!(circle.count(), 13);
+        assert_eq!(squared.count(), 13);
+
+        // A grid without any rows has no cells in range
+        let empty = Grid2D::new(Vec::<i32>::new(), 7, 1);
+        assert_eq!(empty.cells_in_range((0, 0), 2, Chebyshev).count(), 0);
+    }
+
     #[test]
     fn insert() {
         let mut grid = Grid2D::new([0; { 3 * 3 * 2 }], 3, 2);
@@ -0,0 +1,200 @@
+use std::cmp::Ordering;
+
+use num_traits::{Num, Zero};
+
+use crate::{widen::wide_mul, Point2D, Polygon2D, ToPoint2D, Widen};
+
+/// Returns the smallest convex polygon containing every point in `points`,
+/// using Andrew's monotone chain algorithm.
+///
+/// The hull goes around [`Winding::CounterClockwise`](crate::Winding::CounterClockwise),
+/// starting at the lowest point on the X axis. Points on the edges of the hull and duplicate
+/// points are left out, so points in a line give a hull of just its two ends.
+///
+/// Cross products are found in the [wider type](Widen) of `T`, so the hull is exact
+/// for integers as long as the difference between any two coordinates fits in `T`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let points = [(0, 0), (5, 5), (10, 0), (5, 0), (10, 10), (0, 10), (0, 0)];
+///
+/// assert_eq!(convex_hull(points), polygon![(0, 0), (10, 0), (10, 10), (0, 10)]);
+/// ```
+pub fn convex_hull<T, I, P>(points: I) -> Polygon2D<T>
+where
+    T: Num + Copy + PartialOrd + Widen,
+    I: IntoIterator<Item = P>,
+    P: ToPoint2D<T>,
+{
+    let mut points: Vec<Point2D<T>> = points.into_iter().map(|point| point.to_vector()).collect();
+
+    points.sort_by(|a, b| {
+        a.x.partial_cmp(&b.x)
+            .and_then(|ordering| Some(ordering.then(a.y.partial_cmp(&b.y)?)))
+            .unwrap_or(Ordering::Equal)
+    });
+    points.dedup();
+
+    if points.len() < 3 {
+        return Polygon2D::new(points);
+    }
+
+    // The lower half is built going right, and the upper half going back left
+    let mut hull: Vec<Point2D<T>> = Vec::with_capacity(points.len() + 1);
+
+    for &point in &points {
+        while hull.len() >= 2 && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], point) {
+            hull.pop();
+        }
+
+        hull.push(point);
+    }
+
+    let lower = hull.len() + 1;
+
+    for &point in points.iter().rev().skip(1) {
+        while hull.len() >= lower && !turns_left(hull[hull.len() - 2], hull[hull.len() - 1], point)
+        {
+            hull.pop();
+        }
+
+        hull.push(point);
+    }
+
+    // The first point was added again to close the upper half
+    hull.pop();
+
+    Polygon2D::new(hull)
+}
+
+/// Returns `true` if `a`, `b` and `c` go around counter-clockwise.
+fn turns_left<T>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) -> bool
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    orientation(a, b, c) == Ordering::Greater
+}
+
+/// Returns [`Ordering::Greater`] if `a`, `b` and `c` go around counter-clockwise,
+/// [`Ordering::Less`] if they go around clockwise, and [`Ordering::Equal`] if they are in a line.
+///
+/// This is the sign of the cross product `(b - a) × (c - a)`, which is exact for integers
+/// as long as the differences between the coordinates fit in `T`.
+pub(crate) fn orientation<T>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>) -> Ordering
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    // Differences are kept as a size and a sign, so unsigned integers work too,
+    // and their products are found in the wider type so that they cannot overflow
+    let (x1, x1_negative) = difference(a.x, b.x);
+    let (y1, y1_negative) = difference(a.y, b.y);
+    let (x2, x2_negative) = difference(a.x, c.x);
+    let (y2, y2_negative) = difference(a.y, c.y);
+
+    let (left, right) = (wide_mul(x1, y2), wide_mul(y1, x2));
+    let both_zero = left.is_zero() && right.is_zero();
+
+    match (x1_negative != y2_negative, y1_negative != x2_negative) {
+        (false, false) => left.partial_cmp(&right).unwrap_or(Ordering::Equal),
+        (true, true) => right.partial_cmp(&left).unwrap_or(Ordering::Equal),
+        (false, true) if both_zero => Ordering::Equal,
+        (false, true) => Ordering::Greater,
+        (true, false) if both_zero => Ordering::Equal,
+        (true, false) => Ordering::Less,
+    }
+}
+
+/// Returns the size of `to - from`, and whether it is negative.
+pub(crate) fn difference<T>(from: T, to: T) -> (T, bool)
+where
+    T: Num + Copy + PartialOrd,
+{
+    if to >= from {
+        (to - from, false)
+    } else {
+        (from - to, true)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn convex_hull_of_lines() {
+        assert_eq!(
+            convex_hull([(3u32, 3), (1, 1), (2, 2), (1, 1)]),
+            polygon![(1, 1), (3, 3)]
+        );
+        assert_eq!(
+            convex_hull([
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 2.0),
+                (1.0, 1.0),
+                (0.0, 2.0)
+            ]),
+            polygon![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]
+        );
+    }
+
+    #[test]
+    fn convex_hull_far_from_origin() {
+        let offset = 1e8;
+        let square = [
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+            (0.5, 0.5),
+            (0.5, 0.0),
+        ];
+
+        assert_eq!(
+            convex_hull(square.map(|(x, y)| (x + offset, y + offset))),
+            polygon![
+                (offset, offset),
+                (offset + 1.0, offset),
+                (offset + 1.0, offset + 1.0),
+                (offset, offset + 1.0)
+            ]
+        );
+
+        let offset = 4_000_000_000u32;
+
+        assert_eq!(
+            convex_hull([(0, 0), (2, 0), (2, 2), (0, 2), (1, 1)].map(|(x, y)| (x + offset, y))),
+            polygon![(offset, 0), (offset + 2, 0), (offset + 2, 2), (offset, 2)]
+        );
+    }
+
+    #[test]
+    fn convex_hull_of_large_integers() {
+        // The cross products of these are far beyond `i32`
+        let far = 1_000_000_000;
+        let points = [
+            (-far, -far),
+            (far, -far),
+            (far + 1, 0),
+            (far, far),
+            (-far, far),
+            (-far, 0),
+            (0, far),
+            (-far + 1, far - 1),
+        ];
+
+        assert_eq!(
+            convex_hull(points),
+            polygon![
+                (-far, -far),
+                (far, -far),
+                (far + 1, 0),
+                (far, far),
+                (-far, far)
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    ops::{Add, Sub},
+};
+
+use num_traits::Num;
+
+use crate::UnknownUnit;
+
+/// The distances from each edge of a rectangle, like padding or margins.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let bounds = bounds!(0, 0, 100, 50);
+/// let padding = insets!(5, 10);
+///
+/// assert_eq!(bounds.inset(padding), bounds!(10, 5, 80, 40));
+/// assert_eq!(bounds.inset(padding).outset(padding), bounds);
+/// ```
+pub struct Insets2D<T, U = UnknownUnit> {
+    pub top: T,
+    pub right: T,
+    pub bottom: T,
+    pub left: T,
+
+    _unit: PhantomData<U>,
+}
+
+impl<T, U> Insets2D<T, U>
+where
+    T: Copy,
+{
+    /// Creates a new [Insets2D], in the same order as CSS, which is clockwise from the top.
+    /// In most cases you should use the `insets!()` macro instead.
+    pub fn new(top: T, right: T, bottom: T, left: T) -> Self {
+        Self {
+            top,
+            right,
+            bottom,
+            left,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns a new [Insets2D] where all edges are set to `value`.
+    pub fn uniform(value: T) -> Self {
+        Self::new(value, value, value, value)
+    }
+
+    /// Returns a new [Insets2D] where the top and bottom edges are set to `vertical`,
+    /// and the left and right edges are set to `horizontal`.
+    pub fn symmetric(vertical: T, horizontal: T) -> Self {
+        Self::new(vertical, horizontal, vertical, horizontal)
+    }
+
+    /// Reinterprets `self` as insets in another unit, keeping the components as they are.
+    ///
+    /// See [`VectorN::cast_unit()`](crate::VectorN::cast_unit) for more information.
+    pub fn cast_unit<V>(&self) -> Insets2D<T, V> {
+        Insets2D::new(self.top, self.right, self.bottom, self.left)
+    }
+
+    /// Returns the sum of the left and right edges.
+    pub fn horizontal(&self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.left + self.right
+    }
+
+    /// Returns the sum of the top and bottom edges.
+    pub fn vertical(&self) -> T
+    where
+        T: Add<Output = T>,
+    {
+        self.top + self.bottom
+    }
+}
+
+impl<T, U> Add for Insets2D<T, U>
+where
+    T: Num + Copy,
+{
+    type Output = Insets2D<T, U>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Insets2D::new(
+            self.top + rhs.top,
+            self.right + rhs.right,
+            self.bottom + rhs.bottom,
+            self.left + rhs.left,
+        )
+    }
+}
+
+impl<T, U> Sub for Insets2D<T, U>
+where
+    T: Num + Copy,
+{
+    type Output = Insets2D<T, U>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Insets2D::new(
+            self.top - rhs.top,
+            self.right - rhs.right,
+            self.bottom - rhs.bottom,
+            self.left - rhs.left,
+        )
+    }
+}
+
+impl<T, U> From<(T, T, T, T)> for Insets2D<T, U>
+where
+    T: Copy,
+{
+    fn from((top, right, bottom, left): (T, T, T, T)) -> Self {
+        Self::new(top, right, bottom, left)
+    }
+}
+
+// These are implemented by hand, so that the unit marker
+// is not required to implement the traits as well.
+impl<T, U> Clone for Insets2D<T, U>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            top: self.top.clone(),
+            right: self.right.clone(),
+            bottom: self.bottom.clone(),
+            left: self.left.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Copy for Insets2D<T, U> where T: Copy {}
+
+impl<T, U> PartialEq for Insets2D<T, U>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.top == other.top
+            && self.right == other.right
+            && self.bottom == other.bottom
+            && self.left == other.left
+    }
+}
+
+impl<T, U> Eq for Insets2D<T, U> where T: Eq {}
+
+impl<T, U> Hash for Insets2D<T, U>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.top.hash(state);
+        self.right.hash(state);
+        self.bottom.hash(state);
+        self.left.hash(state);
+    }
+}
+
+impl<T, U> Default for Insets2D<T, U>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            top: T::default(),
+            right: T::default(),
+            bottom: T::default(),
+            left: T::default(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Debug for Insets2D<T, U>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Insets2D")
+            .field("top", &self.top)
+            .field("right", &self.right)
+            .field("bottom", &self.bottom)
+            .field("left", &self.left)
+            .finish()
+    }
+}
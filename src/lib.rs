@@ -33,17 +33,65 @@
 #[macro_use]
 pub mod macros;
 
+mod align;
+mod angle;
+mod aspect;
 mod bounds;
+mod gjk;
 mod grid;
+mod hull;
+mod insets;
+mod line;
+mod metric;
+mod multipolygon;
 mod offset;
+mod order;
+mod packing;
 mod point;
+mod polygon;
+mod quadtree;
+#[cfg(test)]
+mod random;
+mod region;
+mod rtree;
+mod sat;
+mod shape;
 mod size;
+mod slots;
+mod spatial;
+mod sweep;
+mod transform;
+mod triangulate;
+mod unit;
 mod vector;
+mod widen;
 
+pub use crate::align::*;
+pub use crate::angle::*;
+pub use crate::aspect::*;
 pub use crate::bounds::*;
+pub use crate::gjk::*;
 pub use crate::grid::*;
-pub use crate::macros::*;
+pub use crate::hull::*;
+pub use crate::insets::*;
+pub use crate::line::*;
+pub use crate::metric::*;
+pub use crate::multipolygon::*;
 pub use crate::offset::*;
+pub use crate::order::*;
+pub use crate::packing::*;
 pub use crate::point::*;
+pub use crate::polygon::*;
+pub use crate::quadtree::*;
+pub use crate::region::*;
+pub use crate::rtree::*;
+pub use crate::sat::*;
+pub use crate::shape::*;
 pub use crate::size::*;
+pub use crate::spatial::*;
+pub use crate::sweep::*;
+pub use crate::transform::*;
+pub use crate::triangulate::*;
+pub use crate::unit::*;
 pub use crate::vector::*;
+pub use crate::widen::*;
@@ -0,0 +1,537 @@
+use num_traits::Float;
+
+use crate::{Bounds2D, IntoBounds2D, Offset2D, Point2D, ToOffset2D, ToPoint2D, Vector};
+
+/// Where a [Ray2D] or [Segment2D] enters and exits a [Bounds2D].
+///
+/// The parameters are multiples of the direction, so the entry point is `ray.at(hit.entry)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<T> {
+    /// When the bounds are entered. This is `0` if the ray starts inside.
+    pub entry: T,
+    /// When the bounds are exited.
+    pub exit: T,
+    /// The normal of the edge that was entered through,
+    /// or a zero offset if the ray starts inside.
+    pub normal: Offset2D<T>,
+}
+
+/// A half-infinite line, starting at `origin` and going on forever in `direction`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let ray = Ray2D::new((0.0, 5.0), (1.0, 0.0));
+/// let hit = ray.intersect_bounds(bounds!(10.0, 0.0, 10.0, 10.0)).unwrap();
+///
+/// assert_eq!(hit.entry, 10.0);
+/// assert_eq!(hit.exit, 20.0);
+/// assert_eq!(hit.normal, offset!(-1.0, 0.0));
+/// assert_eq!(ray.at(hit.entry), point!(10.0, 5.0));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Ray2D<T> {
+    pub origin: Point2D<T>,
+    pub direction: Offset2D<T>,
+}
+
+impl<T> Ray2D<T>
+where
+    T: Float,
+{
+    /// Creates a new [Ray2D] starting at `origin` going in `direction`.
+    ///
+    /// The direction does not have to be normalized,
+    /// but then the parameters are in multiples of its length.
+    pub fn new<P, O>(origin: P, direction: O) -> Self
+    where
+        P: ToPoint2D<T>,
+        O: ToOffset2D<T>,
+    {
+        Self {
+            origin: origin.to_vector(),
+            direction: direction.to_vector(),
+        }
+    }
+
+    /// Returns the point at `t` times the direction from the origin.
+    pub fn at(&self, t: T) -> Point2D<T> {
+        self.origin + self.direction * t
+    }
+
+    /// Returns where the ray enters and exits `bounds`, using the slab method.
+    ///
+    /// The edges of `bounds` are included, so a ray only grazing an edge still hits it.
+    pub fn intersect_bounds<B: IntoBounds2D<T>>(&self, bounds: B) -> Option<RayHit<T>> {
+        let hit = slabs(self.origin, self.direction, bounds.to_bounds())?;
+        (hit.exit >= T::zero()).then_some(hit)
+    }
+
+    /// Returns the point on the ray closest to `point`.
+    pub fn closest_point<P: ToPoint2D<T>>(&self, point: P) -> Point2D<T> {
+        let t = project(self.origin, self.direction, point.to_vector());
+        self.at(t.max(T::zero()))
+    }
+}
+
+/// A straight line between two points.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let a = Segment2D::new((0.0, 0.0), (10.0, 10.0));
+/// let b = Segment2D::new((0.0, 10.0), (10.0, 0.0));
+///
+/// assert_eq!(a.intersect(b), Some(SegmentIntersection::Point(point!(5.0, 5.0))));
+/// assert_eq!(a.closest_point((10.0, 0.0)), point!(5.0, 5.0));
+///
+/// let clipped = a.clip(bounds!(2.0, 0.0, 4.0, 10.0)).unwrap();
+/// assert_eq!(clipped, Segment2D::new((2.0, 2.0), (6.0, 6.0)));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Segment2D<T> {
+    pub start: Point2D<T>,
+    pub end: Point2D<T>,
+}
+
+/// Where two [Segment2D] meet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection<T> {
+    /// The segments cross or touch at a single point.
+    Point(Point2D<T>),
+    /// The segments lie on the same line and share this part of it.
+    Overlap(Segment2D<T>),
+}
+
+/// The algorithm used by [`Segment2D::clip_with()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClipMethod {
+    /// Clips the segment in a single pass, by solving where it crosses each edge.
+    #[default]
+    LiangBarsky,
+    /// Classifies the ends by which side of the bounds they are on,
+    /// and moves them onto the edges one at a time.
+    CohenSutherland,
+}
+
+impl<T> Segment2D<T>
+where
+    T: Float,
+{
+    /// Creates a new [Segment2D] from `start` to `end`.
+    pub fn new<P, Q>(start: P, end: Q) -> Self
+    where
+        P: ToPoint2D<T>,
+        Q: ToPoint2D<T>,
+    {
+        Self {
+            start: start.to_vector(),
+            end: end.to_vector(),
+        }
+    }
+
+    /// Returns the offset from the start to the end.
+    pub fn direction(&self) -> Offset2D<T> {
+        self.start.offset(self.end)
+    }
+
+    /// Returns the length of the segment.
+    pub fn length(&self) -> T {
+        self.direction().length()
+    }
+
+    /// Returns the point at `t` along the segment, where `0` is the start and `1` is the end.
+    pub fn at(&self, t: T) -> Point2D<T> {
+        self.start + self.direction() * t
+    }
+
+    /// Returns the segment going the other way.
+    pub fn reverse(&self) -> Self {
+        Self {
+            start: self.end,
+            end: self.start,
+        }
+    }
+
+    /// Returns where the segment enters and exits `bounds`.
+    ///
+    /// The parameters are along the segment, as in [`at()`](crate::Segment2D::at),
+    /// so an exit greater than `1` means the segment ends inside.
+    pub fn intersect_bounds<B: IntoBounds2D<T>>(&self, bounds: B) -> Option<RayHit<T>> {
+        let hit = slabs(self.start, self.direction(), bounds.to_bounds())?;
+        (hit.exit >= T::zero() && hit.entry <= T::one()).then_some(hit)
+    }
+
+    /// Returns where `self` and `other` meet.
+    ///
+    /// Points are compared exactly, so segments that nearly touch
+    /// may or may not intersect due to rounding.
+    pub fn intersect(&self, other: Self) -> Option<SegmentIntersection<T>> {
+        let (r, s) = (self.direction(), other.direction());
+        let between = self.start.offset(other.start);
+
+        // A segment without length is a point
+        if r.length_squared() == T::zero() || s.length_squared() == T::zero() {
+            let (point, segment) = if r.length_squared() == T::zero() {
+                (self.start, other)
+            } else {
+                (other.start, *self)
+            };
+
+            return (segment.closest_point(point) == point)
+                .then_some(SegmentIntersection::Point(point));
+        }
+
+        let denominator = r.cross(s);
+
+        if denominator == T::zero() {
+            if between.cross(r) != T::zero() {
+                return None;
+            }
+
+            // The segments are on the same line, so find where `other` is along `self`
+            let length = r.length_squared();
+            let a = between.dot(r) / length;
+            let b = a + s.dot(r) / length;
+
+            let start = a.min(b).max(T::zero());
+            let end = a.max(b).min(T::one());
+
+            return match start.partial_cmp(&end)? {
+                std::cmp::Ordering::Greater => None,
+                std::cmp::Ordering::Equal => Some(SegmentIntersection::Point(self.at(start))),
+                std::cmp::Ordering::Less => Some(SegmentIntersection::Overlap(Self {
+                    start: self.at(start),
+                    end: self.at(end),
+                })),
+            };
+        }
+
+        let t = between.cross(s) / denominator;
+        let u = between.cross(r) / denominator;
+        let range = T::zero()..=T::one();
+
+        (range.contains(&t) && range.contains(&u)).then(|| SegmentIntersection::Point(self.at(t)))
+    }
+
+    /// Returns the point on the segment closest to `point`.
+    pub fn closest_point<P: ToPoint2D<T>>(&self, point: P) -> Point2D<T> {
+        let t = project(self.start, self.direction(), point.to_vector());
+        self.at(t.max(T::zero()).min(T::one()))
+    }
+
+    /// Returns the closest pair of points between `self` and `other`,
+    /// where the first point is on `self` and the second on `other`.
+    pub fn closest_points(&self, other: Self) -> (Point2D<T>, Point2D<T>) {
+        match self.intersect(other) {
+            Some(SegmentIntersection::Point(point)) => return (point, point),
+            Some(SegmentIntersection::Overlap(overlap)) => return (overlap.start, overlap.start),
+            None => {}
+        }
+
+        // Segments that do not cross are closest at one of their ends
+        [
+            (self.start, other.closest_point(self.start)),
+            (self.end, other.closest_point(self.end)),
+            (self.closest_point(other.start), other.start),
+            (self.closest_point(other.end), other.end),
+        ]
+        .into_iter()
+        .reduce(|best, pair| {
+            if pair.0.euclidean_distance(pair.1) < best.0.euclidean_distance(best.1) {
+                pair
+            } else {
+                best
+            }
+        })
+        .expect("There must be a pair")
+    }
+
+    /// Returns the part of the segment inside `bounds`, using [`ClipMethod::LiangBarsky`].
+    ///
+    /// The edges of `bounds` are included, so a segment along an edge is kept.
+    pub fn clip<B: IntoBounds2D<T>>(&self, bounds: B) -> Option<Self> {
+        self.clip_with(bounds, ClipMethod::LiangBarsky)
+    }
+
+    /// Returns the part of the segment inside `bounds`, using `method`.
+    ///
+    /// Both methods give the same result, apart from rounding.
+    pub fn clip_with<B: IntoBounds2D<T>>(&self, bounds: B, method: ClipMethod) -> Option<Self> {
+        let bounds = bounds.to_bounds();
+
+        match method {
+            ClipMethod::LiangBarsky => self.liang_barsky(bounds),
+            ClipMethod::CohenSutherland => self.cohen_sutherland(bounds),
+        }
+    }
+
+    fn liang_barsky(&self, bounds: Bounds2D<T>) -> Option<Self> {
+        let direction = self.direction();
+        let (mut enter, mut exit) = (T::zero(), T::one());
+
+        let edges = [
+            (-direction.x, self.start.x - bounds.left()),
+            (direction.x, bounds.right() - self.start.x),
+            (-direction.y, self.start.y - bounds.top()),
+            (direction.y, bounds.bottom() - self.start.y),
+        ];
+
+        for (p, q) in edges {
+            if p == T::zero() {
+                // Parallel to this edge, so it is either fully inside or fully outside of it
+                if q < T::zero() {
+                    return None;
+                }
+            } else if p < T::zero() {
+                enter = enter.max(q / p);
+            } else {
+                exit = exit.min(q / p);
+            }
+
+            if enter > exit {
+                return None;
+            }
+        }
+
+        Some(Self {
+            start: self.at(enter),
+            end: self.at(exit),
+        })
+    }
+
+    fn cohen_sutherland(&self, bounds: Bounds2D<T>) -> Option<Self> {
+        const LEFT: u8 = 1;
+        const RIGHT: u8 = 2;
+        const TOP: u8 = 4;
+        const BOTTOM: u8 = 8;
+
+        let code = |point: Point2D<T>| {
+            let mut code = 0;
+
+            if point.x < bounds.left() {
+                code |= LEFT;
+            } else if point.x > bounds.right() {
+                code |= RIGHT;
+            }
+
+            if point.y < bounds.top() {
+                code |= TOP;
+            } else if point.y > bounds.bottom() {
+                code |= BOTTOM;
+            }
+
+            code
+        };
+
+        let (mut start, mut end) = (self.start, self.end);
+        let (mut start_code, mut end_code) = (code(start), code(end));
+
+        loop {
+            if start_code | end_code == 0 {
+                return Some(Self { start, end });
+            }
+
+            if start_code & end_code != 0 {
+                return None;
+            }
+
+            // Move an end that is outside onto the edge it is outside of
+            let outside = if start_code != 0 {
+                start_code
+            } else {
+                end_code
+            };
+            let (dx, dy) = (end.x - start.x, end.y - start.y);
+
+            let point = if outside & TOP != 0 {
+                let y = bounds.top();
+                Point2D::new(start.x + dx * (y - start.y) / dy, y)
+            } else if outside & BOTTOM != 0 {
+                let y = bounds.bottom();
+                Point2D::new(start.x + dx * (y - start.y) / dy, y)
+            } else if outside & RIGHT != 0 {
+                let x = bounds.right();
+                Point2D::new(x, start.y + dy * (x - start.x) / dx)
+            } else {
+                let x = bounds.left();
+                Point2D::new(x, start.y + dy * (x - start.x) / dx)
+            };
+
+            if outside == start_code {
+                start = point;
+                start_code = code(start);
+            } else {
+                end = point;
+                end_code = code(end);
+            }
+        }
+    }
+}
+
+/// Returns where a line through `origin` in `direction` crosses the slabs of `bounds`.
+fn slabs<T: Float>(
+    origin: Point2D<T>,
+    direction: Offset2D<T>,
+    bounds: Bounds2D<T>,
+) -> Option<RayHit<T>> {
+    let mut entry = T::neg_infinity();
+    let mut exit = T::infinity();
+    let mut normal = Offset2D::new(T::zero(), T::zero());
+
+    let axes = [
+        (origin.x, direction.x, bounds.left(), bounds.right()),
+        (origin.y, direction.y, bounds.top(), bounds.bottom()),
+    ];
+
+    for (axis, (origin, direction, min, max)) in axes.into_iter().enumerate() {
+        if direction == T::zero() {
+            if origin < min || origin > max {
+                return None;
+            }
+
+            continue;
+        }
+
+        let (a, b) = ((min - origin) / direction, (max - origin) / direction);
+        let (near, far) = (a.min(b), a.max(b));
+
+        if near > entry {
+            entry = near;
+
+            let side = -direction.signum();
+            normal = match axis {
+                0 => Offset2D::new(side, T::zero()),
+                _ => Offset2D::new(T::zero(), side),
+            };
+        }
+
+        exit = exit.min(far);
+    }
+
+    if entry > exit {
+        return None;
+    }
+
+    if entry < T::zero() {
+        entry = T::zero();
+        normal = Offset2D::new(T::zero(), T::zero());
+    }
+
+    Some(RayHit {
+        entry,
+        exit,
+        normal,
+    })
+}
+
+/// Returns how many times `direction` the projection of `point` is from `origin`.
+fn project<T: Float>(origin: Point2D<T>, direction: Offset2D<T>, point: Point2D<T>) -> T {
+    let length = direction.length_squared();
+
+    if length == T::zero() {
+        return T::zero();
+    }
+
+    origin.offset(point).dot(direction) / length
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    #[test]
+    fn clip_methods_agree() {
+        let mut random = Random::new(1029384756);
+        let mut next = move || random.float(100.0);
+
+        let bounds = bounds!(25.0, 30.0, 40.0, 35.0);
+
+        for _ in 0..1000 {
+            let segment = Segment2D::new((next(), next()), (next(), next()));
+
+            let a = segment.clip_with(bounds, ClipMethod::LiangBarsky);
+            let b = segment.clip_with(bounds, ClipMethod::CohenSutherland);
+
+            match (a, b) {
+                (Some(a), Some(b)) => {
+                    assert!(a.start.euclidean_distance(b.start) < 1e-9);
+                    assert!(a.end.euclidean_distance(b.end) < 1e-9);
+                }
+                (a, b) => assert_eq!(a, b),
+            }
+
+            // The clipped part is where the segment enters and exits the bounds
+            if let (Some(a), Some(hit)) = (a, segment.intersect_bounds(bounds)) {
+                let exit = hit.exit.min(1.0);
+                assert!(a.start.euclidean_distance(segment.at(hit.entry)) < 1e-9);
+                assert!(a.end.euclidean_distance(segment.at(exit)) < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn clip_edge_cases() {
+        let bounds = bounds!(25.0, 30.0, 40.0, 35.0);
+        let segment = |start: (f64, f64), end: (f64, f64)| Segment2D::new(start, end);
+
+        for (input, expected) in [
+            // Inside, unchanged
+            (
+                segment((30.0, 35.0), (40.0, 40.0)),
+                Some(segment((30.0, 35.0), (40.0, 40.0))),
+            ),
+            // Without length
+            (
+                segment((30.0, 35.0), (30.0, 35.0)),
+                Some(segment((30.0, 35.0), (30.0, 35.0))),
+            ),
+            (segment((0.0, 0.0), (0.0, 0.0)), None),
+            // Vertical and horizontal
+            (
+                segment((30.0, 0.0), (30.0, 100.0)),
+                Some(segment((30.0, 30.0), (30.0, 65.0))),
+            ),
+            (
+                segment((100.0, 40.0), (0.0, 40.0)),
+                Some(segment((65.0, 40.0), (25.0, 40.0))),
+            ),
+            // Parallel to an edge, outside
+            (segment((0.0, 0.0), (100.0, 0.0)), None),
+            (segment((10.0, 0.0), (10.0, 100.0)), None),
+            // Through a corner
+            (
+                segment((15.0, 20.0), (35.0, 40.0)),
+                Some(segment((25.0, 30.0), (35.0, 40.0))),
+            ),
+        ] {
+            for method in [ClipMethod::LiangBarsky, ClipMethod::CohenSutherland] {
+                assert_eq!(input.clip_with(bounds, method), expected, "{method:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn collinear_segments() {
+        let a = Segment2D::new((0.0, 0.0), (10.0, 0.0));
+
+        assert_eq!(
+            a.intersect(Segment2D::new((12.0, 0.0), (5.0, 0.0))),
+            Some(SegmentIntersection::Overlap(Segment2D::new(
+                (5.0, 0.0),
+                (10.0, 0.0)
+            )))
+        );
+
+        assert_eq!(
+            a.intersect(Segment2D::new((10.0, 0.0), (15.0, 0.0))),
+            Some(SegmentIntersection::Point(point!(10.0, 0.0)))
+        );
+
+        assert_eq!(a.intersect(Segment2D::new((11.0, 0.0), (15.0, 0.0))), None);
+        assert_eq!(a.intersect(Segment2D::new((0.0, 1.0), (10.0, 1.0))), None);
+    }
+}
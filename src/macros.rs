@@ -13,15 +13,21 @@
 //! ```ignore
 //! // This creates a Size2D, because we specified 2 arguments.
 //! let very_big = size!(500, 500);
+//!
+//! // This creates a Point3D, because we specified 3 arguments.
+//! let voxel = point!(2, 4, 8);
 //! ```
 //!
 //! ## Exceptions
-//! For [`Bounds2D`](crate::Bounds2D) it is the same,
+//! For [`Bounds2D`](crate::Bounds2D) and [`Bounds3D`](crate::Bounds3D) it is the same,
 //! except the number of arguments are doubled.
 //!
 //! ```ignore
 //! // This creates a Bounds2D
 //! let bounds = bounds!(0, 20, 10, 10);
+//!
+//! // This creates a Bounds3D
+//! let bounds = bounds!(0, 20, 5, 10, 10, 10);
 //! ```
 //!
 //! # Splat syntax
@@ -40,19 +46,31 @@
 //!
 //! // No exception for bounds here, this is a Bounds2D.
 //! let bounds = bounds!(10; 2);
+//!
+//! // This creates a Size3D where all components are `1`.
+//! let size = size!(1; 3);
 //! ```
 
 /// Creates a new size.
 #[macro_export]
 macro_rules! size {
     ($t: ty; 2) => {
-        $crate::Size2D::square(<$t>::default());
+        $crate::Size2D::<_>::square(<$t>::default())
     };
     ($v:expr; 2) => {
-        $crate::Size2D::square($v)
+        $crate::Size2D::<_>::square($v)
+    };
+    ($t: ty; 3) => {
+        $crate::Size3D::cube(<$t>::default())
+    };
+    ($v:expr; 3) => {
+        $crate::Size3D::cube($v)
     };
     ($width:expr, $height:expr) => {
-        $crate::Size2D::new($width, $height)
+        $crate::Size2D::<_>::new($width, $height)
+    };
+    ($width:expr, $height:expr, $depth:expr) => {
+        $crate::Size3D::new($width, $height, $depth)
     };
 }
 
@@ -60,44 +78,109 @@ macro_rules! size {
 #[macro_export]
 macro_rules! bounds {
     ($t: ty; 2) => {
-        $crate::Bounds2D::splat(<$t>::default());
+        $crate::Bounds2D::<_>::splat(<$t>::default())
     };
     ($v:expr; 2) => {
-        $crate::Bounds2D::splat($v)
+        $crate::Bounds2D::<_>::splat($v)
+    };
+    ($t: ty; 3) => {
+        $crate::Bounds3D::splat(<$t>::default())
+    };
+    ($v:expr; 3) => {
+        $crate::Bounds3D::splat($v)
     };
     ($x:expr, $y:expr, $width:expr, $height:expr) => {
-        $crate::Bounds2D::new($x, $y, $width, $height)
+        $crate::Bounds2D::<_>::new($x, $y, $width, $height)
+    };
+    ($x:expr, $y:expr, $z:expr, $width:expr, $height:expr, $depth:expr) => {
+        $crate::Bounds3D::new($x, $y, $z, $width, $height, $depth)
+    };
+}
+
+/// Creates new insets.
+///
+/// Like in CSS, one value is used for every edge, two values are the vertical and
+/// horizontal edges, and four values go clockwise from the top.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// assert_eq!(insets!(4), Insets2D::uniform(4));
+/// assert_eq!(insets!(4, 8), Insets2D::symmetric(4, 8));
+/// assert_eq!(insets!(1, 2, 3, 4), Insets2D::new(1, 2, 3, 4));
+/// ```
+#[macro_export]
+macro_rules! insets {
+    ($v:expr) => {
+        $crate::Insets2D::<_>::uniform($v)
+    };
+    ($vertical:expr, $horizontal:expr) => {
+        $crate::Insets2D::<_>::symmetric($vertical, $horizontal)
+    };
+    ($top:expr, $right:expr, $bottom:expr, $left:expr) => {
+        $crate::Insets2D::<_>::new($top, $right, $bottom, $left)
+    };
+}
+
+/// Creates a new polygon from its vertices, which can be points or tuples.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let triangle = polygon![(0, 0), point!(10, 0), (0, 10)];
+///
+/// assert_eq!(triangle, Polygon2D::new([(0, 0), (10, 0), (0, 10)]));
+/// ```
+#[macro_export]
+macro_rules! polygon {
+    ($($vertex:expr),* $(,)?) => {
+        $crate::Polygon2D::new([$($crate::Point2D::<_>::from($vertex)),*])
     };
 }
 
 /// Creates a new point vector.
 #[macro_export]
 macro_rules! point {
-    ($($t:tt)*) => {{
-        let point: $crate::Point2D<_> = $crate::__vector!($($t)*);
-        point
-    }};
+    ($($t:tt)*) => {
+        $crate::__vector!(Point2D, Point3D; $($t)*)
+    };
 }
 
 /// Creates a new offset vector.
 #[macro_export]
 macro_rules! offset {
-    ($($t:tt)*) => {{
-        let offset: $crate::Offset2D<_> = $crate::__vector!($($t)*);
-        offset
-    }};
+    ($($t:tt)*) => {
+        $crate::__vector!(Offset2D, Offset3D; $($t)*)
+    };
 }
 
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __vector {
-    ($t: ty; 2) => {
-        $crate::Vector2D::splat(<$t>::default());
-    };
-    ($v:expr; 2) => {
-        $crate::Vector2D::splat($v)
-    };
-    ($x:expr, $y:expr) => {
-        $crate::Vector2D::new($x, $y)
-    };
+    ($v2:ident, $v3:ident; $t: ty; 2) => {{
+        let vector: $crate::$v2<_> = $crate::Vector2D::splat(<$t>::default());
+        vector
+    }};
+    ($v2:ident, $v3:ident; $v:expr; 2) => {{
+        let vector: $crate::$v2<_> = $crate::Vector2D::splat($v);
+        vector
+    }};
+    ($v2:ident, $v3:ident; $t: ty; 3) => {{
+        let vector: $crate::$v3<_> = $crate::Vector3D::splat(<$t>::default());
+        vector
+    }};
+    ($v2:ident, $v3:ident; $v:expr; 3) => {{
+        let vector: $crate::$v3<_> = $crate::Vector3D::splat($v);
+        vector
+    }};
+    ($v2:ident, $v3:ident; $x:expr, $y:expr) => {{
+        let vector: $crate::$v2<_> = $crate::Vector2D::new($x, $y);
+        vector
+    }};
+    ($v2:ident, $v3:ident; $x:expr, $y:expr, $z:expr) => {{
+        let vector: $crate::$v3<_> = $crate::Vector3D::new($x, $y, $z);
+        vector
+    }};
 }
@@ -0,0 +1,192 @@
+use num_traits::{Float, Num};
+
+/// A way of measuring the distance between two vectors.
+///
+/// Implementors receive the absolute difference of every component,
+/// so they never have to deal with negative values, which means
+/// unsigned types like `usize` can be measured as well.
+///
+/// Metrics are expected to never measure less than the largest difference,
+/// as range queries like [`Grid2D::cells_in_range()`](crate::Grid2D::cells_in_range)
+/// rely on it to know where to stop looking.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let a = point!(0.0, 0.0);
+/// let b = point!(3.0, 4.0);
+///
+/// assert_eq!(a.distance_with(Manhattan, b), 7.0);
+/// assert_eq!(a.distance_with(Euclidean, b), 5.0);
+/// assert_eq!(a.distance_with(Chebyshev, b), 4.0);
+///
+/// // Metrics can also be picked while running
+/// let metric: Box<dyn Metric<f64>> = if a == b { Box::new(Manhattan) } else { Box::new(Euclidean) };
+/// assert_eq!(a.distance_with(&metric, b), 5.0);
+/// ```
+pub trait Metric<T> {
+    /// Measures the distance described by the absolute component differences in `deltas`.
+    fn measure(&self, deltas: &[T]) -> T;
+}
+
+impl<T, M> Metric<T> for &M
+where
+    M: Metric<T> + ?Sized,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        (**self).measure(deltas)
+    }
+}
+
+impl<T, M> Metric<T> for Box<M>
+where
+    M: Metric<T> + ?Sized,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        (**self).measure(deltas)
+    }
+}
+
+/// The sum of all component differences, also known as taxicab distance.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Manhattan;
+
+impl<T> Metric<T> for Manhattan
+where
+    T: Num + Copy,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        deltas.iter().fold(T::zero(), |sum, &delta| sum + delta)
+    }
+}
+
+/// The straight-line distance.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Euclidean;
+
+impl<T> Metric<T> for Euclidean
+where
+    T: Float,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        SquaredEuclidean.measure(deltas).sqrt()
+    }
+}
+
+/// The straight-line distance squared.
+///
+/// This orders distances the same way as [Euclidean], without needing
+/// a square root, so it works for integer types too.
+/// Remember to square the radius when using it for range queries.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SquaredEuclidean;
+
+impl<T> Metric<T> for SquaredEuclidean
+where
+    T: Num + Copy,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        deltas
+            .iter()
+            .fold(T::zero(), |sum, &delta| sum + delta * delta)
+    }
+}
+
+/// The largest component difference, also known as chessboard distance.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Chebyshev;
+
+impl<T> Metric<T> for Chebyshev
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        deltas.iter().fold(
+            T::zero(),
+            |max, &delta| if delta > max { delta } else { max },
+        )
+    }
+}
+
+/// The generalized distance of order `p`.
+///
+/// A `p` of `1` is equal to [Manhattan], and a `p` of `2` is equal to [Euclidean].
+/// Orders below `1` do not describe a proper metric.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let distance = point!(0.0, 0.0).distance_with(Minkowski::new(1.0), (3.0, 4.0));
+///
+/// assert_eq!(distance, 7.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+pub struct Minkowski<T> {
+    pub p: T,
+}
+
+impl<T> Minkowski<T> {
+    /// Creates a new [Minkowski] metric of order `p`.
+    pub fn new(p: T) -> Self {
+        Self { p }
+    }
+}
+
+impl<T> Metric<T> for Minkowski<T>
+where
+    T: Float,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        deltas
+            .iter()
+            .fold(T::zero(), |sum, &delta| sum + delta.powf(self.p))
+            .powf(self.p.recip())
+    }
+}
+
+/// The cost of moving on a grid where diagonal steps are allowed,
+/// and cost the square root of the number of axes they move along.
+///
+/// In two dimensions this is `max + (√2 - 1) * min`, which is
+/// the usual admissible heuristic for eight-way grid pathing.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let distance = point!(0.0, 0.0).distance_with(Octile, (3.0, 1.0));
+///
+/// assert_eq!(distance, 2.0 + 2f64.sqrt());
+///
+/// let distance = point!(0.0, 0.0, 0.0).distance_with(Octile, (1.0, 3.0, 1.0));
+/// assert!((distance - (2.0 + 3f64.sqrt())).abs() < 1e-12);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Octile;
+
+impl<T> Metric<T> for Octile
+where
+    T: Float,
+{
+    fn measure(&self, deltas: &[T]) -> T {
+        // Sorted largest first, every additional axis moved along costs
+        // the difference between consecutive square roots. The rank of each
+        // delta is counted instead of sorting, to avoid allocating.
+        deltas
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |sum, (index, &delta)| {
+                let rank = deltas
+                    .iter()
+                    .enumerate()
+                    .filter(|&(other, &larger)| {
+                        larger > delta || (larger == delta && other < index)
+                    })
+                    .fold(T::zero(), |rank, _| rank + T::one());
+
+                sum + delta * ((rank + T::one()).sqrt() - rank.sqrt())
+            })
+    }
+}
@@ -0,0 +1,868 @@
+use std::{cmp::Ordering, collections::BTreeMap};
+
+use num_traits::{Float, Num};
+
+use crate::{
+    FillRule, Offset2D, Point2D, Polygon2D, Segment2D, SegmentIntersection, ToPoint2D, Vector,
+    Winding,
+};
+
+/// A [Polygon2D] outline with any number of holes cut out of it.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let frame = PolygonWithHoles2D::new(
+///     polygon![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+///     [polygon![(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)]],
+/// );
+///
+/// assert_eq!(frame.area(), 64.0);
+/// assert!(frame.contains_point((1.0, 1.0)));
+/// assert!(!frame.contains_point((5.0, 5.0)));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct PolygonWithHoles2D<T> {
+    outline: Polygon2D<T>,
+    holes: Vec<Polygon2D<T>>,
+}
+
+impl<T> PolygonWithHoles2D<T> {
+    /// Creates a new [PolygonWithHoles2D] from an `outline` and the `holes` inside it.
+    pub fn new<I>(outline: Polygon2D<T>, holes: I) -> Self
+    where
+        I: IntoIterator<Item = Polygon2D<T>>,
+    {
+        Self {
+            outline,
+            holes: holes.into_iter().collect(),
+        }
+    }
+
+    /// Returns the outline of the polygon.
+    pub fn outline(&self) -> &Polygon2D<T> {
+        &self.outline
+    }
+
+    /// Returns the holes of the polygon.
+    pub fn holes(&self) -> &[Polygon2D<T>] {
+        &self.holes
+    }
+}
+
+impl<T> PolygonWithHoles2D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Returns `true` if `point` is inside the outline and outside every hole.
+    ///
+    /// The edges follow the same rules as [`Polygon2D::contains_point()`].
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P) -> bool {
+        let point = point.to_vector();
+
+        self.outline.contains_point(point, FillRule::NonZero)
+            && !self
+                .holes
+                .iter()
+                .any(|hole| hole.contains_point(point, FillRule::NonZero))
+    }
+}
+
+impl<T> PolygonWithHoles2D<T>
+where
+    T: Float,
+{
+    /// Returns the area inside the outline, minus the area of the holes.
+    pub fn area(&self) -> T {
+        self.holes
+            .iter()
+            .fold(self.outline.signed_area().abs(), |area, hole| {
+                area - hole.signed_area().abs()
+            })
+    }
+}
+
+impl<T> From<Polygon2D<T>> for PolygonWithHoles2D<T> {
+    fn from(outline: Polygon2D<T>) -> Self {
+        Self {
+            outline,
+            holes: Vec::new(),
+        }
+    }
+}
+
+/// Any number of separate [PolygonWithHoles2D], treated as a single area.
+///
+/// Boolean operations between multipolygons can merge and cut any polygons,
+/// including ones with holes or with edges lying on top of each other.
+/// The polygons making up each side must not overlap or cross themselves.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let a = MultiPolygon2D::from(polygon![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+/// let b = MultiPolygon2D::from(polygon![(5.0, 5.0), (15.0, 5.0), (15.0, 15.0), (5.0, 15.0)]);
+///
+/// assert_eq!(a.union(&b).area(), 175.0);
+/// assert_eq!(a.intersection(&b).area(), 25.0);
+/// assert_eq!(a.subtract(&b).area(), 75.0);
+///
+/// // The two corners left over only touch at a point, so they are separate polygons
+/// assert_eq!(a.xor(&b).len(), 2);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct MultiPolygon2D<T> {
+    polygons: Vec<PolygonWithHoles2D<T>>,
+}
+
+impl<T> MultiPolygon2D<T> {
+    /// Creates a new [MultiPolygon2D] from `polygons`.
+    pub fn new<I, P>(polygons: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PolygonWithHoles2D<T>>,
+    {
+        Self {
+            polygons: polygons.into_iter().map(Into::into).collect(),
+        }
+    }
+
+    /// Returns the polygons making up the area.
+    pub fn polygons(&self) -> &[PolygonWithHoles2D<T>] {
+        &self.polygons
+    }
+
+    /// Returns the number of polygons.
+    pub fn len(&self) -> usize {
+        self.polygons.len()
+    }
+
+    /// Returns `true` if there are no polygons.
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+}
+
+impl<T> MultiPolygon2D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Returns `true` if `point` is inside any of the polygons.
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P) -> bool {
+        let point = point.to_vector();
+
+        self.polygons
+            .iter()
+            .any(|polygon| polygon.contains_point(point))
+    }
+}
+
+impl<T> MultiPolygon2D<T>
+where
+    T: Float,
+{
+    /// Returns the total area of the polygons.
+    pub fn area(&self) -> T {
+        self.polygons
+            .iter()
+            .fold(T::zero(), |area, polygon| area + polygon.area())
+    }
+
+    /// Returns the area covered by either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns the area covered by both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Returns the area covered by `self`, but not by `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let square = MultiPolygon2D::from(polygon![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]);
+    /// let hole = MultiPolygon2D::from(polygon![(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)]);
+    ///
+    /// let frame = square.subtract(&hole);
+    ///
+    /// assert_eq!(frame.len(), 1);
+    /// assert_eq!(frame.polygons()[0].holes().len(), 1);
+    /// assert_eq!(frame.union(&hole), square);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Returns the area covered by exactly one of `self` and `other`.
+    pub fn xor(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// Applies a boolean operation on both sides of every edge, by cutting the edges
+    /// of both multipolygons wherever they meet and keeping the ones that separate
+    /// an area inside the result from one outside it.
+    fn combine<F>(&self, other: &Self, operation: F) -> Self
+    where
+        F: Fn(bool, bool) -> bool,
+    {
+        let operands = [self.rings(), other.rings()];
+        let fragments = split(&operands);
+
+        let mut vertices: Vec<Point2D<T>> = fragments
+            .iter()
+            .flat_map(|&(segment, _)| [segment.start, segment.end])
+            .collect();
+
+        vertices.sort_by(compare);
+        vertices.dedup();
+
+        let vertex = |point: Point2D<T>| {
+            vertices
+                .binary_search_by(|other| compare(other, &point))
+                .expect("Vertex must exist")
+        };
+
+        // Every operand counts +1 for each of its edges going from the lower vertex
+        // to the higher one, and -1 for each going the other way
+        let mut segments: BTreeMap<(usize, usize), [Option<i32>; 2]> = BTreeMap::new();
+
+        for (segment, operand) in fragments {
+            let (start, end) = (vertex(segment.start), vertex(segment.end));
+            let key = (start.min(end), start.max(end));
+            let count = &mut segments.entry(key).or_default()[operand];
+
+            *count = Some(count.unwrap_or(0) + if start < end { 1 } else { -1 });
+        }
+
+        let middle = |(start, end): (usize, usize)| {
+            let (a, b) = (vertices[start], vertices[end]);
+            let two = T::one() + T::one();
+            Point2D::new((a.x + b.x) / two, (a.y + b.y) / two)
+        };
+
+        // Edges of only one operand are on the same side of the other operand all the way,
+        // so the other operand is looked up at their middle, all at once
+        let mut windings = [0, 1].map(|operand| {
+            let points: Vec<_> = segments
+                .iter()
+                .filter(|(_, counts)| counts[operand].is_none())
+                .map(|(&key, _)| middle(key))
+                .collect();
+
+            winding_numbers(&operands[operand], &points).into_iter()
+        });
+
+        let mut edges = Vec::new();
+
+        for ((start, end), counts) in segments {
+            // Returns whether each side of the edge is inside the operand, left side first.
+            // Rings go around with the inside on their left, and where two edges cancel
+            // out, the operand is on both sides.
+            let mut sides = |operand: usize| match counts[operand] {
+                Some(count) => (count >= 0, count <= 0),
+                None => {
+                    let winding = windings[operand].next().expect("Edge must have a winding");
+                    (winding != 0, winding != 0)
+                }
+            };
+
+            let (a, b) = (sides(0), sides(1));
+            let (left, right) = (operation(a.0, b.0), operation(a.1, b.1));
+
+            if left != right {
+                edges.push(if left { (start, end) } else { (end, start) });
+            }
+        }
+
+        assemble(&vertices, trace(&vertices, &edges))
+    }
+
+    /// Returns every ring with an area, going around with the inside on their left.
+    fn rings(&self) -> Vec<Polygon2D<T>> {
+        let mut rings = Vec::new();
+
+        for polygon in &self.polygons {
+            let holes = polygon.holes.iter().map(|hole| (hole, Winding::Clockwise));
+
+            for (ring, winding) in
+                std::iter::once((&polygon.outline, Winding::CounterClockwise)).chain(holes)
+            {
+                if ring.winding().is_some() {
+                    let mut ring = ring.clone();
+                    ring.set_winding(winding);
+                    rings.push(ring);
+                }
+            }
+        }
+
+        rings
+    }
+}
+
+impl<T> From<Polygon2D<T>> for MultiPolygon2D<T> {
+    fn from(polygon: Polygon2D<T>) -> Self {
+        Self::new([polygon])
+    }
+}
+
+impl<T> From<PolygonWithHoles2D<T>> for MultiPolygon2D<T> {
+    fn from(polygon: PolygonWithHoles2D<T>) -> Self {
+        Self::new([polygon])
+    }
+}
+
+impl<T, P> FromIterator<P> for MultiPolygon2D<T>
+where
+    P: Into<PolygonWithHoles2D<T>>,
+{
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Cuts the edges of every ring wherever they meet another edge,
+/// returning the pieces along with the operand they came from.
+fn split<T>(operands: &[Vec<Polygon2D<T>>; 2]) -> Vec<(Segment2D<T>, usize)>
+where
+    T: Float,
+{
+    let edges: Vec<(Segment2D<T>, usize)> = operands
+        .iter()
+        .enumerate()
+        .flat_map(|(operand, rings)| {
+            rings
+                .iter()
+                .flat_map(Polygon2D::edges)
+                .filter(|edge| edge.start != edge.end)
+                .map(move |edge| (edge, operand))
+        })
+        .collect();
+
+    let mut cuts: Vec<Vec<Point2D<T>>> = edges
+        .iter()
+        .map(|(edge, _)| vec![edge.start, edge.end])
+        .collect();
+
+    let left = |edge: &Segment2D<T>| edge.start.x.min(edge.end.x);
+    let right = |edge: &Segment2D<T>| edge.start.x.max(edge.end.x);
+
+    // Sweeping from left to right, only edges that are still active can meet the next one
+    let mut order: Vec<usize> = (0..edges.len()).collect();
+    order.sort_by(|&a, &b| {
+        left(&edges[a].0)
+            .partial_cmp(&left(&edges[b].0))
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut active: Vec<usize> = Vec::new();
+
+    for i in order {
+        let a = edges[i].0;
+        active.retain(|&j| right(&edges[j].0) >= left(&a));
+
+        for &j in &active {
+            let b = edges[j].0;
+
+            if a.start.y.max(a.end.y) < b.start.y.min(b.end.y)
+                || b.start.y.max(b.end.y) < a.start.y.min(a.end.y)
+            {
+                continue;
+            }
+
+            for point in meeting_points(a, b) {
+                cuts[i].push(point);
+                cuts[j].push(point);
+            }
+        }
+
+        active.push(i);
+    }
+
+    let mut fragments = Vec::new();
+
+    for ((edge, operand), mut points) in edges.into_iter().zip(cuts) {
+        let direction = edge.direction();
+        let along = |point: &Point2D<T>| edge.start.offset(*point).dot(direction);
+
+        points.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal));
+        points.dedup();
+
+        for pair in points.windows(2) {
+            fragments.push((Segment2D::new(pair[0], pair[1]), operand));
+        }
+    }
+
+    fragments
+}
+
+/// Returns the points where two edges meet.
+///
+/// Ends of either edge lying on the other are returned as they are, so that both edges
+/// are cut at exactly the same points. This also covers edges lying on top of each other.
+fn meeting_points<T>(a: Segment2D<T>, b: Segment2D<T>) -> Vec<Point2D<T>>
+where
+    T: Float,
+{
+    let ends: Vec<Point2D<T>> = [(a.start, b), (a.end, b), (b.start, a), (b.end, a)]
+        .into_iter()
+        .filter(|&(end, edge)| touches(edge, end))
+        .map(|(end, _)| end)
+        .collect();
+
+    if !ends.is_empty() {
+        return ends;
+    }
+
+    match a.intersect(b) {
+        Some(SegmentIntersection::Point(point)) => vec![point],
+        _ => Vec::new(),
+    }
+}
+
+/// Returns `true` if `point` lies exactly on `edge`.
+fn touches<T>(edge: Segment2D<T>, point: Point2D<T>) -> bool
+where
+    T: Float,
+{
+    let within = |value: T, a: T, b: T| a.min(b) <= value && value <= a.max(b);
+
+    edge.start.offset(point).cross(edge.direction()) == T::zero()
+        && within(point.x, edge.start.x, edge.end.x)
+        && within(point.y, edge.start.y, edge.end.y)
+}
+
+/// Returns how many times the rings go around each of `points`, counting holes negatively.
+///
+/// This follows [`Polygon2D::contains_point()`], but every edge is only
+/// compared with the points level with it, by sorting the points by height.
+fn winding_numbers<T>(rings: &[Polygon2D<T>], points: &[Point2D<T>]) -> Vec<i32>
+where
+    T: Float,
+{
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| {
+        points[a]
+            .y
+            .partial_cmp(&points[b].y)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut windings = vec![0; points.len()];
+
+    for edge in rings.iter().flat_map(Polygon2D::edges) {
+        let (start, end) = (edge.start, edge.end);
+        let upwards = end.y > start.y;
+
+        // Only points on the horizontal lines crossing the edge count
+        let first = order.partition_point(|&index| points[index].y < start.y.min(end.y));
+        let last = order.partition_point(|&index| points[index].y < start.y.max(end.y));
+
+        for &index in &order[first..last] {
+            let point = points[index];
+            let side =
+                (end.x - start.x) * (point.y - start.y) - (end.y - start.y) * (point.x - start.x);
+
+            // The crossing is to the right of the point
+            if (upwards && side > T::zero()) || (!upwards && side < T::zero()) {
+                windings[index] += if upwards { 1 } else { -1 };
+            }
+        }
+    }
+
+    windings
+}
+
+/// Follows the edges into closed rings of vertex indices.
+fn trace<T>(vertices: &[Point2D<T>], edges: &[(usize, usize)]) -> Vec<Vec<usize>>
+where
+    T: Float,
+{
+    let mut outgoing = vec![Vec::new(); vertices.len()];
+
+    for (index, &(start, _)) in edges.iter().enumerate() {
+        outgoing[start].push(index);
+    }
+
+    let mut used = vec![false; edges.len()];
+    let mut rings = Vec::new();
+
+    for first in 0..edges.len() {
+        if used[first] {
+            continue;
+        }
+
+        let mut ring = Vec::new();
+        let mut current = first;
+
+        loop {
+            used[current] = true;
+
+            let (start, end) = edges[current];
+            let back = vertices[end].offset(vertices[start]);
+
+            ring.push(start);
+
+            // Turning as far left as possible follows the area on the left of the edges,
+            // so areas touching at a vertex are kept apart
+            let next = outgoing[end]
+                .iter()
+                .copied()
+                .filter(|&edge| !used[edge] || edge == first)
+                .map(|edge| {
+                    (
+                        edge,
+                        clockwise_angle(back, vertices[end].offset(vertices[edges[edge].1])),
+                    )
+                })
+                .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+            match next {
+                Some((next, _)) if next != first => current = next,
+                _ => break,
+            }
+        }
+
+        rings.push(ring);
+    }
+
+    rings
+}
+
+/// Returns a value that sorts directions by how far clockwise they are from `from`,
+/// where `from` itself comes last.
+fn clockwise_angle<T>(from: Offset2D<T>, to: Offset2D<T>) -> (bool, T)
+where
+    T: Float,
+{
+    let angle = to.cross(from).atan2(to.dot(from));
+    (angle <= T::zero(), angle)
+}
+
+/// Turns the rings into polygons, putting each hole in the smallest outline around it.
+fn assemble<T>(vertices: &[Point2D<T>], rings: Vec<Vec<usize>>) -> MultiPolygon2D<T>
+where
+    T: Float,
+{
+    let mut polygons: Vec<PolygonWithHoles2D<T>> = Vec::new();
+    let mut holes = Vec::new();
+
+    for ring in rings {
+        if ring.len() < 3 {
+            continue;
+        }
+
+        let points: Vec<Point2D<T>> = ring.iter().map(|&index| vertices[index]).collect();
+
+        // The middle of an edge is not on any other ring, which makes it safe
+        // for finding the outline around a hole
+        let two = T::one() + T::one();
+        let probe = Point2D::new(
+            (points[0].x + points[1].x) / two,
+            (points[0].y + points[1].y) / two,
+        );
+
+        let ring = simplify(points);
+
+        match ring.winding() {
+            Some(Winding::CounterClockwise) => polygons.push(ring.into()),
+            Some(Winding::Clockwise) => holes.push((probe, ring)),
+            None => {}
+        }
+    }
+
+    for (probe, hole) in holes {
+        let area = |polygon: &&mut PolygonWithHoles2D<T>| polygon.outline.signed_area();
+
+        let outline = polygons
+            .iter_mut()
+            .filter(|polygon| polygon.outline.contains_point(probe, FillRule::NonZero))
+            .min_by(|a, b| area(a).partial_cmp(&area(b)).unwrap_or(Ordering::Equal));
+
+        if let Some(outline) = outline {
+            outline.holes.push(hole);
+        }
+    }
+
+    MultiPolygon2D { polygons }
+}
+
+/// Removes vertices in a straight line with their neighbours, and starts the ring at its lowest vertex.
+fn simplify<T>(mut points: Vec<Point2D<T>>) -> Polygon2D<T>
+where
+    T: Float,
+{
+    let mut changed = true;
+
+    while changed && points.len() >= 3 {
+        changed = false;
+        let mut index = 0;
+
+        while index < points.len() && points.len() >= 3 {
+            let len = points.len();
+            let previous = points[(index + len - 1) % len];
+            let next = points[(index + 1) % len];
+
+            if previous
+                .offset(points[index])
+                .cross(points[index].offset(next))
+                == T::zero()
+            {
+                points.remove(index);
+                changed = true;
+            } else {
+                index += 1;
+            }
+        }
+    }
+
+    // Rings start at their lowest vertex, so the same area always comes out the same way
+    let lowest = (0..points.len()).min_by(|&a, &b| compare(&points[a], &points[b]));
+    points.rotate_left(lowest.unwrap_or(0));
+
+    Polygon2D::new(points)
+}
+
+fn compare<T>(a: &Point2D<T>, b: &Point2D<T>) -> Ordering
+where
+    T: Float,
+{
+    a.x.partial_cmp(&b.x)
+        .and_then(|ordering| Some(ordering.then(a.y.partial_cmp(&b.y)?)))
+        .unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    fn rectangle(bounds: Bounds2D<f64>) -> Polygon2D<f64> {
+        let (left, top, right, bottom) =
+            (bounds.left(), bounds.top(), bounds.right(), bounds.bottom());
+        polygon![(left, top), (right, top), (right, bottom), (left, bottom)]
+    }
+
+    fn square(x: f64, y: f64, size: f64) -> MultiPolygon2D<f64> {
+        rectangle(Bounds2D::new(x, y, size, size)).into()
+    }
+
+    #[test]
+    fn shared_edges() {
+        let left = square(0.0, 0.0, 10.0);
+        let right = square(10.0, 0.0, 10.0);
+
+        let union = left.union(&right);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union.polygons()[0].outline().len(), 4);
+        assert_eq!(union.area(), 200.0);
+
+        assert!(left.intersection(&right).is_empty());
+        assert_eq!(left.subtract(&right), left);
+        assert_eq!(left.xor(&right), union);
+
+        // Only part of the edge is shared
+        let shifted = square(10.0, 5.0, 10.0);
+        let union = left.union(&shifted);
+
+        assert_eq!(union.len(), 1);
+        assert_eq!(union.polygons()[0].outline().len(), 8);
+        assert_eq!(union.area(), 200.0);
+        assert!(left.intersection(&shifted).is_empty());
+    }
+
+    #[test]
+    fn identical_polygons() {
+        let a = square(0.0, 0.0, 10.0);
+
+        assert_eq!(a.union(&a), a);
+        assert_eq!(a.intersection(&a), a);
+        assert!(a.subtract(&a).is_empty());
+        assert!(a.xor(&a).is_empty());
+
+        // The same area, but with extra vertices on the edges and going the other way
+        let mut b = polygon![
+            (0.0, 0.0),
+            (0.0, 5.0),
+            (0.0, 10.0),
+            (10.0, 10.0),
+            (10.0, 0.0),
+            (5.0, 0.0)
+        ];
+        b.reverse();
+        let b = MultiPolygon2D::from(b);
+
+        assert_eq!(a.union(&b), a);
+        assert!(a.xor(&b).is_empty());
+    }
+
+    #[test]
+    fn touching_corners() {
+        let a = square(0.0, 0.0, 10.0);
+        let b = square(10.0, 10.0, 10.0);
+
+        let union = a.union(&b);
+        assert_eq!(union.len(), 2);
+        assert_eq!(union.area(), 200.0);
+        assert!(a.intersection(&b).is_empty());
+
+        // A square inside another, touching its edge from within
+        let inner = square(0.0, 2.0, 5.0);
+        let notched = a.subtract(&inner);
+
+        assert_eq!(notched.len(), 1);
+        assert!(notched.polygons()[0].holes().is_empty());
+        assert_eq!(notched.area(), 75.0);
+        assert_eq!(a.union(&inner), a);
+    }
+
+    #[test]
+    fn holes() {
+        let square = square(0.0, 0.0, 10.0);
+        let hole = self::square(2.0, 2.0, 6.0);
+        let frame = square.subtract(&hole);
+
+        assert_eq!(frame.polygons()[0].holes().len(), 1);
+        assert_eq!(frame.area(), 64.0);
+
+        // An island inside the hole becomes its own polygon
+        let island = self::square(4.0, 4.0, 2.0);
+        let union = frame.union(&island);
+
+        assert_eq!(union.len(), 2);
+        assert_eq!(union.area(), 68.0);
+        assert!(union.contains_point((5.0, 5.0)));
+        assert!(!union.contains_point((3.0, 3.0)));
+
+        // Cutting across the frame splits it in two
+        let cut = union.subtract(&rectangle(Bounds2D::new(-1.0, 4.5, 12.0, 1.0)).into());
+
+        assert_eq!(cut.len(), 4);
+        assert_eq!(cut.area(), 68.0 - 6.0);
+        assert!(cut
+            .polygons()
+            .iter()
+            .all(|polygon| polygon.holes().is_empty()));
+    }
+
+    #[test]
+    fn crossing_edges() {
+        let square = square(0.0, 0.0, 10.0);
+        let triangle = MultiPolygon2D::from(polygon![(3.0, -5.0), (15.0, 5.0), (3.0, 15.0)]);
+
+        let intersection = square.intersection(&triangle);
+        let expected = 415.0 / 6.0;
+
+        assert_eq!(intersection.len(), 1);
+        assert_eq!(intersection.polygons()[0].outline().len(), 6);
+        assert!((intersection.area() - expected).abs() < 1e-9);
+        assert!((square.union(&triangle).area() - (220.0 - expected)).abs() < 1e-9);
+
+        // The left of the square, two of its corners and the three tips of the triangle are left over
+        assert_eq!(square.xor(&triangle).len(), 6);
+    }
+
+    /// Returns a random polygon going around `center`, which may have dents but never crosses itself.
+    fn star(random: &mut Random, center: (f64, f64), vertices: usize) -> Polygon2D<f64> {
+        Polygon2D::new((0..vertices).map(|index| {
+            let angle = Angle::radians(
+                (index as f64 + random.float(0.9)) / vertices as f64 * std::f64::consts::TAU,
+            );
+            let radius = random.float(8.0) + 2.0;
+            let (sin, cos) = angle.sin_cos();
+
+            (center.0 + cos * radius, center.1 + sin * radius)
+        }))
+    }
+
+    #[test]
+    fn random_polygons() {
+        let mut random = Random::new(2023);
+
+        for _ in 0..50 {
+            let operand = |random: &mut Random| {
+                let center = (random.float(10.0), random.float(10.0));
+                let vertices = random.below(20) as usize + 3;
+
+                MultiPolygon2D::from(star(random, center, vertices))
+            };
+
+            let (a, b) = (operand(&mut random), operand(&mut random));
+
+            let union = a.union(&b);
+            let intersection = a.intersection(&b);
+            let subtract = a.subtract(&b);
+            let xor = a.xor(&b);
+
+            let close = |x: f64, y: f64| (x - y).abs() < 1e-9 * (1.0 + x.abs());
+
+            assert!(close(
+                union.area() + intersection.area(),
+                a.area() + b.area()
+            ));
+            assert!(close(subtract.area(), a.area() - intersection.area()));
+            assert!(close(xor.area(), union.area() - intersection.area()));
+
+            for _ in 0..100 {
+                let point = (random.float(30.0) - 10.0, random.float(30.0) - 10.0);
+                let (in_a, in_b) = (a.contains_point(point), b.contains_point(point));
+
+                assert_eq!(union.contains_point(point), in_a || in_b);
+                assert_eq!(intersection.contains_point(point), in_a && in_b);
+                assert_eq!(subtract.contains_point(point), in_a && !in_b);
+                assert_eq!(xor.contains_point(point), in_a != in_b);
+            }
+        }
+    }
+
+    #[test]
+    fn matches_region() {
+        let mut random = Random::new(23);
+        let mut random = move |max: u32| random.below(max) as f64;
+
+        for _ in 0..20 {
+            let mut operands = Vec::new();
+
+            for _ in 0..2 {
+                let mut polygons = MultiPolygon2D::default();
+                let mut region = Region2D::new();
+
+                for _ in 0..4 {
+                    let bounds =
+                        Bounds2D::new(random(12), random(12), random(6) + 1.0, random(6) + 1.0);
+
+                    polygons = polygons.union(&rectangle(bounds).into());
+                    region = region.union(&bounds.into());
+                }
+
+                operands.push((polygons, region));
+            }
+
+            let [(a, region_a), (b, region_b)] = [operands[0].clone(), operands[1].clone()];
+
+            let results = [
+                (a.union(&b), region_a.union(&region_b)),
+                (a.intersection(&b), region_a.intersection(&region_b)),
+                (a.subtract(&b), region_a.subtract(&region_b)),
+                (a.xor(&b), region_a.xor(&region_b)),
+            ];
+
+            for (polygons, region) in results {
+                let area: f64 = region.iter().map(|bounds| bounds.area()).sum();
+                assert_eq!(polygons.area(), area);
+
+                for x in 0..20 {
+                    for y in 0..20 {
+                        let point = (x as f64 + 0.5, y as f64 + 0.5);
+                        assert_eq!(polygons.contains_point(point), region.contains_point(point));
+                    }
+                }
+            }
+        }
+    }
+}
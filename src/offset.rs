@@ -1,49 +1,78 @@
 use std::ops::{Add, Mul, Sub};
 
-use crate::{ToVector2D, Vector2D};
+use num_traits::{Float, FloatConst};
+
+use crate::{Angle, ToVector2D, ToVector3D, ToVectorN, UnknownUnit, Vector2D, Vector3D, VectorN};
 
 /// Marker struct for a vector used as a translation or velocity.
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub struct Offset;
 
 /// A two-dimensional vector representing an offset.
-pub type Offset2D<T> = Vector2D<T, Offset>;
+pub type Offset2D<T, U = UnknownUnit> = Vector2D<T, Offset, U>;
+
+impl<T, U> Offset2D<T, U>
+where
+    T: Float + FloatConst,
+{
+    /// Returns a new [Offset2D] pointing in the direction of `angle`, with the given `length`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let velocity = Offset2D::from_angle(Angle::degrees(0.0), 4.0);
+    ///
+    /// assert_eq!(velocity, offset!(4.0, 0.0));
+    /// ```
+    pub fn from_angle(angle: Angle<T>, length: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos * length, sin * length)
+    }
+}
 
-impl<T, Rhs> Add<Rhs> for Offset2D<T>
+impl<T, const N: usize, U, Rhs> Add<Rhs> for VectorN<T, N, Offset, U>
 where
-    Rhs: ToOffset2D<T>,
+    Rhs: ToVectorN<T, N, Offset, U>,
     T: Add<Output = T>,
 {
-    type Output = Offset2D<T>;
+    type Output = VectorN<T, N, Offset, U>;
 
     fn add(self, rhs: Rhs) -> Self::Output {
         self.add_components(rhs)
     }
 }
 
-impl<T, Rhs> Sub<Rhs> for Offset2D<T>
+impl<T, const N: usize, U, Rhs> Sub<Rhs> for VectorN<T, N, Offset, U>
 where
-    Rhs: ToOffset2D<T>,
+    Rhs: ToVectorN<T, N, Offset, U>,
     T: Sub<Output = T>,
 {
-    type Output = Offset2D<T>;
+    type Output = VectorN<T, N, Offset, U>;
 
     fn sub(self, rhs: Rhs) -> Self::Output {
         self.sub_components(rhs)
     }
 }
 
-impl<T> Mul<T> for Offset2D<T>
+impl<T, const N: usize, U> Mul<T> for VectorN<T, N, Offset, U>
 where
     T: Copy + Mul<Output = T>,
 {
-    type Output = Offset2D<T>;
+    type Output = VectorN<T, N, Offset, U>;
 
     fn mul(self, rhs: T) -> Self::Output {
-        Offset2D::new(self.x * rhs, self.y * rhs)
+        self.map(|component| component * rhs)
     }
 }
 
 /// Trait alias for [ToVector2D] where `Kind` is [Offset].
-pub trait ToOffset2D<T>: ToVector2D<T, Offset> {}
-impl<T, V: ToVector2D<T, Offset>> ToOffset2D<T> for V {}
+pub trait ToOffset2D<T, U = UnknownUnit>: ToVector2D<T, Offset, U> {}
+impl<T, U, V: ToVector2D<T, Offset, U>> ToOffset2D<T, U> for V {}
+
+/// A three-dimensional vector representing an offset.
+pub type Offset3D<T, U = UnknownUnit> = Vector3D<T, Offset, U>;
+
+/// Trait alias for [ToVector3D] where `Kind` is [Offset].
+pub trait ToOffset3D<T, U = UnknownUnit>: ToVector3D<T, Offset, U> {}
+impl<T, U, V: ToVector3D<T, Offset, U>> ToOffset3D<T, U> for V {}
@@ -0,0 +1,193 @@
+use std::cmp::Ordering;
+
+use num_traits::Num;
+
+use crate::Size2D;
+
+/// A way of ordering sizes.
+///
+/// Sizes have no natural order, so instead of comparing them directly,
+/// pick the order that makes sense for what you are doing.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut sizes = vec![size!(1, 100), size!(50, 1), size!(10, 10)];
+///
+/// sizes.sort_by(|a, b| ByArea.compare(a, b).unwrap());
+/// assert_eq!(sizes, vec![size!(50, 1), size!(1, 100), size!(10, 10)]);
+///
+/// sizes.sort_by(|a, b| ByWidth.compare(a, b).unwrap());
+/// assert_eq!(sizes, vec![size!(1, 100), size!(10, 10), size!(50, 1)]);
+/// ```
+pub trait SizeOrder<T> {
+    /// Compares `a` to `b`, returning [`None`] if they cannot be ordered.
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering>;
+}
+
+impl<T, O> SizeOrder<T> for &O
+where
+    O: SizeOrder<T>,
+{
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering> {
+        (*self).compare(a, b)
+    }
+}
+
+/// Orders sizes by their area.
+///
+/// Different sizes with the same area are ordered as equal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByArea;
+
+impl<T> SizeOrder<T> for ByArea
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering> {
+        a.area().partial_cmp(&b.area())
+    }
+}
+
+/// Orders sizes by their width, ignoring the height.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByWidth;
+
+impl<T> SizeOrder<T> for ByWidth
+where
+    T: PartialOrd,
+{
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering> {
+        a.width.partial_cmp(&b.width)
+    }
+}
+
+/// Orders sizes by their height, ignoring the width.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByHeight;
+
+impl<T> SizeOrder<T> for ByHeight
+where
+    T: PartialOrd,
+{
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering> {
+        a.height.partial_cmp(&b.height)
+    }
+}
+
+/// Orders sizes by their width, and then by their height when the widths are equal.
+///
+/// This is a total order for integers, and only orders
+/// different sizes as equal if they are the same.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lexicographic;
+
+impl<T> SizeOrder<T> for Lexicographic
+where
+    T: PartialOrd,
+{
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering> {
+        match a.width.partial_cmp(&b.width)? {
+            Ordering::Equal => a.height.partial_cmp(&b.height),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+/// Orders sizes by whether one fits inside the other.
+///
+/// A size is less than another if it fits inside it, and greater if it contains it.
+/// When neither fits inside the other, they cannot be ordered.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// # use std::cmp::Ordering;
+/// #
+/// assert_eq!(Dominance.compare(&size!(10, 10), &size!(20, 10)), Some(Ordering::Less));
+/// assert_eq!(Dominance.compare(&size!(10, 20), &size!(20, 10)), None);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Dominance;
+
+impl<T> SizeOrder<T> for Dominance
+where
+    T: PartialOrd,
+{
+    fn compare<U>(&self, a: &Size2D<T, U>, b: &Size2D<T, U>) -> Option<Ordering> {
+        let width = a.width.partial_cmp(&b.width)?;
+        let height = a.height.partial_cmp(&b.height)?;
+
+        match (width, height) {
+            (width, height) if width == height => Some(width),
+            (Ordering::Equal, ordering) | (ordering, Ordering::Equal) => Some(ordering),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn equal_area() {
+        let tall = size!(1, 100);
+        let wide = size!(50, 2);
+
+        assert_ne!(tall, wide);
+        assert_eq!(ByArea.compare(&tall, &wide), Some(Ordering::Equal));
+        assert_eq!(ByArea.compare(&tall, &size!(10, 11)), Some(Ordering::Less));
+        assert_eq!(
+            ByArea.compare(&size!(0, 5), &size!(5, 0)),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn lexicographic() {
+        let tall = size!(1, 100);
+        let wide = size!(50, 2);
+
+        assert_eq!(Lexicographic.compare(&tall, &wide), Some(Ordering::Less));
+        assert_eq!(Lexicographic.compare(&wide, &tall), Some(Ordering::Greater));
+        assert_eq!(
+            Lexicographic.compare(&size!(1, 2), &size!(1, 3)),
+            Some(Ordering::Less)
+        );
+        assert_eq!(Lexicographic.compare(&tall, &tall), Some(Ordering::Equal));
+        assert_eq!(
+            Lexicographic.compare(&size!(f64::NAN, 1.0), &size!(1.0, 1.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn dominance() {
+        let tall = size!(1, 100);
+        let wide = size!(50, 2);
+
+        assert_eq!(Dominance.compare(&tall, &wide), None);
+        assert_eq!(Dominance.compare(&wide, &tall), None);
+        assert_eq!(Dominance.compare(&tall, &size!(50, 1)), None);
+        assert_eq!(Dominance.compare(&tall, &tall), Some(Ordering::Equal));
+        assert_eq!(
+            Dominance.compare(&size!(1, 50), &tall),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            Dominance.compare(&size!(2, 100), &tall),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Dominance.compare(&size!(60, 3), &wide),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            Dominance.compare(&size!(f64::NAN, 1.0), &size!(1.0, 1.0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,613 @@
+use crate::{Bounds2D, Point2D, Size2D};
+
+/// A way of finding free space for rectangles in an [Atlas].
+///
+/// Strategies only deal with the space they are given, padding and
+/// rotation are handled by the [Atlas] before the strategy is asked.
+pub trait PackingStrategy {
+    /// Clears all placed rectangles, and sets the size of the space to pack into.
+    fn reset(&mut self, size: Size2D<u32>);
+
+    /// Finds a position for a rectangle of `size`, together with a score
+    /// describing how good the position is, where lower is better.
+    ///
+    /// Returns [`None`] if there is no room for the rectangle.
+    fn find(&self, size: Size2D<u32>) -> Option<(Point2D<u32>, (u64, u64))>;
+
+    /// Marks the space covered by `bounds` as used.
+    fn reserve(&mut self, bounds: Bounds2D<u32>);
+}
+
+/// Where a rectangle ended up in an [Atlas].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Placement {
+    /// The space covered by the rectangle, not including padding.
+    /// If the rectangle was rotated, the width and height are swapped.
+    pub bounds: Bounds2D<u32>,
+    /// Whether the rectangle was rotated by 90 degrees to fit.
+    pub rotated: bool,
+}
+
+/// Packs rectangles into a fixed size, like sprites or glyphs into a texture atlas.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut atlas = Atlas::new(size!(64, 64), MaxRects::default()).with_padding(2);
+///
+/// let placements = atlas.pack(&[size!(30, 30), size!(30, 30), size!(62, 30)]);
+/// assert!(placements.iter().all(|placement| placement.is_some()));
+///
+/// // More rectangles can be added later on
+/// assert!(atlas.insert(size!(10, 10)).is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Atlas<S> {
+    size: Size2D<u32>,
+    padding: u32,
+    rotation: bool,
+    strategy: S,
+}
+
+impl<S> Atlas<S>
+where
+    S: PackingStrategy,
+{
+    /// Creates a new, empty [Atlas] of `size`, using `strategy` to place rectangles.
+    pub fn new(size: Size2D<u32>, mut strategy: S) -> Self {
+        strategy.reset(size);
+
+        Self {
+            size,
+            padding: 0,
+            rotation: false,
+            strategy,
+        }
+    }
+
+    /// Sets the space to keep between rectangles.
+    ///
+    /// The padding is added to the right and bottom of every rectangle,
+    /// so rectangles can still be placed against the edges of the atlas.
+    /// This clears the atlas, so it should be set right away.
+    pub fn with_padding(mut self, padding: u32) -> Self {
+        self.padding = padding;
+        self.clear();
+        self
+    }
+
+    /// Sets whether rectangles may be rotated by 90 degrees when it results in a better fit.
+    pub fn with_rotation(mut self, rotation: bool) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Returns the size of the atlas.
+    pub fn size(&self) -> Size2D<u32> {
+        self.size
+    }
+
+    /// Removes all rectangles from the atlas.
+    pub fn clear(&mut self) {
+        // Nothing can be placed past the largest u32 anyway
+        let padded = Size2D::new(
+            self.size.width.saturating_add(self.padding),
+            self.size.height.saturating_add(self.padding),
+        );
+
+        self.strategy.reset(padded);
+    }
+
+    /// Returns `size` with the padding added, or [`None`] if it does not fit in a u32.
+    fn padded(&self, size: Size2D<u32>) -> Option<Size2D<u32>> {
+        Some(Size2D::new(
+            size.width.checked_add(self.padding)?,
+            size.height.checked_add(self.padding)?,
+        ))
+    }
+
+    /// Places a rectangle of `size` in the atlas,
+    /// or returns [`None`] if there is no room left for it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let mut atlas = Atlas::new(size!(100, 20), Skyline::default()).with_rotation(true);
+    ///
+    /// let placement = atlas.insert(size!(20, 50)).unwrap();
+    ///
+    /// assert!(placement.rotated);
+    /// assert_eq!(placement.bounds, bounds!(0, 0, 50, 20));
+    /// ```
+    pub fn insert(&mut self, size: Size2D<u32>) -> Option<Placement> {
+        if size.width == 0 || size.height == 0 {
+            return Some(Placement {
+                bounds: Bounds2D::from_position_and_size((0, 0), size),
+                rotated: false,
+            });
+        }
+
+        let padded = self.padded(size)?;
+        let upright = self.strategy.find(padded);
+
+        let rotated = if self.rotation && size.width != size.height {
+            self.strategy.find(Size2D::new(padded.height, padded.width))
+        } else {
+            None
+        };
+
+        let (position, rotated) = match (upright, rotated) {
+            (Some(upright), Some(rotated)) if rotated.1 < upright.1 => (rotated.0, true),
+            (Some(upright), _) => (upright.0, false),
+            (None, Some(rotated)) => (rotated.0, true),
+            (None, None) => return None,
+        };
+
+        let (size, padded) = if rotated {
+            (
+                Size2D::new(size.height, size.width),
+                Size2D::new(padded.height, padded.width),
+            )
+        } else {
+            (size, padded)
+        };
+
+        self.strategy
+            .reserve(Bounds2D::from_position_and_size(position, padded));
+
+        Some(Placement {
+            bounds: Bounds2D::from_position_and_size(position, size),
+            rotated,
+        })
+    }
+
+    /// Places all of `sizes` in the atlas, returning the placements in the same order.
+    ///
+    /// The rectangles are inserted from largest to smallest, which packs a lot tighter
+    /// than inserting them as they come. Rectangles that do not fit are [`None`].
+    pub fn pack(&mut self, sizes: &[Size2D<u32>]) -> Vec<Option<Placement>> {
+        let mut order: Vec<_> = (0..sizes.len()).collect();
+
+        order.sort_by_key(|&index| {
+            let size = sizes[index];
+            let longest = size.width.max(size.height);
+
+            std::cmp::Reverse((longest, size.width as u64 * size.height as u64))
+        });
+
+        let mut placements = vec![None; sizes.len()];
+
+        for index in order {
+            placements[index] = self.insert(sizes[index]);
+        }
+
+        placements
+    }
+}
+
+/// Returns `true` if something of `length` placed at `start` ends before `end`,
+/// treating lengths that overflow as not fitting.
+fn fits_after(start: u32, length: u32, end: u32) -> bool {
+    start.checked_add(length).is_some_and(|sum| sum <= end)
+}
+
+/// Places rectangles in rows, where each row is as tall as the first rectangle placed in it.
+///
+/// This is the fastest strategy, and works well when the rectangles have similar heights,
+/// like glyphs of the same font.
+#[derive(Debug, Default, Clone)]
+pub struct Shelf {
+    size: Size2D<u32>,
+    shelves: Vec<ShelfRow>,
+}
+
+#[derive(Debug, Clone)]
+struct ShelfRow {
+    y: u32,
+    height: u32,
+    used: u32,
+}
+
+impl PackingStrategy for Shelf {
+    fn reset(&mut self, size: Size2D<u32>) {
+        self.size = size;
+        self.shelves.clear();
+    }
+
+    fn find(&self, size: Size2D<u32>) -> Option<(Point2D<u32>, (u64, u64))> {
+        let existing = self
+            .shelves
+            .iter()
+            .filter(|shelf| {
+                size.height <= shelf.height && fits_after(shelf.used, size.width, self.size.width)
+            })
+            .map(|shelf| {
+                let waste = (shelf.height - size.height) as u64;
+                (Point2D::new(shelf.used, shelf.y), (waste, shelf.y as u64))
+            })
+            .min_by_key(|(_, score)| *score);
+
+        if existing.is_some() {
+            return existing;
+        }
+
+        let y = self
+            .shelves
+            .last()
+            .map(|shelf| shelf.y + shelf.height)
+            .unwrap_or(0);
+
+        let fits = size.width <= self.size.width && fits_after(y, size.height, self.size.height);
+
+        // Opening a new shelf is always worse than using an existing one
+        fits.then(|| {
+            let score = (u32::MAX as u64 + size.height as u64, y as u64);
+            (Point2D::new(0, y), score)
+        })
+    }
+
+    fn reserve(&mut self, bounds: Bounds2D<u32>) {
+        let existing = self
+            .shelves
+            .iter_mut()
+            .find(|shelf| shelf.y == bounds.top() && bounds.height() <= shelf.height);
+
+        match existing {
+            Some(shelf) => shelf.used = shelf.used.max(bounds.right()),
+            None => self.shelves.push(ShelfRow {
+                y: bounds.top(),
+                height: bounds.height(),
+                used: bounds.right(),
+            }),
+        }
+    }
+}
+
+/// Places rectangles as low as possible on top of the outline of the rectangles already placed.
+///
+/// This packs tighter than [Shelf] when heights vary, while staying fast.
+#[derive(Debug, Default, Clone)]
+pub struct Skyline {
+    size: Size2D<u32>,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    x: u32,
+    y: u32,
+    width: u32,
+}
+
+impl Skyline {
+    /// Returns the height a rectangle of `size` would be placed at,
+    /// if its left edge is at the start of the segment at `index`.
+    fn fit(&self, index: usize, size: Size2D<u32>) -> Option<u32> {
+        let x = self.segments[index].x;
+
+        if !fits_after(x, size.width, self.size.width) {
+            return None;
+        }
+
+        let mut covered = 0;
+        let mut y = 0;
+
+        for segment in &self.segments[index..] {
+            if covered >= size.width {
+                break;
+            }
+
+            y = y.max(segment.y);
+            covered += segment.width;
+        }
+
+        fits_after(y, size.height, self.size.height).then_some(y)
+    }
+}
+
+impl PackingStrategy for Skyline {
+    fn reset(&mut self, size: Size2D<u32>) {
+        self.size = size;
+        self.segments = vec![Segment {
+            x: 0,
+            y: 0,
+            width: size.width,
+        }];
+    }
+
+    fn find(&self, size: Size2D<u32>) -> Option<(Point2D<u32>, (u64, u64))> {
+        (0..self.segments.len())
+            .filter_map(|index| {
+                let x = self.segments[index].x;
+                let y = self.fit(index, size)?;
+
+                Some((Point2D::new(x, y), ((y + size.height) as u64, x as u64)))
+            })
+            .min_by_key(|(_, score)| *score)
+    }
+
+    fn reserve(&mut self, bounds: Bounds2D<u32>) {
+        let (left, right) = (bounds.left(), bounds.right());
+        let mut segments = Vec::with_capacity(self.segments.len() + 2);
+
+        for segment in &self.segments {
+            let end = segment.x + segment.width;
+
+            if end <= left || segment.x >= right {
+                segments.push(*segment);
+                continue;
+            }
+
+            if segment.x < left {
+                segments.push(Segment {
+                    width: left - segment.x,
+                    ..*segment
+                });
+            }
+
+            if end > right {
+                segments.push(Segment {
+                    x: right,
+                    y: segment.y,
+                    width: end - right,
+                });
+            }
+        }
+
+        segments.push(Segment {
+            x: left,
+            y: bounds.bottom(),
+            width: bounds.width(),
+        });
+        segments.sort_by_key(|segment| segment.x);
+
+        // Neighbouring segments at the same height are merged
+        self.segments.clear();
+
+        for segment in segments {
+            match self.segments.last_mut() {
+                Some(last) if last.y == segment.y => last.width += segment.width,
+                _ => self.segments.push(segment),
+            }
+        }
+    }
+}
+
+/// How [MaxRects] picks between the free rectangles a rectangle fits in.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MaxRectsHeuristic {
+    /// Picks the free rectangle where the shortest leftover side is the smallest.
+    #[default]
+    BestShortSideFit,
+    /// Picks the free rectangle with the smallest area.
+    BestAreaFit,
+}
+
+/// Keeps track of every maximal free rectangle, and places rectangles in the best one.
+///
+/// This packs the tightest out of the strategies, but is also the slowest,
+/// which makes it a good fit for atlases that are packed once.
+#[derive(Debug, Default, Clone)]
+pub struct MaxRects {
+    heuristic: MaxRectsHeuristic,
+    free: Vec<Bounds2D<u32>>,
+}
+
+impl MaxRects {
+    /// Creates a new [MaxRects] strategy using `heuristic`.
+    pub fn new(heuristic: MaxRectsHeuristic) -> Self {
+        Self {
+            heuristic,
+            free: Vec::new(),
+        }
+    }
+}
+
+impl PackingStrategy for MaxRects {
+    fn reset(&mut self, size: Size2D<u32>) {
+        self.free = vec![Bounds2D::from_position_and_size((0, 0), size)];
+    }
+
+    fn find(&self, size: Size2D<u32>) -> Option<(Point2D<u32>, (u64, u64))> {
+        self.free
+            .iter()
+            .filter(|free| size.fits_in(free.size()))
+            .map(|free| {
+                let leftover_width = (free.width() - size.width) as u64;
+                let leftover_height = (free.height() - size.height) as u64;
+
+                let short = leftover_width.min(leftover_height);
+                let long = leftover_width.max(leftover_height);
+
+                let score = match self.heuristic {
+                    MaxRectsHeuristic::BestShortSideFit => (short, long),
+                    MaxRectsHeuristic::BestAreaFit => {
+                        let area = free.width() as u64 * free.height() as u64;
+                        let used = size.width as u64 * size.height as u64;
+
+                        (area - used, short)
+                    }
+                };
+
+                (free.position(), score)
+            })
+            .min_by_key(|(_, score)| *score)
+    }
+
+    fn reserve(&mut self, bounds: Bounds2D<u32>) {
+        let mut free = Vec::with_capacity(self.free.len() + 4);
+
+        for rect in self.free.drain(..) {
+            if !rect.intersects(bounds) {
+                free.push(rect);
+                continue;
+            }
+
+            // Unlike subtracting bounds, each leftover piece is as large as possible,
+            // so the pieces overlap each other.
+            let pieces = [
+                ((rect.left(), rect.top()), (rect.right(), bounds.top())),
+                (
+                    (rect.left(), bounds.bottom()),
+                    (rect.right(), rect.bottom()),
+                ),
+                ((rect.left(), rect.top()), (bounds.left(), rect.bottom())),
+                ((bounds.right(), rect.top()), (rect.right(), rect.bottom())),
+            ];
+
+            for (min, max) in pieces {
+                if min.0 < max.0 && min.1 < max.1 {
+                    free.push(Bounds2D::from_corners(min, max));
+                }
+            }
+        }
+
+        // Free rectangles inside other free rectangles are redundant
+        let mut index = 0;
+
+        while index < free.len() {
+            let rect = free[index];
+            let redundant = free.iter().enumerate().any(|(other, bounds)| {
+                other != index && bounds.contains_bounds(rect) && (bounds != &rect || other < index)
+            });
+
+            if redundant {
+                free.swap_remove(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        self.free = free;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    fn sizes() -> Vec<Size2D<u32>> {
+        let mut random = Random::new(12345);
+        let mut next = move |max: u32| random.below(max) + 1;
+
+        (0..120).map(|_| Size2D::new(next(40), next(40))).collect()
+    }
+
+    fn check<S: PackingStrategy>(strategy: S, rotation: bool) {
+        let sizes = sizes();
+        let mut atlas = Atlas::new(size!(256, 256), strategy)
+            .with_padding(1)
+            .with_rotation(rotation);
+
+        let placements = atlas.pack(&sizes);
+        let placed: Vec<_> = placements.iter().flatten().collect();
+
+        assert!(placed.len() > 40, "only placed {}", placed.len());
+
+        for (index, placement) in placements.iter().enumerate() {
+            let Some(placement) = placement else {
+                continue;
+            };
+
+            let size = sizes[index];
+            let expected = if placement.rotated {
+                size!(size.height, size.width)
+            } else {
+                size
+            };
+
+            assert_eq!(placement.bounds.size(), expected);
+            assert!(bounds!(0, 0, 256, 256).contains_bounds(placement.bounds));
+        }
+
+        for (i, a) in placed.iter().enumerate() {
+            for b in &placed[i + 1..] {
+                assert!(
+                    !(a.bounds + size!(1, 1)).intersects(b.bounds),
+                    "{a:?} overlaps {b:?}"
+                );
+                assert!(
+                    !(b.bounds + size!(1, 1)).intersects(a.bounds),
+                    "{b:?} overlaps {a:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn shelf() {
+        check(Shelf::default(), false);
+        check(Shelf::default(), true);
+    }
+
+    #[test]
+    fn skyline() {
+        check(Skyline::default(), false);
+        check(Skyline::default(), true);
+    }
+
+    #[test]
+    fn max_rects() {
+        check(MaxRects::new(MaxRectsHeuristic::BestShortSideFit), false);
+        check(MaxRects::new(MaxRectsHeuristic::BestShortSideFit), true);
+        check(MaxRects::new(MaxRectsHeuristic::BestAreaFit), false);
+        check(MaxRects::new(MaxRectsHeuristic::BestAreaFit), true);
+    }
+
+    #[test]
+    fn fills_exactly() {
+        let mut atlas = Atlas::new(size!(4, 4), MaxRects::default());
+
+        for _ in 0..4 {
+            assert!(atlas.insert(size!(2, 2)).is_some());
+        }
+
+        assert_eq!(atlas.insert(size!(1, 1)), None);
+
+        atlas.clear();
+        assert_eq!(
+            atlas.insert(size!(4, 4)).map(|placement| placement.bounds),
+            Some(bounds!(0, 0, 4, 4))
+        );
+    }
+
+    fn edge_cases<S: PackingStrategy + Clone>(strategy: S) {
+        // An empty atlas has no room for anything with an area
+        let mut atlas = Atlas::new(size!(0, 0), strategy.clone());
+
+        assert_eq!(atlas.insert(size!(1, 1)), None);
+        assert_eq!(
+            atlas.insert(size!(0, 5)).map(|placement| placement.bounds),
+            Some(bounds!(0, 0, 0, 5))
+        );
+        assert!(atlas.pack(&[]).is_empty());
+
+        // Sizes that overflow when padded or placed do not fit
+        let mut atlas = Atlas::new(size!(64, 64), strategy.clone())
+            .with_padding(1)
+            .with_rotation(true);
+
+        assert_eq!(atlas.insert(size!(u32::MAX, 1)), None);
+        assert_eq!(atlas.insert(size!(1, u32::MAX)), None);
+        assert!(atlas.insert(size!(10, 10)).is_some());
+        assert_eq!(atlas.insert(size!(u32::MAX - 5, 1)), None);
+
+        // The largest atlas can still be padded
+        let mut atlas = Atlas::new(size!(u32::MAX, u32::MAX), strategy).with_padding(4);
+
+        assert!(atlas.insert(size!(10, 10)).is_some());
+        assert!(atlas.insert(size!(u32::MAX - 20, 10)).is_some());
+        assert!(atlas.insert(size!(100, 10)).is_some());
+    }
+
+    #[test]
+    fn empty_and_overflowing() {
+        edge_cases(Shelf::default());
+        edge_cases(Skyline::default());
+        edge_cases(MaxRects::new(MaxRectsHeuristic::BestShortSideFit));
+        edge_cases(MaxRects::new(MaxRectsHeuristic::BestAreaFit));
+    }
+}
@@ -1,8 +1,11 @@
 use std::ops::{Add, Sub};
 
-use num_traits::Num;
+use num_traits::{Float, FloatConst, Num};
 
-use crate::{Bounds2D, Offset2D, ToOffset2D, ToSize2D, ToVector2D, Vector2D};
+use crate::{
+    Angle, Bounds2D, Bounds3D, Offset, ToSize2D, ToSize3D, ToVector2D, ToVector3D, ToVectorN,
+    UnknownUnit, Vector2D, Vector3D, VectorN,
+};
 
 /// Marker struct for a vector used as a point.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
@@ -24,9 +27,9 @@ pub struct Point;
 /// let moved_point = point + (20, 5);
 /// assert_eq!(moved_point, point!(30, 5));
 /// ```
-pub type Point2D<T> = Vector2D<T, Point>;
+pub type Point2D<T, U = UnknownUnit> = Vector2D<T, Point, U>;
 
-impl<T> Point2D<T> {
+impl<T, const N: usize, U> VectorN<T, N, Point, U> {
     /// Returns the offset between `self` and `point`.
     ///
     /// Order matters here, so if you are trying to get the offset
@@ -40,15 +43,18 @@ impl<T> Point2D<T> {
     /// let b = point!(5, 60);
     ///
     /// assert_eq!(a.offset(b), offset!(-5, 20));
+    /// assert_eq!(point!(0, 0, 0).offset((1, 2, 3)), offset!(1, 2, 3));
     /// ```
-    pub fn offset<P: ToPoint2D<T>>(self, point: P) -> Offset2D<T>
+    pub fn offset<P: ToVectorN<T, N, Point, U>>(self, point: P) -> VectorN<T, N, Offset, U>
     where
         T: Sub<Output = T>,
     {
-        let point = point.to_vector();
-        Offset2D::new(point.x - self.x, point.y - self.y)
+        let offset = point.to_vector().zip_map(self, |a, b| a - b);
+        VectorN::from_array(offset.to_array())
     }
+}
 
+impl<T, U> Point2D<T, U> {
     /// Returns a new [Bounds2D] using `self` as position,
     /// and `size` as the size.
     ///
@@ -60,32 +66,51 @@ impl<T> Point2D<T> {
     ///
     /// assert_eq!(bounds, bounds!(20, 30, 50, 50));
     /// ```
-    pub fn with_size<S: ToSize2D<T>>(self, size: S) -> Bounds2D<T>
+    pub fn with_size<S: ToSize2D<T, U>>(self, size: S) -> Bounds2D<T, U>
     where
         T: Num + Copy,
     {
         Bounds2D::from_position_and_size(self, size)
     }
+
+    /// Returns `self` rotated counter-clockwise by `angle` around `pivot`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let point = point!(3.0, 1.0);
+    /// let rotated = point.rotate_around((1.0, 1.0), Angle::degrees(180.0));
+    ///
+    /// assert!(rotated.euclidean_distance((-1.0, 1.0)) < 1e-10);
+    /// ```
+    pub fn rotate_around<P: ToPoint2D<T, U>>(&self, pivot: P, angle: Angle<T>) -> Point2D<T, U>
+    where
+        T: Float + FloatConst,
+    {
+        let pivot = pivot.to_vector();
+        pivot + pivot.offset(*self).rotate(angle)
+    }
 }
 
-impl<T, Rhs> Add<Rhs> for Point2D<T>
+impl<T, const N: usize, U, Rhs> Add<Rhs> for VectorN<T, N, Point, U>
 where
-    Rhs: ToOffset2D<T>,
+    Rhs: ToVectorN<T, N, Offset, U>,
     T: Add<Output = T>,
 {
-    type Output = Point2D<T>;
+    type Output = VectorN<T, N, Point, U>;
 
     fn add(self, rhs: Rhs) -> Self::Output {
         self.add_components(rhs)
     }
 }
 
-impl<T, Rhs> Sub<Rhs> for Point2D<T>
+impl<T, const N: usize, U, Rhs> Sub<Rhs> for VectorN<T, N, Point, U>
 where
-    Rhs: ToOffset2D<T>,
+    Rhs: ToVectorN<T, N, Offset, U>,
     T: Sub<Output = T>,
 {
-    type Output = Point2D<T>;
+    type Output = VectorN<T, N, Point, U>;
 
     fn sub(self, rhs: Rhs) -> Self::Output {
         self.sub_components(rhs)
@@ -93,5 +118,43 @@ where
 }
 
 /// Trait alias for [ToVector2D] where `Kind` is [Point].
-pub trait ToPoint2D<T>: ToVector2D<T, Point> {}
-impl<T, V: ToVector2D<T, Point>> ToPoint2D<T> for V {}
+pub trait ToPoint2D<T, U = UnknownUnit>: ToVector2D<T, Point, U> {}
+impl<T, U, V: ToVector2D<T, Point, U>> ToPoint2D<T, U> for V {}
+
+/// A three-dimensional vector representing a point.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let point = point!(10, 0, 5);
+///
+/// // Just like Point2D, a point can be moved with an offset
+/// let moved_point = point + offset!(20, 5, 5);
+/// assert_eq!(moved_point, point!(30, 5, 10));
+/// ```
+pub type Point3D<T, U = UnknownUnit> = Vector3D<T, Point, U>;
+
+impl<T> Point3D<T> {
+    /// Returns a new [Bounds3D] using `self` as position,
+    /// and `size` as the size.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = point!(20u32, 30, 40).with_size(size!(50; 3));
+    ///
+    /// assert_eq!(bounds, bounds!(20, 30, 40, 50, 50, 50));
+    /// ```
+    pub fn with_size<S: ToSize3D<T>>(self, size: S) -> Bounds3D<T>
+    where
+        T: Num + Copy,
+    {
+        Bounds3D::from_position_and_size(self, size)
+    }
+}
+
+/// Trait alias for [ToVector3D] where `Kind` is [Point].
+pub trait ToPoint3D<T, U = UnknownUnit>: ToVector3D<T, Point, U> {}
+impl<T, U, V: ToVector3D<T, Point, U>> ToPoint3D<T, U> for V {}
@@ -0,0 +1,331 @@
+use num_traits::{Float, Num};
+
+use crate::{Bounds2D, Point2D, Segment2D, ToPoint2D};
+
+/// The direction the vertices of a [Polygon2D] go around in.
+///
+/// This follows the usual mathematical convention where Y points up.
+/// With Y pointing down, as on a screen, the directions appear mirrored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Winding {
+    Clockwise,
+    CounterClockwise,
+}
+
+/// Decides which points are inside a [Polygon2D] that crosses itself.
+///
+/// Both rules agree for polygons that do not cross themselves.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// A point is inside if a line from it crosses the outline an odd number of times.
+    #[default]
+    EvenOdd,
+    /// A point is inside if the outline goes around it at least once.
+    NonZero,
+}
+
+/// A closed shape made of straight edges between vertices.
+///
+/// The last vertex connects back to the first, so it should not be repeated.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut outline = polygon![(0, 0), (10, 0), (10, 10), (0, 10)];
+///
+/// assert_eq!(outline.signed_area(), 100);
+/// assert_eq!(outline.winding(), Some(Winding::CounterClockwise));
+/// assert_eq!(outline.bounds(), Some(bounds!(0, 0, 10, 10)));
+/// assert!(outline.is_convex());
+///
+/// outline.reverse();
+/// assert_eq!(outline.signed_area(), -100);
+/// assert!(outline.contains_point((5, 5), FillRule::EvenOdd));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon2D<T> {
+    vertices: Vec<Point2D<T>>,
+}
+
+impl<T> Polygon2D<T> {
+    /// Creates a new [Polygon2D] from `vertices`.
+    pub fn new<I, P>(vertices: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: ToPoint2D<T>,
+    {
+        Self {
+            vertices: vertices.into_iter().map(|vertex| vertex.to_vector()).collect(),
+        }
+    }
+
+    /// Returns the vertices of the polygon.
+    pub fn vertices(&self) -> &[Point2D<T>] {
+        &self.vertices
+    }
+
+    /// Returns the number of vertices.
+    pub fn len(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// Returns `true` if the polygon has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Reverses the order of the vertices, and with it the [Winding].
+    pub fn reverse(&mut self) {
+        self.vertices.reverse();
+    }
+
+    /// Returns every edge of the polygon, including the one from the last vertex to the first.
+    pub fn edges(&self) -> impl Iterator<Item = Segment2D<T>> + '_
+    where
+        T: Copy,
+    {
+        let next = self.vertices.iter().cycle().skip(1);
+
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(&start, &end)| Segment2D { start, end })
+    }
+}
+
+impl<T> Polygon2D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Returns twice the signed area of the polygon, which is exact for integers.
+    ///
+    /// See [`signed_area()`](crate::Polygon2D::signed_area) for the sign.
+    pub fn doubled_signed_area(&self) -> T {
+        self.edges()
+            .fold(T::zero(), |sum, edge| sum + cross(edge.start, edge.end))
+    }
+
+    /// Returns the signed area of the polygon, which is positive if the vertices
+    /// go around [`Winding::CounterClockwise`] and negative if they go around clockwise.
+    ///
+    /// For integers, the area is rounded towards zero.
+    pub fn signed_area(&self) -> T {
+        self.doubled_signed_area() / (T::one() + T::one())
+    }
+
+    /// Returns the direction the vertices go around in,
+    /// or [`None`] if the polygon has no area.
+    pub fn winding(&self) -> Option<Winding> {
+        let area = self.doubled_signed_area();
+
+        if area > T::zero() {
+            Some(Winding::CounterClockwise)
+        } else if area < T::zero() {
+            Some(Winding::Clockwise)
+        } else {
+            None
+        }
+    }
+
+    /// Reverses the vertices if needed, so that they go around in `winding`.
+    pub fn set_winding(&mut self, winding: Winding) {
+        if self.winding().is_some_and(|current| current != winding) {
+            self.reverse();
+        }
+    }
+
+    /// Returns `true` if the polygon is convex, which means every edge turns the same way
+    /// and it does not cross itself. Vertices in a straight line are allowed.
+    pub fn is_convex(&self) -> bool {
+        let mut turn = None;
+        let mut direction_changes = [0, 0];
+        let mut directions = [None, None];
+
+        let edges: Vec<_> = self.edges().collect();
+
+        for (edge, next) in edges.iter().zip(edges.iter().cycle().skip(1)) {
+            let (a, b) = (edge.start.offset(edge.end), next.start.offset(next.end));
+            let cross = a.x * b.y - a.y * b.x;
+
+            if cross != T::zero() {
+                let positive = cross > T::zero();
+
+                if *turn.get_or_insert(positive) != positive {
+                    return false;
+                }
+            }
+
+            // Going around a convex shape, each axis changes direction exactly twice
+            for (axis, value) in [a.x, a.y].into_iter().enumerate() {
+                if value == T::zero() {
+                    continue;
+                }
+
+                let positive = value > T::zero();
+
+                if directions[axis].is_some_and(|previous| previous != positive) {
+                    direction_changes[axis] += 1;
+                }
+
+                directions[axis] = Some(positive);
+            }
+        }
+
+        direction_changes.iter().all(|&changes| changes <= 2)
+    }
+
+    /// Returns the smallest bounds containing every vertex,
+    /// or [`None`] if the polygon has no vertices.
+    pub fn bounds(&self) -> Option<Bounds2D<T>> {
+        let first = *self.vertices.first()?;
+
+        let (min, max) = self.vertices.iter().fold((first, first), |(min, max), v| {
+            let min = Point2D::new(
+                if v.x < min.x { v.x } else { min.x },
+                if v.y < min.y { v.y } else { min.y },
+            );
+            let max = Point2D::new(
+                if v.x > max.x { v.x } else { max.x },
+                if v.y > max.y { v.y } else { max.y },
+            );
+
+            (min, max)
+        });
+
+        Some(Bounds2D::from_corners(min, max))
+    }
+
+    /// Returns `true` if `point` is inside the polygon according to `rule`.
+    ///
+    /// Like [Bounds2D], points on the bottom and right edges are outside,
+    /// so polygons sharing an edge never both contain a point on it.
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P, rule: FillRule) -> bool {
+        let point = point.to_vector();
+        let mut winding = 0i32;
+
+        for edge in self.edges() {
+            let (start, end) = (edge.start, edge.end);
+
+            // Only edges crossing the horizontal line through the point count
+            if (start.y <= point.y) == (end.y <= point.y) {
+                continue;
+            }
+
+            let side =
+                (end.x - start.x) * (point.y - start.y) - (end.y - start.y) * (point.x - start.x);
+            let upwards = end.y > start.y;
+
+            // The crossing is to the right of the point
+            if (upwards && side > T::zero()) || (!upwards && side < T::zero()) {
+                winding += if upwards { 1 } else { -1 };
+            }
+        }
+
+        match rule {
+            FillRule::EvenOdd => winding % 2 != 0,
+            FillRule::NonZero => winding != 0,
+        }
+    }
+}
+
+impl<T> Polygon2D<T>
+where
+    T: Float,
+{
+    /// Returns the length of the outline of the polygon.
+    pub fn perimeter(&self) -> T {
+        self.edges()
+            .fold(T::zero(), |sum, edge| sum + edge.length())
+    }
+
+    /// Returns the center of mass of the polygon, or [`None`] if it has no area.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let triangle = polygon![(0.0, 0.0), (6.0, 0.0), (0.0, 3.0)];
+    ///
+    /// assert_eq!(triangle.centroid(), Some(point!(2.0, 1.0)));
+    /// assert_eq!(polygon![(0.0, 0.0), (1.0, 1.0)].centroid(), None);
+    /// ```
+    pub fn centroid(&self) -> Option<Point2D<T>> {
+        let area = self.doubled_signed_area();
+
+        if area == T::zero() {
+            return None;
+        }
+
+        let (x, y) = self.edges().fold((T::zero(), T::zero()), |(x, y), edge| {
+            let (a, b) = (edge.start, edge.end);
+            let cross = cross(a, b);
+
+            (x + (a.x + b.x) * cross, y + (a.y + b.y) * cross)
+        });
+
+        let three = T::one() + T::one() + T::one();
+        Some(Point2D::new(x / (three * area), y / (three * area)))
+    }
+}
+
+impl<T, P> FromIterator<P> for Polygon2D<T>
+where
+    P: ToPoint2D<T>,
+{
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+fn cross<T: Num + Copy>(a: Point2D<T>, b: Point2D<T>) -> T {
+    a.x * b.y - a.y * b.x
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn self_crossing_polygon() {
+        // A five pointed star, where the pentagon in the middle is wound twice
+        let star = polygon![(0, 10), (6, -8), (-10, 3), (10, 3), (-6, -8)];
+
+        assert!(!star.is_convex());
+        assert!(star.contains_point((0, 6), FillRule::EvenOdd));
+        assert!(star.contains_point((0, 0), FillRule::NonZero));
+        assert!(!star.contains_point((0, 0), FillRule::EvenOdd));
+        assert!(!star.contains_point((10, 10), FillRule::NonZero));
+    }
+
+    #[test]
+    fn shared_edges() {
+        let left = polygon![(0, 0), (5, 0), (5, 5), (0, 5)];
+        let right = polygon![(5, 0), (10, 0), (10, 5), (5, 5)];
+
+        for y in 0..6 {
+            let inside = [&left, &right]
+                .iter()
+                .filter(|polygon| polygon.contains_point((5, y), FillRule::NonZero))
+                .count();
+
+            assert_eq!(inside, if y < 5 { 1 } else { 0 });
+        }
+
+        assert!(left.contains_point((0, 0), FillRule::EvenOdd));
+        assert!(!left.contains_point((0, 5), FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn winding() {
+        let mut square = polygon![(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0)];
+
+        assert_eq!(square.winding(), Some(Winding::Clockwise));
+        assert_eq!(square.perimeter(), 8.0);
+        assert_eq!(square.centroid(), Some(point!(1.0, 1.0)));
+
+        square.set_winding(Winding::CounterClockwise);
+        assert_eq!(square.signed_area(), 4.0);
+        assert_eq!(square.vertices()[0], point!(2.0, 0.0));
+    }
+}
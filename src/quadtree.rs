@@ -0,0 +1,546 @@
+use num_traits::Num;
+
+use crate::{
+    slots::{Key, Slots},
+    Bounds2D, IntoBounds2D, Metric, Point2D, ToPoint2D,
+};
+
+/// Refers to an item in a [QuadTree].
+///
+/// Handles of removed items no longer refer to anything,
+/// even after another item is inserted in their place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct QuadHandle(Key);
+
+#[derive(Debug, Clone)]
+struct Entry<T, V> {
+    bounds: Bounds2D<T>,
+    value: V,
+    node: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T> {
+    bounds: Bounds2D<T>,
+    depth: usize,
+    children: Option<[usize; 4]>,
+    items: Vec<usize>,
+}
+
+/// A spatial index for finding items by their bounds.
+///
+/// Every item is stored in the smallest node that fully contains it, and nodes split into
+/// four when they hold more items than their capacity, until the maximum depth is reached.
+/// Items outside the bounds of the tree are kept at the root, so they can still be found.
+///
+/// Queries follow the edge rules of [Bounds2D], so items without area are never found
+/// by region or point queries, but they are still found by [`nearest()`](crate::QuadTree::nearest).
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut tree = QuadTree::new(bounds!(0.0, 0.0, 100.0, 100.0));
+///
+/// let player = tree.insert(bounds!(10.0, 10.0, 2.0, 2.0), "player");
+/// let enemy = tree.insert(bounds!(50.0, 50.0, 4.0, 4.0), "enemy");
+///
+/// let found: Vec<_> = tree.query_region(bounds!(0.0, 0.0, 20.0, 20.0)).collect();
+/// assert_eq!(found, vec![(player, &"player")]);
+///
+/// tree.update(player, bounds!(49.0, 49.0, 2.0, 2.0));
+/// assert_eq!(tree.query_point((50.5, 50.5)).count(), 2);
+///
+/// assert_eq!(tree.remove(enemy), Some("enemy"));
+/// assert_eq!(tree.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct QuadTree<T, V> {
+    nodes: Vec<Node<T>>,
+    entries: Slots<Entry<T, V>>,
+    max_depth: usize,
+    capacity: usize,
+}
+
+impl<T, V> QuadTree<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Creates a new, empty [QuadTree] covering `bounds`.
+    pub fn new<B: IntoBounds2D<T>>(bounds: B) -> Self {
+        Self {
+            nodes: vec![Node {
+                bounds: bounds.to_bounds(),
+                depth: 0,
+                children: None,
+                items: Vec::new(),
+            }],
+            entries: Slots::new(),
+            max_depth: 8,
+            capacity: 8,
+        }
+    }
+
+    /// Sets how many times nodes may be split. Defaults to `8`.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets how many items a node holds before it is split. Defaults to `8`.
+    pub fn with_node_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// Returns the number of items in the tree.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the tree has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of the item referred to by `handle`.
+    pub fn get(&self, handle: QuadHandle) -> Option<&V> {
+        self.entries.get(handle.0).map(|entry| &entry.value)
+    }
+
+    /// Returns a mutable reference to the value of the item referred to by `handle`.
+    pub fn get_mut(&mut self, handle: QuadHandle) -> Option<&mut V> {
+        self.entries.get_mut(handle.0).map(|entry| &mut entry.value)
+    }
+
+    /// Returns the bounds of the item referred to by `handle`.
+    pub fn bounds(&self, handle: QuadHandle) -> Option<Bounds2D<T>> {
+        self.entries.get(handle.0).map(|entry| entry.bounds)
+    }
+
+    /// Adds an item with `bounds` to the tree, returning a handle to it.
+    pub fn insert<B: IntoBounds2D<T>>(&mut self, bounds: B, value: V) -> QuadHandle {
+        let bounds = bounds.to_bounds();
+        let node = self.find_node(bounds);
+
+        let entry = Entry {
+            bounds,
+            value,
+            node,
+        };
+
+        let key = self.entries.insert(entry);
+
+        self.nodes[node].items.push(key.index);
+        self.split_if_full(node);
+
+        QuadHandle(key)
+    }
+
+    /// Removes the item referred to by `handle` from the tree, returning its value.
+    pub fn remove(&mut self, handle: QuadHandle) -> Option<V> {
+        let entry = self.entries.remove(handle.0)?;
+        self.detach(handle.0.index, entry.node);
+
+        Some(entry.value)
+    }
+
+    /// Moves the item referred to by `handle` to `bounds`, keeping the same handle.
+    ///
+    /// Returns `false` if there is no such item.
+    pub fn update<B: IntoBounds2D<T>>(&mut self, handle: QuadHandle, bounds: B) -> bool {
+        let bounds = bounds.to_bounds();
+
+        let Some(old) = self.entries.get(handle.0).map(|entry| entry.node) else {
+            return false;
+        };
+
+        let node = self.find_node(bounds);
+        let index = handle.0.index;
+
+        self.entries[index].bounds = bounds;
+        self.entries[index].node = node;
+
+        if node != old {
+            self.detach(index, old);
+            self.nodes[node].items.push(index);
+            self.split_if_full(node);
+        }
+
+        true
+    }
+
+    /// Removes every item from the tree, keeping its bounds and configuration.
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[0].children = None;
+        self.nodes[0].items.clear();
+        self.entries.clear();
+    }
+
+    /// Returns every item that intersects `region`.
+    ///
+    /// See [`Bounds2D::intersects()`](crate::Bounds2D::intersects) for how edges are treated.
+    pub fn query_region<B: IntoBounds2D<T>>(
+        &self,
+        region: B,
+    ) -> impl Iterator<Item = (QuadHandle, &V)> {
+        let region = region.to_bounds();
+
+        self.collect(
+            |node| node.intersects(region),
+            |item| item.intersects(region),
+        )
+        .into_iter()
+    }
+
+    /// Returns every item that contains `point`.
+    ///
+    /// See [`Bounds2D::contains_point()`](crate::Bounds2D::contains_point) for how edges are treated.
+    pub fn query_point<P: ToPoint2D<T>>(&self, point: P) -> impl Iterator<Item = (QuadHandle, &V)> {
+        let point = point.to_vector();
+
+        self.collect(
+            |node| node.contains_point(point),
+            |item| item.contains_point(point),
+        )
+        .into_iter()
+    }
+
+    /// Returns the item closest to `point` as measured by `metric`, and its distance.
+    ///
+    /// The distance to an item is measured to the closest point of its bounds,
+    /// so it is zero for items containing `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let mut tree = QuadTree::new(bounds!(0.0, 0.0, 100.0, 100.0));
+    ///
+    /// tree.insert(bounds!(10.0, 10.0, 0.0, 0.0), "a");
+    /// tree.insert(bounds!(80.0, 80.0, 10.0, 10.0), "b");
+    ///
+    /// let (_, value, distance) = tree.nearest((70.0, 90.0), Manhattan).unwrap();
+    ///
+    /// assert_eq!(*value, "b");
+    /// assert_eq!(distance, 10.0);
+    /// ```
+    pub fn nearest<P, M>(&self, point: P, metric: M) -> Option<(QuadHandle, &V, T)>
+    where
+        P: ToPoint2D<T>,
+        M: Metric<T>,
+    {
+        let point = point.to_vector();
+        let distance =
+            |bounds: &Bounds2D<T>| bounds.clamp_point(point).distance_with(&metric, point);
+
+        let mut best: Option<(usize, T)> = None;
+        let mut stack = vec![(0, T::zero())];
+
+        while let Some((node, node_distance)) = stack.pop() {
+            if best.is_some_and(|(_, best)| node_distance >= best) {
+                continue;
+            }
+
+            let node = &self.nodes[node];
+
+            for &index in &node.items {
+                let item = distance(&self.entries[index].bounds);
+
+                let is_closer = match best {
+                    Some((_, best)) => item < best,
+                    None => true,
+                };
+
+                if is_closer {
+                    best = Some((index, item));
+                }
+            }
+
+            if let Some(children) = node.children {
+                let mut children =
+                    children.map(|child| (child, distance(&self.nodes[child].bounds)));
+
+                // Furthest first, so that the closest child is visited next
+                children.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                stack.extend(children);
+            }
+        }
+
+        best.map(|(index, distance)| {
+            let handle = QuadHandle(self.entries.key(index));
+            (handle, &self.entries[index].value, distance)
+        })
+    }
+
+    /// Returns the deepest existing node that fully contains `bounds`.
+    fn find_node(&self, bounds: Bounds2D<T>) -> usize {
+        let mut node = 0;
+
+        while let Some(children) = self.nodes[node].children {
+            match children
+                .into_iter()
+                .find(|&child| encloses(&self.nodes[child].bounds, &bounds))
+            {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+
+        node
+    }
+
+    fn detach(&mut self, index: usize, node: usize) {
+        let items = &mut self.nodes[node].items;
+
+        if let Some(position) = items.iter().position(|&item| item == index) {
+            items.swap_remove(position);
+        }
+    }
+
+    fn split_if_full(&mut self, node: usize) {
+        let Node {
+            bounds,
+            depth,
+            children,
+            ref items,
+        } = self.nodes[node];
+
+        if children.is_some() || items.len() <= self.capacity || depth >= self.max_depth {
+            return;
+        }
+
+        let two = T::one() + T::one();
+        let center = Point2D::new(
+            bounds.left() + bounds.width() / two,
+            bounds.top() + bounds.height() / two,
+        );
+
+        let quadrants = [
+            Bounds2D::from_corners((bounds.left(), bounds.top()), center),
+            Bounds2D::from_corners((center.x, bounds.top()), (bounds.right(), center.y)),
+            Bounds2D::from_corners((bounds.left(), center.y), (center.x, bounds.bottom())),
+            Bounds2D::from_corners(center, (bounds.right(), bounds.bottom())),
+        ];
+
+        let first = self.nodes.len();
+
+        self.nodes.extend(quadrants.map(|bounds| Node {
+            bounds,
+            depth: depth + 1,
+            children: None,
+            items: Vec::new(),
+        }));
+
+        self.nodes[node].children = Some([first, first + 1, first + 2, first + 3]);
+
+        let items = std::mem::take(&mut self.nodes[node].items);
+
+        for index in items {
+            let entry = &mut self.entries[index];
+            let target = (first..first + 4)
+                .find(|&child| encloses(&self.nodes[child].bounds, &entry.bounds))
+                .unwrap_or(node);
+
+            entry.node = target;
+            self.nodes[target].items.push(index);
+        }
+
+        for child in first..first + 4 {
+            self.split_if_full(child);
+        }
+    }
+
+    fn collect<N, I>(&self, visit: N, matches: I) -> Vec<(QuadHandle, &V)>
+    where
+        N: Fn(&Bounds2D<T>) -> bool,
+        I: Fn(&Bounds2D<T>) -> bool,
+    {
+        let mut found = Vec::new();
+        let mut stack = vec![0];
+
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+
+            for &index in &node.items {
+                let entry = &self.entries[index];
+
+                if matches(&entry.bounds) {
+                    found.push((QuadHandle(self.entries.key(index)), &entry.value));
+                }
+            }
+
+            if let Some(children) = node.children {
+                stack.extend(
+                    children
+                        .into_iter()
+                        .filter(|&child| visit(&self.nodes[child].bounds)),
+                );
+            }
+        }
+
+        found
+    }
+}
+
+/// Returns `true` if `inner` lies within the edges of `outer`.
+///
+/// Unlike [`Bounds2D::contains_bounds()`], bounds without area are
+/// placed by their position, so they end up in the right node.
+fn encloses<T>(outer: &Bounds2D<T>, inner: &Bounds2D<T>) -> bool
+where
+    T: Num + Copy + PartialOrd,
+{
+    inner.left() >= outer.left()
+        && inner.right() <= outer.right()
+        && inner.top() >= outer.top()
+        && inner.bottom() <= outer.bottom()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    fn items() -> Vec<Bounds2D<f32>> {
+        let mut random = Random::new(987654321);
+        let mut next = move |max: f32| random.float(max as f64) as f32;
+
+        (0..2000)
+            .map(|_| {
+                Bounds2D::new(
+                    next(1100.0) - 50.0,
+                    next(1100.0) - 50.0,
+                    next(20.0),
+                    next(20.0),
+                )
+            })
+            .collect()
+    }
+
+    fn sorted<'a>(found: impl Iterator<Item = (QuadHandle, &'a usize)>) -> Vec<usize> {
+        let mut found: Vec<_> = found.map(|(_, index)| *index).collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn queries_match_brute_force() {
+        let items = items();
+        let mut tree = QuadTree::new(bounds!(0.0, 0.0, 1000.0, 1000.0)).with_node_capacity(4);
+        let handles: Vec<_> = items
+            .iter()
+            .enumerate()
+            .map(|(index, bounds)| tree.insert(*bounds, index))
+            .collect();
+
+        // Move some items around, and remove some others
+        for (index, handle) in handles.iter().enumerate().step_by(7) {
+            tree.update(*handle, items[index] + offset!(300.0, -200.0));
+        }
+
+        for handle in handles.iter().skip(3).step_by(11) {
+            tree.remove(*handle);
+        }
+
+        let current: Vec<_> = handles
+            .iter()
+            .filter_map(|handle| Some((*tree.get(*handle)?, tree.bounds(*handle)?)))
+            .collect();
+
+        for region in [
+            bounds!(100.0, 100.0, 200.0, 50.0),
+            bounds!(-100.0, -100.0, 150.0, 150.0),
+            bounds!(990.0, 0.0, 200.0, 1000.0),
+        ] {
+            let mut expected: Vec<_> = current
+                .iter()
+                .filter(|(_, bounds)| bounds.intersects(region))
+                .map(|(index, _)| *index)
+                .collect();
+            expected.sort();
+
+            assert_eq!(sorted(tree.query_region(region)), expected);
+        }
+
+        for point in [
+            point!(500.0, 500.0),
+            point!(10.0, 990.0),
+            point!(-20.0, 30.0),
+        ] {
+            let mut expected: Vec<_> = current
+                .iter()
+                .filter(|(_, bounds)| bounds.contains_point(point))
+                .map(|(index, _)| *index)
+                .collect();
+            expected.sort();
+
+            assert_eq!(sorted(tree.query_point(point)), expected);
+
+            let (_, _, distance) = tree.nearest(point, Euclidean).unwrap();
+            let expected = current
+                .iter()
+                .map(|(_, bounds)| bounds.clamp_point(point).euclidean_distance(point))
+                .fold(f32::INFINITY, f32::min);
+
+            assert_eq!(distance, expected);
+        }
+    }
+
+    #[test]
+    fn stale_handles_are_rejected() {
+        let mut tree = QuadTree::new(bounds!(0.0, 0.0, 100.0, 100.0)).with_node_capacity(1);
+
+        let a = tree.insert(bounds!(10.0, 10.0, 5.0, 5.0), "a");
+        let b = tree.insert(bounds!(80.0, 80.0, 5.0, 5.0), "b");
+
+        assert_eq!(tree.remove(a), Some("a"));
+
+        // The next item takes the place of the removed one, but not its handle
+        let c = tree.insert(bounds!(60.0, 20.0, 5.0, 5.0), "c");
+
+        assert_ne!(c, a);
+        assert_eq!(tree.get(a), None);
+        assert_eq!(tree.get_mut(a), None);
+        assert_eq!(tree.bounds(a), None);
+        assert_eq!(tree.remove(a), None);
+        assert!(!tree.update(a, bounds!(0.0, 0.0, 1.0, 1.0)));
+
+        assert_eq!(tree.len(), 2);
+        assert_eq!(tree.get(c), Some(&"c"));
+        assert_eq!(tree.bounds(c), Some(bounds!(60.0, 20.0, 5.0, 5.0)));
+        assert_eq!(tree.query_point((12.0, 12.0)).count(), 0);
+        assert_eq!(
+            tree.query_point((62.0, 22.0)).collect::<Vec<_>>(),
+            vec![(c, &"c")]
+        );
+        assert_eq!(tree.get(b), Some(&"b"));
+
+        // Handles from before clearing are rejected as well
+        tree.clear();
+        let d = tree.insert(bounds!(60.0, 20.0, 5.0, 5.0), "d");
+
+        assert_eq!(tree.get(c), None);
+        assert!(!tree.update(c, bounds!(0.0, 0.0, 1.0, 1.0)));
+        assert_eq!(tree.get(d), Some(&"d"));
+    }
+
+    #[test]
+    fn empty() {
+        let mut tree = QuadTree::<f32, ()>::new(bounds!(0.0, 0.0, 100.0, 100.0));
+
+        assert!(tree.is_empty());
+        assert_eq!(
+            tree.query_region(bounds!(0.0, 0.0, 100.0, 100.0)).count(),
+            0
+        );
+        assert!(tree.nearest((50.0, 50.0), Euclidean).is_none());
+
+        let handle = tree.insert(bounds!(10.0, 10.0, 5.0, 5.0), ());
+        tree.clear();
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.get(handle), None);
+    }
+}
@@ -0,0 +1,30 @@
+//! A small deterministic random number generator for tests,
+//! so randomized tests are reproducible and need no extra dependencies.
+
+/// A linear congruential generator.
+#[derive(Debug, Clone)]
+pub struct Random {
+    seed: u32,
+}
+
+impl Random {
+    /// Creates a new [Random] starting from `seed`.
+    pub fn new(seed: u32) -> Self {
+        Self { seed }
+    }
+
+    fn next(&mut self) -> u32 {
+        self.seed = self.seed.wrapping_mul(1103515245).wrapping_add(12345);
+        self.seed
+    }
+
+    /// Returns an integer in the range `0..max`.
+    pub fn below(&mut self, max: u32) -> u32 {
+        (self.next() >> 16) % max
+    }
+
+    /// Returns a float in the range `0.0..max`.
+    pub fn float(&mut self, max: f64) -> f64 {
+        (self.next() >> 8) as f64 / (1u32 << 24) as f64 * max
+    }
+}
@@ -0,0 +1,419 @@
+use std::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use num_traits::Num;
+
+use crate::{Bounds2D, IntoBounds2D, ToPoint2D, UnknownUnit};
+
+/// A horizontal strip of a [Region2D], with the covered ranges along the X axis.
+#[derive(Debug, Clone, PartialEq)]
+struct Band<T> {
+    top: T,
+    bottom: T,
+    spans: Vec<(T, T)>,
+}
+
+/// A two-dimensional area made out of any number of rectangles.
+///
+/// The area is stored as bands, which are horizontal strips sorted from top to bottom,
+/// each holding sorted ranges along the X axis. Bands never overlap, and neighbouring
+/// bands with the same ranges are merged, so the same area is always stored the same way.
+///
+/// Just like [Bounds2D], the bottom and right edges of the area are not part of it.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut damage = Region2D::from(bounds!(0, 0, 10, 10));
+/// damage = damage.union(&bounds!(5, 5, 10, 10).into());
+///
+/// assert!(damage.contains_point((12, 12)));
+/// assert!(!damage.contains_point((12, 2)));
+///
+/// let rects: Vec<_> = damage.iter().collect();
+/// assert_eq!(
+///     rects,
+///     vec![
+///         bounds!(0, 0, 10, 5),
+///         bounds!(0, 5, 15, 5),
+///         bounds!(5, 10, 10, 5),
+///     ]
+/// );
+/// ```
+pub struct Region2D<T, U = UnknownUnit> {
+    bands: Vec<Band<T>>,
+
+    _unit: PhantomData<U>,
+}
+
+impl<T, U> Region2D<T, U> {
+    /// Creates a new, empty [Region2D].
+    pub fn new() -> Self {
+        Self {
+            bands: Vec::new(),
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns `true` if the region covers no area.
+    pub fn is_empty(&self) -> bool {
+        self.bands.is_empty()
+    }
+}
+
+impl<T, U> Region2D<T, U>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Returns the smallest bounds containing the whole region,
+    /// or [`None`] if the region is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let region: Region2D<_> = [bounds!(0, 0, 5, 5), bounds!(10, 10, 5, 5)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(region.extents(), Some(bounds!(0, 0, 15, 15)));
+    /// ```
+    pub fn extents(&self) -> Option<Bounds2D<T, U>> {
+        self.iter().reduce(|extents, bounds| extents.union(bounds))
+    }
+
+    /// Returns an iterator over the rectangles making up the region.
+    ///
+    /// The rectangles never overlap, and are ordered from top to bottom, then left to right.
+    pub fn iter(&self) -> impl Iterator<Item = Bounds2D<T, U>> + '_ {
+        self.bands.iter().flat_map(|band| {
+            band.spans.iter().map(|&(left, right)| {
+                Bounds2D::from_corners((left, band.top), (right, band.bottom))
+            })
+        })
+    }
+
+    /// Returns `true` if `point` is inside the region.
+    pub fn contains_point<P: ToPoint2D<T, U>>(&self, point: P) -> bool {
+        let point = point.to_vector();
+        let index = self.bands.partition_point(|band| band.bottom <= point.y);
+
+        self.bands
+            .get(index)
+            .filter(|band| band.top <= point.y)
+            .map(|band| {
+                let index = band.spans.partition_point(|&(_, right)| right <= point.x);
+
+                band.spans
+                    .get(index)
+                    .is_some_and(|&(left, _)| left <= point.x)
+            })
+            .unwrap_or(false)
+    }
+
+    /// Returns the area covered by either `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Returns the area covered by both `self` and `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = Region2D::from(bounds!(0, 0, 10, 10));
+    /// let b = Region2D::from(bounds!(5, 5, 10, 10));
+    ///
+    /// assert_eq!(a.intersection(&b), Region2D::from(bounds!(5, 5, 5, 5)));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Returns the area covered by `self`, but not by `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let region = Region2D::from(bounds!(0, 0, 10, 10));
+    /// let hole = Region2D::from(bounds!(2, 2, 6, 6));
+    ///
+    /// let frame = region.subtract(&hole);
+    ///
+    /// assert!(frame.contains_point((1, 1)));
+    /// assert!(!frame.contains_point((5, 5)));
+    /// assert_eq!(frame.iter().count(), 4);
+    /// ```
+    pub fn subtract(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Returns the area covered by exactly one of `self` and `other`.
+    pub fn xor(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a != b)
+    }
+
+    /// Applies a boolean operation to every part of the plane,
+    /// by splitting it at every band edge and span edge of both regions.
+    fn combine<F>(&self, other: &Self, operation: F) -> Self
+    where
+        F: Fn(bool, bool) -> bool,
+    {
+        let edges = sorted_edges(
+            self.bands
+                .iter()
+                .chain(&other.bands)
+                .flat_map(|band| [band.top, band.bottom]),
+        );
+
+        let mut bands: Vec<Band<T>> = Vec::new();
+        let (mut a, mut b) = (0, 0);
+
+        for pair in edges.windows(2) {
+            let (top, bottom) = (pair[0], pair[1]);
+
+            let spans_a = band_spans(&self.bands, &mut a, top);
+            let spans_b = band_spans(&other.bands, &mut b, top);
+            let spans = combine_spans(spans_a, spans_b, &operation);
+
+            if spans.is_empty() {
+                continue;
+            }
+
+            match bands.last_mut() {
+                Some(last) if last.bottom == top && last.spans == spans => last.bottom = bottom,
+                _ => bands.push(Band { top, bottom, spans }),
+            }
+        }
+
+        Self {
+            bands,
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// Returns the spans of the band covering the strip starting at `top`,
+/// advancing `index` past bands that end before it.
+fn band_spans<'a, T>(bands: &'a [Band<T>], index: &mut usize, top: T) -> &'a [(T, T)]
+where
+    T: Copy + PartialOrd,
+{
+    while *index < bands.len() && bands[*index].bottom <= top {
+        *index += 1;
+    }
+
+    match bands.get(*index) {
+        Some(band) if band.top <= top => &band.spans,
+        _ => &[],
+    }
+}
+
+/// Applies `operation` along the X axis, merging touching ranges.
+fn combine_spans<T, F>(a: &[(T, T)], b: &[(T, T)], operation: &F) -> Vec<(T, T)>
+where
+    T: Copy + PartialOrd,
+    F: Fn(bool, bool) -> bool,
+{
+    let edges = sorted_edges(a.iter().chain(b).flat_map(|&(left, right)| [left, right]));
+
+    let mut spans: Vec<(T, T)> = Vec::new();
+    let (mut i, mut j) = (0, 0);
+
+    for pair in edges.windows(2) {
+        let (left, right) = (pair[0], pair[1]);
+
+        let inside_a = span_contains(a, &mut i, left);
+        let inside_b = span_contains(b, &mut j, left);
+
+        if !operation(inside_a, inside_b) {
+            continue;
+        }
+
+        match spans.last_mut() {
+            Some(last) if last.1 == left => last.1 = right,
+            _ => spans.push((left, right)),
+        }
+    }
+
+    spans
+}
+
+/// Returns `true` if the range starting at `left` is covered by `spans`,
+/// advancing `index` past spans that end before it.
+fn span_contains<T>(spans: &[(T, T)], index: &mut usize, left: T) -> bool
+where
+    T: Copy + PartialOrd,
+{
+    while *index < spans.len() && spans[*index].1 <= left {
+        *index += 1;
+    }
+
+    spans.get(*index).is_some_and(|span| span.0 <= left)
+}
+
+fn sorted_edges<T, I>(edges: I) -> Vec<T>
+where
+    T: Copy + PartialOrd,
+    I: Iterator<Item = T>,
+{
+    let mut edges: Vec<_> = edges.collect();
+
+    edges.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    edges.dedup();
+    edges
+}
+
+impl<T, U> From<Bounds2D<T, U>> for Region2D<T, U>
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn from(bounds: Bounds2D<T, U>) -> Self {
+        if bounds.is_empty() {
+            return Self::new();
+        }
+
+        Self {
+            bands: vec![Band {
+                top: bounds.top(),
+                bottom: bounds.bottom(),
+                spans: vec![(bounds.left(), bounds.right())],
+            }],
+            _unit: PhantomData,
+        }
+    }
+}
+
+/// Collects bounds into the region covering all of them.
+impl<T, U, B> FromIterator<B> for Region2D<T, U>
+where
+    T: Num + Copy + PartialOrd,
+    B: IntoBounds2D<T, U>,
+{
+    fn from_iter<I: IntoIterator<Item = B>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), |region, bounds| {
+            region.union(&bounds.to_bounds().into())
+        })
+    }
+}
+
+// These are implemented by hand, so that the unit marker
+// is not required to implement the traits as well.
+impl<T, U> Clone for Region2D<T, U>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            bands: self.bands.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> PartialEq for Region2D<T, U>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.bands == other.bands
+    }
+}
+
+impl<T, U> Default for Region2D<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, U> Debug for Region2D<T, U>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Region2D")
+            .field("bands", &self.bands)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    fn rects(region: &Region2D<i32>) -> Vec<Bounds2D<i32>> {
+        region.iter().collect()
+    }
+
+    #[test]
+    fn union_merges_bands() {
+        let region: Region2D<i32> = [bounds!(0, 0, 10, 5), bounds!(0, 5, 10, 5)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(rects(&region), vec![bounds!(0, 0, 10, 10)]);
+
+        let region: Region2D<i32> = [bounds!(0, 0, 5, 10), bounds!(5, 0, 5, 10)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(rects(&region), vec![bounds!(0, 0, 10, 10)]);
+    }
+
+    #[test]
+    fn xor() {
+        let a = Region2D::from(bounds!(0, 0, 10, 10));
+        let b = Region2D::from(bounds!(5, 0, 10, 10));
+
+        assert_eq!(
+            rects(&a.xor(&b)),
+            vec![bounds!(0, 0, 5, 10), bounds!(10, 0, 5, 10)]
+        );
+        assert!(a.xor(&a).is_empty());
+    }
+
+    #[test]
+    fn subtract_matches_bounds() {
+        let bounds = bounds!(0, 0, 10, 10);
+        let hole = bounds!(4, -2, 2, 20);
+
+        let region = Region2D::from(bounds).subtract(&hole.into());
+        let expected: Region2D<i32> = bounds.subtract(hole).collect();
+
+        assert_eq!(region, expected);
+        assert_eq!(
+            rects(&region),
+            vec![bounds!(0, 0, 4, 10), bounds!(6, 0, 4, 10)]
+        );
+    }
+
+    #[test]
+    fn operations_are_canonical() {
+        let a: Region2D<i32> = [bounds!(0, 0, 6, 6), bounds!(4, 4, 6, 6)]
+            .into_iter()
+            .collect();
+        let b: Region2D<i32> = [bounds!(4, 4, 6, 6), bounds!(0, 0, 6, 6)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(a, b);
+        assert_eq!(a.union(&a), a);
+        assert_eq!(a.intersection(&a), a);
+        assert!(a.subtract(&a).is_empty());
+    }
+
+    #[test]
+    fn empty_bounds_are_ignored() {
+        let region = Region2D::from(bounds!(0, 0, 0, 10));
+        assert!(region.is_empty());
+
+        let region = Region2D::from(bounds!(0, 0, 10, 10)).union(&bounds!(20, 20, 5, 0).into());
+        assert_eq!(rects(&region), vec![bounds!(0, 0, 10, 10)]);
+    }
+}
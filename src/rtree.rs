@@ -0,0 +1,1055 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use num_traits::Num;
+
+use crate::{Bounds2D, IntoBounds2D, Metric, Point2D, ToPoint2D};
+
+const MAX_CHILDREN: usize = 16;
+const MIN_CHILDREN: usize = 6;
+const REINSERTED: usize = 5;
+
+/// The deepest a tree can get. Every node except the root has at least [MIN_CHILDREN],
+/// so this is far more than what fits in memory, and lets iterators use a fixed stack.
+const MAX_HEIGHT: usize = 32;
+
+/// How [RTree::bulk_load_with] groups items into nodes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BulkLoad {
+    /// Sort-Tile-Recursive, which packs the leaves first and builds the tree upwards.
+    Str,
+    /// Overlap Minimizing Top-down, which applies the same tiling from the root downwards.
+    /// This usually results in less overlap between nodes.
+    #[default]
+    Omt,
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T, V> {
+    bounds: Bounds2D<T>,
+    value: V,
+}
+
+#[derive(Debug, Clone)]
+struct Node<T, V> {
+    bounds: Bounds2D<T>,
+    children: Children<T, V>,
+}
+
+#[derive(Debug, Clone)]
+enum Children<T, V> {
+    Leaf(Vec<Entry<T, V>>),
+    Internal(Vec<Node<T, V>>),
+}
+
+trait Bounded<T> {
+    fn bounds(&self) -> Bounds2D<T>;
+}
+
+impl<T: Copy, V> Bounded<T> for Entry<T, V> {
+    fn bounds(&self) -> Bounds2D<T> {
+        self.bounds
+    }
+}
+
+impl<T: Copy, V> Bounded<T> for Node<T, V> {
+    fn bounds(&self) -> Bounds2D<T> {
+        self.bounds
+    }
+}
+
+impl<T: Copy> Bounded<T> for &Bounds2D<T> {
+    fn bounds(&self) -> Bounds2D<T> {
+        **self
+    }
+}
+
+impl<T: Copy, V> Bounded<T> for &Entry<T, V> {
+    fn bounds(&self) -> Bounds2D<T> {
+        self.bounds
+    }
+}
+
+/// A spatial index that groups nearby items into a balanced tree of bounding boxes.
+///
+/// Compared to a [QuadTree](crate::QuadTree), the nodes adapt to the items instead of
+/// splitting space evenly, which works especially well for data loaded all at once
+/// with [`bulk_load()`](crate::RTree::bulk_load). Items are inserted with the R* strategy.
+///
+/// Queries follow the edge rules of [Bounds2D], so items without area are never found
+/// by window queries, but they are still found by [`nearest_iter()`](crate::RTree::nearest_iter).
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut tree = RTree::bulk_load(vec![
+///     (bounds!(0.0, 0.0, 10.0, 10.0), "park"),
+///     (bounds!(20.0, 0.0, 5.0, 10.0), "school"),
+///     (bounds!(100.0, 100.0, 1.0, 1.0), "well"),
+/// ]);
+///
+/// tree.insert(bounds!(8.0, 8.0, 4.0, 4.0), "cafe");
+///
+/// let mut found: Vec<_> = tree.query(bounds!(5.0, 5.0, 20.0, 20.0)).map(|(_, value)| *value).collect();
+/// found.sort();
+/// assert_eq!(found, vec!["cafe", "park", "school"]);
+///
+/// assert_eq!(tree.remove(bounds!(100.0, 100.0, 1.0, 1.0), &"well"), Some("well"));
+/// assert_eq!(tree.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RTree<T, V> {
+    root: Node<T, V>,
+    len: usize,
+}
+
+impl<T, V> RTree<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Creates a new, empty [RTree].
+    pub fn new() -> Self {
+        Self {
+            root: Node {
+                bounds: Bounds2D::new(T::zero(), T::zero(), T::zero(), T::zero()),
+                children: Children::Leaf(Vec::new()),
+            },
+            len: 0,
+        }
+    }
+
+    /// Creates a new [RTree] out of `items` using the [`BulkLoad::Omt`] strategy.
+    ///
+    /// This is a lot faster than inserting the items one by one,
+    /// and results in a tree that is faster to query.
+    pub fn bulk_load<B: IntoBounds2D<T>>(items: Vec<(B, V)>) -> Self {
+        Self::bulk_load_with(items, BulkLoad::Omt)
+    }
+
+    /// Creates a new [RTree] out of `items` using `strategy`.
+    pub fn bulk_load_with<B: IntoBounds2D<T>>(items: Vec<(B, V)>, strategy: BulkLoad) -> Self {
+        let len = items.len();
+
+        if len == 0 {
+            return Self::new();
+        }
+
+        let entries: Vec<_> = items
+            .into_iter()
+            .map(|(bounds, value)| Entry {
+                bounds: bounds.to_bounds(),
+                value,
+            })
+            .collect();
+
+        let root = match strategy {
+            BulkLoad::Str => load_str(entries),
+            BulkLoad::Omt => {
+                let mut height = 1;
+                let mut capacity = MAX_CHILDREN;
+
+                while capacity < len {
+                    capacity = capacity.saturating_mul(MAX_CHILDREN);
+                    height += 1;
+                }
+
+                load_omt(entries, height)
+            }
+        };
+
+        Self { root, len }
+    }
+
+    /// Returns the number of items in the tree.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds an item with `bounds` to the tree.
+    pub fn insert<B: IntoBounds2D<T>>(&mut self, bounds: B, value: V) {
+        self.len += 1;
+        self.insert_entry(Entry {
+            bounds: bounds.to_bounds(),
+            value,
+        });
+    }
+
+    /// Removes an item with exactly `bounds` and a value equal to `value`, returning its value.
+    pub fn remove<B: IntoBounds2D<T>>(&mut self, bounds: B, value: &V) -> Option<V>
+    where
+        V: PartialEq,
+    {
+        self.remove_where(bounds, |other| other == value)
+    }
+
+    /// Removes an item with exactly `bounds` where `matches` returns `true`, returning its value.
+    pub fn remove_where<B, F>(&mut self, bounds: B, mut matches: F) -> Option<V>
+    where
+        B: IntoBounds2D<T>,
+        F: FnMut(&V) -> bool,
+    {
+        let bounds = bounds.to_bounds();
+        let mut orphans = Vec::new();
+
+        let value = remove_entry(&mut self.root, bounds, &mut matches, &mut orphans)?;
+        self.len -= 1;
+
+        loop {
+            match &mut self.root.children {
+                Children::Internal(children) if children.len() == 1 => {
+                    self.root = children.pop().expect("Node must have a child");
+                }
+                Children::Internal(children) if children.is_empty() => {
+                    self.root.children = Children::Leaf(Vec::new());
+                }
+                _ => break,
+            }
+        }
+
+        for orphan in orphans {
+            self.insert_entry(orphan);
+        }
+
+        Some(value)
+    }
+
+    /// Removes every item from the tree.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Returns an iterator over every item in the tree.
+    pub fn iter(&self) -> RTreeIter<'_, T, V> {
+        RTreeIter::new(self, None)
+    }
+
+    /// Returns an iterator over every item that intersects `window`.
+    ///
+    /// The iterator walks the tree without allocating.
+    /// See [`Bounds2D::intersects()`](crate::Bounds2D::intersects) for how edges are treated.
+    pub fn query<B: IntoBounds2D<T>>(&self, window: B) -> RTreeIter<'_, T, V> {
+        RTreeIter::new(self, Some(window.to_bounds()))
+    }
+
+    /// Returns an iterator over the items ordered by their distance to `point`, closest first,
+    /// as measured by `metric`. Take the first `k` items to get the k nearest neighbours.
+    ///
+    /// The distance to an item is measured to the closest point of its bounds,
+    /// so it is zero for items containing `point`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let tree: RTree<f64, usize> = (0..100)
+    ///     .map(|i| (bounds!(i as f64 * 10.0, 0.0, 1.0, 1.0), i))
+    ///     .collect();
+    ///
+    /// let nearest: Vec<_> = tree
+    ///     .nearest_iter((502.0, 0.0), Euclidean)
+    ///     .take(3)
+    ///     .map(|(_, value, _)| *value)
+    ///     .collect();
+    ///
+    /// assert_eq!(nearest, vec![50, 51, 49]);
+    /// ```
+    pub fn nearest_iter<P, M>(&self, point: P, metric: M) -> NearestIter<'_, T, V, M>
+    where
+        P: ToPoint2D<T>,
+        M: Metric<T>,
+    {
+        let mut iter = NearestIter {
+            point: point.to_vector(),
+            metric,
+            heap: BinaryHeap::new(),
+        };
+
+        if !self.is_empty() {
+            iter.push(Candidate::Node(&self.root));
+        }
+
+        iter
+    }
+
+    fn insert_entry(&mut self, entry: Entry<T, V>) {
+        let mut pending = vec![entry];
+        let mut reinsert = true;
+
+        while let Some(entry) = pending.pop() {
+            match insert(&mut self.root, entry, 0, &mut reinsert) {
+                Overflow::None => {}
+                Overflow::Reinsert(entries) => pending.extend(entries),
+                Overflow::Split(sibling) => {
+                    let placeholder = Node {
+                        bounds: self.root.bounds,
+                        children: Children::Leaf(Vec::new()),
+                    };
+
+                    let old = std::mem::replace(&mut self.root, placeholder);
+                    self.root = Node::new(Children::Internal(vec![old, sibling]));
+                }
+            }
+        }
+    }
+}
+
+impl<T, V> Default for RTree<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Bulk loads the items using the [`BulkLoad::Omt`] strategy.
+impl<T, V, B> FromIterator<(B, V)> for RTree<T, V>
+where
+    T: Num + Copy + PartialOrd,
+    B: IntoBounds2D<T>,
+{
+    fn from_iter<I: IntoIterator<Item = (B, V)>>(iter: I) -> Self {
+        Self::bulk_load(iter.into_iter().collect())
+    }
+}
+
+enum Overflow<T, V> {
+    None,
+    Split(Node<T, V>),
+    Reinsert(Vec<Entry<T, V>>),
+}
+
+fn insert<T, V>(
+    node: &mut Node<T, V>,
+    entry: Entry<T, V>,
+    depth: usize,
+    reinsert: &mut bool,
+) -> Overflow<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    let overflow = match &mut node.children {
+        Children::Leaf(entries) => {
+            entries.push(entry);
+
+            if entries.len() <= MAX_CHILDREN {
+                Overflow::None
+            } else if *reinsert && depth > 0 {
+                // Reinserting the items furthest from the center, only once per insertion,
+                // gives them a chance to end up in a better node before resorting to a split.
+                *reinsert = false;
+
+                let center = enclose_all(entries.iter()).center();
+                entries.sort_by(|a, b| {
+                    let a = distance_squared(a.bounds.center(), center);
+                    let b = distance_squared(b.bounds.center(), center);
+
+                    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+                });
+
+                Overflow::Reinsert(entries.split_off(entries.len() - REINSERTED))
+            } else {
+                Overflow::Split(Node::new(Children::Leaf(split(entries))))
+            }
+        }
+        Children::Internal(children) => {
+            let index = choose_subtree(children, entry.bounds);
+
+            match insert(&mut children[index], entry, depth + 1, reinsert) {
+                Overflow::Split(sibling) => {
+                    children.push(sibling);
+
+                    if children.len() <= MAX_CHILDREN {
+                        Overflow::None
+                    } else {
+                        Overflow::Split(Node::new(Children::Internal(split(children))))
+                    }
+                }
+                overflow => overflow,
+            }
+        }
+    };
+
+    node.bounds = children_bounds(&node.children);
+    overflow
+}
+
+/// Picks the child to insert `bounds` into, as described by the R* tree.
+fn choose_subtree<T, V>(children: &[Node<T, V>], bounds: Bounds2D<T>) -> usize
+where
+    T: Num + Copy + PartialOrd,
+{
+    let leaves = matches!(children[0].children, Children::Leaf(_));
+
+    let costs = children.iter().enumerate().map(|(index, child)| {
+        let enlarged = enclose(child.bounds, bounds);
+        let enlargement = area(enlarged) - area(child.bounds);
+
+        // Right above the leaves, what matters most is the overlap with the other children
+        let overlap = if leaves {
+            children
+                .iter()
+                .enumerate()
+                .filter(|(other, _)| *other != index)
+                .fold(T::zero(), |sum, (_, other)| {
+                    sum + overlap(enlarged, other.bounds) - overlap(child.bounds, other.bounds)
+                })
+        } else {
+            T::zero()
+        };
+
+        (index, [overlap, enlargement, area(child.bounds)])
+    });
+
+    costs
+        .reduce(|best, cost| {
+            if cost.1.partial_cmp(&best.1) == Some(Ordering::Less) {
+                cost
+            } else {
+                best
+            }
+        })
+        .map(|(index, _)| index)
+        .unwrap_or(0)
+}
+
+/// Splits `items` in two as described by the R* tree, returning the second group.
+fn split<T, I>(items: &mut Vec<I>) -> Vec<I>
+where
+    T: Num + Copy + PartialOrd,
+    I: Bounded<T>,
+{
+    let bounds: Vec<_> = items.iter().map(Bounded::bounds).collect();
+
+    let sorted = |key: fn(&Bounds2D<T>) -> T| {
+        let mut order: Vec<usize> = (0..bounds.len()).collect();
+        order.sort_by(|&a, &b| {
+            key(&bounds[a])
+                .partial_cmp(&key(&bounds[b]))
+                .unwrap_or(Ordering::Equal)
+        });
+        order
+    };
+
+    let distributions = |order: &[usize]| -> Vec<_> {
+        (MIN_CHILDREN..=order.len() - MIN_CHILDREN)
+            .map(|split| {
+                let first = enclose_all(order[..split].iter().map(|&index| &bounds[index]));
+                let second = enclose_all(order[split..].iter().map(|&index| &bounds[index]));
+
+                (split, first, second)
+            })
+            .collect()
+    };
+
+    let axes = [
+        [sorted(|bounds| bounds.left()), sorted(|bounds| bounds.right())],
+        [sorted(|bounds| bounds.top()), sorted(|bounds| bounds.bottom())],
+    ];
+
+    // The axis is picked by the smallest total margin of every distribution
+    let margins = axes.each_ref().map(|orders| {
+        orders
+            .iter()
+            .flat_map(|order| distributions(order))
+            .fold(T::zero(), |sum, (_, first, second)| {
+                sum + margin(first) + margin(second)
+            })
+    });
+
+    let axis = if margins[1] < margins[0] { 1 } else { 0 };
+
+    // Then the distribution is picked by the smallest overlap, and then the smallest area
+    let mut best: Option<(&[usize], usize, [T; 2])> = None;
+
+    for order in &axes[axis] {
+        for (split, first, second) in distributions(order) {
+            let cost = [overlap(first, second), area(first) + area(second)];
+
+            let is_better = match best {
+                Some((_, _, best)) => cost.partial_cmp(&best) == Some(Ordering::Less),
+                None => true,
+            };
+
+            if is_better {
+                best = Some((order, split, cost));
+            }
+        }
+    }
+
+    let (order, split, _) = best.expect("There must be a distribution");
+
+    let mut slots: Vec<_> = items.drain(..).map(Some).collect();
+    let mut second = Vec::with_capacity(order.len() - split);
+
+    for (position, &index) in order.iter().enumerate() {
+        let item = slots[index].take().expect("Item must only be taken once");
+
+        if position < split {
+            items.push(item);
+        } else {
+            second.push(item);
+        }
+    }
+
+    second
+}
+
+fn remove_entry<T, V, F>(
+    node: &mut Node<T, V>,
+    bounds: Bounds2D<T>,
+    matches: &mut F,
+    orphans: &mut Vec<Entry<T, V>>,
+) -> Option<V>
+where
+    T: Num + Copy + PartialOrd,
+    F: FnMut(&V) -> bool,
+{
+    if !encloses(node.bounds, bounds) {
+        return None;
+    }
+
+    let value = match &mut node.children {
+        Children::Leaf(entries) => {
+            let index = entries
+                .iter()
+                .position(|entry| entry.bounds == bounds && matches(&entry.value))?;
+
+            entries.swap_remove(index).value
+        }
+        Children::Internal(children) => {
+            let (index, value) = children.iter_mut().enumerate().find_map(|(index, child)| {
+                remove_entry(child, bounds, matches, orphans).map(|value| (index, value))
+            })?;
+
+            // Nodes that are too small are dissolved, and their items inserted again
+            if child_count(&children[index]) < MIN_CHILDREN {
+                collect_entries(children.swap_remove(index), orphans);
+            }
+
+            value
+        }
+    };
+
+    node.bounds = children_bounds(&node.children);
+    Some(value)
+}
+
+fn collect_entries<T, V>(node: Node<T, V>, entries: &mut Vec<Entry<T, V>>) {
+    match node.children {
+        Children::Leaf(leaf) => entries.extend(leaf),
+        Children::Internal(children) => {
+            for child in children {
+                collect_entries(child, entries);
+            }
+        }
+    }
+}
+
+fn load_str<T, V>(entries: Vec<Entry<T, V>>) -> Node<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    let mut level: Vec<_> = tile(entries)
+        .into_iter()
+        .map(|group| Node::new(Children::Leaf(group)))
+        .collect();
+
+    while level.len() > 1 {
+        level = tile(level)
+            .into_iter()
+            .map(|group| Node::new(Children::Internal(group)))
+            .collect();
+    }
+
+    level.pop().expect("There must be a root")
+}
+
+/// Groups `items` into tiles of at most [MAX_CHILDREN], by cutting them into
+/// vertical slices and then cutting each slice horizontally.
+fn tile<T, I>(mut items: Vec<I>) -> Vec<Vec<I>>
+where
+    T: Num + Copy + PartialOrd,
+    I: Bounded<T>,
+{
+    let groups = items.len().div_ceil(MAX_CHILDREN);
+    let slices = (groups as f64).sqrt().ceil() as usize;
+
+    sort_by_center(&mut items, |point| point.x);
+
+    chunks(items, slices * MAX_CHILDREN)
+        .into_iter()
+        .flat_map(|mut slice| {
+            sort_by_center(&mut slice, |point| point.y);
+            chunks(slice, MAX_CHILDREN)
+        })
+        .collect()
+}
+
+fn load_omt<T, V>(mut entries: Vec<Entry<T, V>>, height: usize) -> Node<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    if height <= 1 {
+        return Node::new(Children::Leaf(entries));
+    }
+
+    let per_child = MAX_CHILDREN.saturating_pow(height as u32 - 1);
+    let children = entries.len().div_ceil(per_child);
+    let slices = (children as f64).sqrt().ceil() as usize;
+    let per_slice = per_child * children.div_ceil(slices);
+
+    sort_by_center(&mut entries, |point| point.x);
+
+    let children = chunks(entries, per_slice)
+        .into_iter()
+        .flat_map(|mut slice| {
+            sort_by_center(&mut slice, |point| point.y);
+            chunks(slice, per_child)
+        })
+        .map(|group| load_omt(group, height - 1))
+        .collect();
+
+    Node::new(Children::Internal(children))
+}
+
+fn sort_by_center<T, I>(items: &mut [I], key: fn(Point2D<T>) -> T)
+where
+    T: Num + Copy + PartialOrd,
+    I: Bounded<T>,
+{
+    items.sort_by(|a, b| {
+        key(a.bounds().center())
+            .partial_cmp(&key(b.bounds().center()))
+            .unwrap_or(Ordering::Equal)
+    });
+}
+
+fn chunks<I>(items: Vec<I>, size: usize) -> Vec<Vec<I>> {
+    let mut chunks = Vec::with_capacity(items.len().div_ceil(size));
+
+    for item in items {
+        match chunks.last_mut() {
+            Some(chunk) if Vec::len(chunk) < size => chunk.push(item),
+            _ => {
+                let mut chunk = Vec::with_capacity(size);
+                chunk.push(item);
+                chunks.push(chunk);
+            }
+        }
+    }
+
+    chunks
+}
+
+impl<T, V> Node<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn new(children: Children<T, V>) -> Self {
+        Self {
+            bounds: children_bounds(&children),
+            children,
+        }
+    }
+}
+
+fn child_count<T, V>(node: &Node<T, V>) -> usize {
+    match &node.children {
+        Children::Leaf(entries) => entries.len(),
+        Children::Internal(children) => children.len(),
+    }
+}
+
+fn children_bounds<T, V>(children: &Children<T, V>) -> Bounds2D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    match children {
+        Children::Leaf(entries) => enclose_all(entries.iter().map(|entry| &entry.bounds)),
+        Children::Internal(children) => enclose_all(children.iter().map(|child| &child.bounds)),
+    }
+}
+
+/// Returns the bounds around every bounds in `iter`.
+///
+/// Unlike [`Bounds2D::union()`], bounds without area are included,
+/// since items without area still need to be found by the tree.
+fn enclose_all<T, I>(iter: I) -> Bounds2D<T>
+where
+    T: Num + Copy + PartialOrd,
+    I: IntoIterator,
+    I::Item: Bounded<T>,
+{
+    iter.into_iter()
+        .map(|item| item.bounds())
+        .reduce(enclose)
+        .unwrap_or_else(|| Bounds2D::new(T::zero(), T::zero(), T::zero(), T::zero()))
+}
+
+fn enclose<T>(a: Bounds2D<T>, b: Bounds2D<T>) -> Bounds2D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    let min = |a: T, b: T| if b < a { b } else { a };
+    let max = |a: T, b: T| if b > a { b } else { a };
+
+    Bounds2D::from_corners(
+        (min(a.left(), b.left()), min(a.top(), b.top())),
+        (max(a.right(), b.right()), max(a.bottom(), b.bottom())),
+    )
+}
+
+fn encloses<T>(outer: Bounds2D<T>, inner: Bounds2D<T>) -> bool
+where
+    T: Num + Copy + PartialOrd,
+{
+    inner.left() >= outer.left()
+        && inner.right() <= outer.right()
+        && inner.top() >= outer.top()
+        && inner.bottom() <= outer.bottom()
+}
+
+fn area<T>(bounds: Bounds2D<T>) -> T
+where
+    T: Num + Copy,
+{
+    bounds.width() * bounds.height()
+}
+
+fn margin<T>(bounds: Bounds2D<T>) -> T
+where
+    T: Num + Copy,
+{
+    bounds.width() + bounds.height()
+}
+
+fn overlap<T>(a: Bounds2D<T>, b: Bounds2D<T>) -> T
+where
+    T: Num + Copy + PartialOrd,
+{
+    a.intersection(b).map(area).unwrap_or_else(T::zero)
+}
+
+fn distance_squared<T>(a: Point2D<T>, b: Point2D<T>) -> T
+where
+    T: Num + Copy + PartialOrd,
+{
+    let dx = if a.x > b.x { a.x - b.x } else { b.x - a.x };
+    let dy = if a.y > b.y { a.y - b.y } else { b.y - a.y };
+
+    dx * dx + dy * dy
+}
+
+/// An iterator over the items in an [RTree], optionally limited to a window.
+///
+/// Created by [`RTree::iter()`] and [`RTree::query()`].
+#[derive(Debug, Clone)]
+pub struct RTreeIter<'a, T, V> {
+    window: Option<Bounds2D<T>>,
+    stack: [Option<(&'a Node<T, V>, usize)>; MAX_HEIGHT],
+    depth: usize,
+}
+
+impl<'a, T, V> RTreeIter<'a, T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn new(tree: &'a RTree<T, V>, window: Option<Bounds2D<T>>) -> Self {
+        let mut stack = [None; MAX_HEIGHT];
+        stack[0] = Some((&tree.root, 0));
+
+        Self {
+            window,
+            stack,
+            depth: if tree.is_empty() { 0 } else { 1 },
+        }
+    }
+
+    fn visits(&self, bounds: &Bounds2D<T>) -> bool {
+        match self.window {
+            Some(window) => bounds.intersects(window),
+            None => true,
+        }
+    }
+}
+
+impl<'a, T, V> Iterator for RTreeIter<'a, T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    type Item = (&'a Bounds2D<T>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.depth > 0 {
+            let frame = &mut self.stack[self.depth - 1];
+            let (node, index) = frame.expect("Frame must exist below the depth");
+
+            match &node.children {
+                Children::Leaf(entries) if index < entries.len() => {
+                    *frame = Some((node, index + 1));
+
+                    let entry = &entries[index];
+
+                    if self.visits(&entry.bounds) {
+                        return Some((&entry.bounds, &entry.value));
+                    }
+                }
+                Children::Internal(children) if index < children.len() => {
+                    *frame = Some((node, index + 1));
+
+                    let child = &children[index];
+
+                    if self.visits(&child.bounds) {
+                        self.stack[self.depth] = Some((child, 0));
+                        self.depth += 1;
+                    }
+                }
+                _ => self.depth -= 1,
+            }
+        }
+
+        None
+    }
+}
+
+enum Candidate<'a, T, V> {
+    Node(&'a Node<T, V>),
+    Entry(&'a Entry<T, V>),
+}
+
+struct Queued<'a, T, V> {
+    distance: T,
+    candidate: Candidate<'a, T, V>,
+}
+
+impl<T: PartialOrd, V> PartialEq for Queued<'_, T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: PartialOrd, V> Eq for Queued<'_, T, V> {}
+
+impl<T: PartialOrd, V> PartialOrd for Queued<'_, T, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Reversed, so that the heap pops the closest candidate first.
+impl<T: PartialOrd, V> Ord for Queued<'_, T, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .distance
+            .partial_cmp(&self.distance)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// An iterator over the items in an [RTree], ordered by distance to a point.
+///
+/// Created by [`RTree::nearest_iter()`].
+pub struct NearestIter<'a, T, V, M> {
+    point: Point2D<T>,
+    metric: M,
+    heap: BinaryHeap<Queued<'a, T, V>>,
+}
+
+impl<'a, T, V, M> NearestIter<'a, T, V, M>
+where
+    T: Num + Copy + PartialOrd,
+    M: Metric<T>,
+{
+    fn push(&mut self, candidate: Candidate<'a, T, V>) {
+        let bounds = match candidate {
+            Candidate::Node(node) => node.bounds,
+            Candidate::Entry(entry) => entry.bounds,
+        };
+
+        let distance = bounds
+            .clamp_point(self.point)
+            .distance_with(&self.metric, self.point);
+
+        self.heap.push(Queued {
+            distance,
+            candidate,
+        });
+    }
+}
+
+impl<'a, T, V, M> Iterator for NearestIter<'a, T, V, M>
+where
+    T: Num + Copy + PartialOrd,
+    M: Metric<T>,
+{
+    type Item = (&'a Bounds2D<T>, &'a V, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(Queued {
+            distance,
+            candidate,
+        }) = self.heap.pop()
+        {
+            match candidate {
+                Candidate::Entry(entry) => return Some((&entry.bounds, &entry.value, distance)),
+                Candidate::Node(node) => match &node.children {
+                    Children::Leaf(entries) => {
+                        for entry in entries {
+                            self.push(Candidate::Entry(entry));
+                        }
+                    }
+                    Children::Internal(children) => {
+                        for child in children {
+                            self.push(Candidate::Node(child));
+                        }
+                    }
+                },
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    fn items(count: usize) -> Vec<(Bounds2D<f64>, usize)> {
+        let mut random = Random::new(192837465);
+        let mut next = move |max: f64| random.float(max);
+
+        (0..count)
+            .map(|index| {
+                let bounds = Bounds2D::new(next(1000.0), next(1000.0), next(15.0), next(15.0));
+                (bounds, index)
+            })
+            .collect()
+    }
+
+    fn check(tree: &RTree<f64, usize>, items: &[(Bounds2D<f64>, usize)]) {
+        assert_eq!(tree.len(), items.len());
+        assert_eq!(tree.iter().count(), items.len());
+
+        for window in [
+            bounds!(100.0, 100.0, 200.0, 50.0),
+            bounds!(-10.0, -10.0, 100.0, 100.0),
+            bounds!(500.0, 0.0, 10.0, 1000.0),
+        ] {
+            let mut found: Vec<_> = tree.query(window).map(|(_, index)| *index).collect();
+            let mut expected: Vec<_> = items
+                .iter()
+                .filter(|(bounds, _)| bounds.intersects(window))
+                .map(|(_, index)| *index)
+                .collect();
+
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected);
+        }
+
+        let point = point!(321.0, 654.0);
+        let distances: Vec<_> = tree
+            .nearest_iter(point, Euclidean)
+            .take(10)
+            .map(|(_, _, distance)| distance)
+            .collect();
+
+        let mut expected: Vec<_> = items
+            .iter()
+            .map(|(bounds, _)| bounds.clamp_point(point).euclidean_distance(point))
+            .collect();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        expected.truncate(10);
+
+        assert_eq!(distances, expected);
+    }
+
+    #[test]
+    fn bulk_load() {
+        let items = items(3000);
+
+        check(&RTree::bulk_load_with(items.clone(), BulkLoad::Str), &items);
+        check(&RTree::bulk_load_with(items.clone(), BulkLoad::Omt), &items);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut items = items(2000);
+        let mut tree = RTree::new();
+
+        for (bounds, index) in &items {
+            tree.insert(*bounds, *index);
+        }
+
+        check(&tree, &items);
+
+        for (bounds, index) in items.iter().step_by(3) {
+            assert_eq!(tree.remove(*bounds, index), Some(*index));
+        }
+
+        items = items
+            .into_iter()
+            .enumerate()
+            .filter(|(position, _)| position % 3 != 0)
+            .map(|(_, item)| item)
+            .collect();
+
+        check(&tree, &items);
+
+        for (bounds, index) in &items {
+            assert_eq!(tree.remove(*bounds, index), Some(*index));
+        }
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.iter().count(), 0);
+    }
+
+    #[test]
+    fn empty() {
+        for tree in [
+            RTree::<f64, usize>::new(),
+            RTree::bulk_load(Vec::<(Bounds2D<f64>, usize)>::new()),
+        ] {
+            assert!(tree.is_empty());
+            assert_eq!(tree.iter().count(), 0);
+            assert_eq!(tree.query(bounds!(0.0, 0.0, 10.0, 10.0)).count(), 0);
+            assert_eq!(tree.nearest_iter((0.0, 0.0), Euclidean).count(), 0);
+        }
+    }
+
+    #[test]
+    fn duplicates() {
+        let bounds = bounds!(10.0, 10.0, 5.0, 5.0);
+        let mut tree = RTree::new();
+
+        for _ in 0..3 {
+            tree.insert(bounds, 'a');
+        }
+        tree.insert(bounds, 'b');
+
+        assert_eq!(tree.remove(bounds, &'c'), None);
+        assert_eq!(tree.remove(bounds!(10.0, 10.0, 5.0, 6.0), &'a'), None);
+        assert_eq!(tree.remove(bounds, &'a'), Some('a'));
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.query(bounds).count(), 3);
+
+        // Removing everything and inserting again leaves a working tree
+        while tree.remove_where(bounds, |_| true).is_some() {}
+
+        assert!(tree.is_empty());
+
+        tree.insert(bounds, 'd');
+        assert_eq!(
+            tree.query(bounds).collect::<Vec<_>>(),
+            vec![(&bounds, &'d')]
+        );
+    }
+}
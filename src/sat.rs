@@ -0,0 +1,441 @@
+use num_traits::{Float, FloatConst};
+
+use crate::{
+    Angle, Bounds2D, Circle2D, Offset2D, Penetration, Point2D, Polygon2D, Size2D, ToPoint2D,
+    ToSize2D, Vector,
+};
+
+/// A rectangle of `size` around `center`, rotated counter-clockwise by `angle`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let rect = RotatedRect2D::new((0.0, 0.0), size!(4.0, 2.0), Angle::degrees(90.0));
+///
+/// assert!(rect.contains_point((0.0, 1.5)));
+/// assert!(!rect.contains_point((1.5, 0.0)));
+/// assert!(rect.bounds().center().euclidean_distance((0.0, 0.0)) < 1e-10);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct RotatedRect2D<T> {
+    pub center: Point2D<T>,
+    pub size: Size2D<T>,
+    pub angle: Angle<T>,
+}
+
+impl<T> RotatedRect2D<T>
+where
+    T: Float + FloatConst,
+{
+    /// Creates a new [RotatedRect2D] around `center`.
+    pub fn new<P, S>(center: P, size: S, angle: Angle<T>) -> Self
+    where
+        P: ToPoint2D<T>,
+        S: ToSize2D<T>,
+    {
+        Self {
+            center: center.to_vector(),
+            size: size.to_size(),
+            angle,
+        }
+    }
+
+    /// Returns the directions of the width and height of the rectangle, which are of unit length.
+    pub fn axes(&self) -> [Offset2D<T>; 2] {
+        let (sin, cos) = self.angle.sin_cos();
+        [Offset2D::new(cos, sin), Offset2D::new(-sin, cos)]
+    }
+
+    /// Returns the corners of the rectangle, going around counter-clockwise.
+    pub fn corners(&self) -> [Point2D<T>; 4] {
+        let two = T::one() + T::one();
+        let [x, y] = self.axes();
+        let (x, y) = (x * (self.size.width / two), y * (self.size.height / two));
+
+        [
+            self.center - x - y,
+            self.center + x - y,
+            self.center + x + y,
+            self.center - x + y,
+        ]
+    }
+
+    /// Returns the smallest bounds containing the rectangle.
+    pub fn bounds(&self) -> Bounds2D<T> {
+        let corners = self.corners();
+        let (min, max) =
+            corners[1..]
+                .iter()
+                .fold((corners[0], corners[0]), |(min, max), corner| {
+                    (
+                        Point2D::new(min.x.min(corner.x), min.y.min(corner.y)),
+                        Point2D::new(max.x.max(corner.x), max.y.max(corner.y)),
+                    )
+                });
+
+        Bounds2D::from_corners(min, max)
+    }
+
+    /// Returns `true` if `point` is inside the rectangle, including its edges.
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P) -> bool {
+        let two = T::one() + T::one();
+        let offset = self.center.offset(point);
+        let [x, y] = self.axes();
+
+        offset.dot(x).abs() <= self.size.width / two
+            && offset.dot(y).abs() <= self.size.height / two
+    }
+}
+
+impl<T> From<Bounds2D<T>> for RotatedRect2D<T>
+where
+    T: Float + FloatConst,
+{
+    fn from(bounds: Bounds2D<T>) -> Self {
+        Self::new(bounds.center(), bounds.size(), Angle::zero())
+    }
+}
+
+/// A convex shape that can be tested for overlap with the separating axis theorem.
+///
+/// See [`separating_axis_test()`] for how the methods are used.
+pub trait SeparatingAxes<T> {
+    /// Returns the directions the edges of the shape face, which do not need to be of
+    /// unit length. Only one of two opposite edges is needed. Round shapes return none.
+    fn axes(&self) -> Vec<Offset2D<T>>;
+
+    /// Returns the corners of the shape, or none if it is round.
+    fn vertices(&self) -> Vec<Point2D<T>>;
+
+    /// Returns the center of the shape.
+    fn center(&self) -> Point2D<T>;
+
+    /// Returns the lowest and highest value of the shape projected onto `axis`,
+    /// which is of unit length.
+    fn project(&self, axis: Offset2D<T>) -> (T, T);
+}
+
+/// Returns how far two convex shapes overlap, using the separating axis theorem,
+/// or [`None`] if they do not overlap. Shapes that only touch do not overlap.
+///
+/// The shapes are projected onto the directions their edges face, and the axis where
+/// they overlap the least gives the [Penetration]. Round shapes have no edges, so they
+/// are instead projected onto the direction towards the closest corner of the other shape.
+///
+/// Moving `b` by [`Penetration::translation()`] separates the shapes.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let diamond = RotatedRect2D::new((0.0, 0.0), size!(2.0, 2.0), Angle::degrees(45.0));
+/// let bounds = bounds!(1.0, -1.0, 2.0, 2.0);
+///
+/// let penetration = separating_axis_test(&diamond, &bounds).unwrap();
+///
+/// assert_eq!(penetration.normal, offset!(1.0, 0.0));
+/// assert!((penetration.depth - (2.0f64.sqrt() - 1.0)).abs() < 1e-10);
+///
+/// let moved = bounds + penetration.translation() * 1.01;
+/// assert!(separating_axis_test(&diamond, &moved).is_none());
+/// ```
+pub fn separating_axis_test<T, A, B>(a: &A, b: &B) -> Option<Penetration<T>>
+where
+    T: Float,
+    A: SeparatingAxes<T> + ?Sized,
+    B: SeparatingAxes<T> + ?Sized,
+{
+    let (a_axes, b_axes) = (a.axes(), b.axes());
+    let (a_vertices, b_vertices) = (a.vertices(), b.vertices());
+
+    let mut axes: Vec<Offset2D<T>> = a_axes
+        .iter()
+        .chain(&b_axes)
+        .filter_map(|axis| axis.try_normalize())
+        .collect();
+
+    if a_axes.is_empty() {
+        axes.push(round_axis(a.center(), &b_vertices, b.center()));
+    }
+
+    if b_axes.is_empty() {
+        axes.push(round_axis(b.center(), &a_vertices, a.center()));
+    }
+
+    let mut best: Option<Penetration<T>> = None;
+
+    for axis in axes {
+        let (a_min, a_max) = a.project(axis);
+        let (b_min, b_max) = b.project(axis);
+
+        // The second shape can be pushed out either way along the axis
+        let forward = a_max - b_min;
+        let backward = b_max - a_min;
+
+        let (depth, normal) = if forward <= backward {
+            (forward, axis)
+        } else {
+            (backward, axis * -T::one())
+        };
+
+        // A single axis without overlap separates the shapes
+        if depth <= T::zero() {
+            return None;
+        }
+
+        let is_shallower = match best {
+            Some(best) => depth < best.depth,
+            None => true,
+        };
+
+        if is_shallower {
+            best = Some(Penetration { normal, depth });
+        }
+    }
+
+    best
+}
+
+/// Returns the direction from `center` to the closest of `vertices`,
+/// or to `other` if there are no vertices.
+fn round_axis<T: Float>(
+    center: Point2D<T>,
+    vertices: &[Point2D<T>],
+    other: Point2D<T>,
+) -> Offset2D<T> {
+    let closest = vertices
+        .iter()
+        .map(|&vertex| center.offset(vertex))
+        .reduce(|best, offset| {
+            if offset.length_squared() < best.length_squared() {
+                offset
+            } else {
+                best
+            }
+        })
+        .unwrap_or_else(|| center.offset(other));
+
+    // Shapes around the same center can be pushed out in any direction
+    closest
+        .try_normalize()
+        .unwrap_or(Offset2D::new(T::one(), T::zero()))
+}
+
+/// Returns the lowest and highest value of `points` projected onto `axis`.
+fn project_points<T, I>(points: I, axis: Offset2D<T>) -> (T, T)
+where
+    T: Float,
+    I: IntoIterator<Item = Point2D<T>>,
+{
+    points
+        .into_iter()
+        .fold((T::infinity(), T::neg_infinity()), |(min, max), point| {
+            let value = point.x * axis.x + point.y * axis.y;
+            (min.min(value), max.max(value))
+        })
+}
+
+impl<T: Float> SeparatingAxes<T> for Bounds2D<T> {
+    fn axes(&self) -> Vec<Offset2D<T>> {
+        vec![
+            Offset2D::new(T::one(), T::zero()),
+            Offset2D::new(T::zero(), T::one()),
+        ]
+    }
+
+    fn vertices(&self) -> Vec<Point2D<T>> {
+        vec![
+            Point2D::new(self.left(), self.top()),
+            Point2D::new(self.right(), self.top()),
+            Point2D::new(self.right(), self.bottom()),
+            Point2D::new(self.left(), self.bottom()),
+        ]
+    }
+
+    fn center(&self) -> Point2D<T> {
+        Bounds2D::center(self)
+    }
+
+    fn project(&self, axis: Offset2D<T>) -> (T, T) {
+        project_points(SeparatingAxes::vertices(self), axis)
+    }
+}
+
+impl<T: Float + FloatConst> SeparatingAxes<T> for RotatedRect2D<T> {
+    fn axes(&self) -> Vec<Offset2D<T>> {
+        RotatedRect2D::axes(self).to_vec()
+    }
+
+    fn vertices(&self) -> Vec<Point2D<T>> {
+        self.corners().to_vec()
+    }
+
+    fn center(&self) -> Point2D<T> {
+        self.center
+    }
+
+    fn project(&self, axis: Offset2D<T>) -> (T, T) {
+        project_points(self.corners(), axis)
+    }
+}
+
+/// The polygon is expected to be convex. Concave polygons are treated as if they were
+/// convex, which may report overlaps inside their dents.
+impl<T: Float> SeparatingAxes<T> for Polygon2D<T> {
+    fn axes(&self) -> Vec<Offset2D<T>> {
+        self.edges()
+            .map(|edge| edge.direction().perpendicular())
+            .collect()
+    }
+
+    fn vertices(&self) -> Vec<Point2D<T>> {
+        Polygon2D::vertices(self).to_vec()
+    }
+
+    fn center(&self) -> Point2D<T> {
+        let vertices = Polygon2D::vertices(self);
+        let count = T::from(vertices.len()).unwrap_or_else(T::one).max(T::one());
+
+        let (x, y) = vertices
+            .iter()
+            .fold((T::zero(), T::zero()), |(x, y), vertex| {
+                (x + vertex.x, y + vertex.y)
+            });
+
+        Point2D::new(x / count, y / count)
+    }
+
+    fn project(&self, axis: Offset2D<T>) -> (T, T) {
+        project_points(Polygon2D::vertices(self).iter().copied(), axis)
+    }
+}
+
+impl<T: Float + FloatConst> SeparatingAxes<T> for Circle2D<T> {
+    fn axes(&self) -> Vec<Offset2D<T>> {
+        Vec::new()
+    }
+
+    fn vertices(&self) -> Vec<Point2D<T>> {
+        Vec::new()
+    }
+
+    fn center(&self) -> Point2D<T> {
+        self.center
+    }
+
+    fn project(&self, axis: Offset2D<T>) -> (T, T) {
+        let center = self.center.x * axis.x + self.center.y * axis.y;
+        (center - self.radius, center + self.radius)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random::Random;
+    use crate::*;
+
+    fn random() -> impl FnMut(f64) -> f64 {
+        let mut random = Random::new(24);
+        move |max| random.float(max)
+    }
+
+    #[test]
+    fn matches_circles() {
+        let mut random = random();
+        let bounds = bounds!(0.0, 0.0, 10.0, 6.0);
+
+        for _ in 0..500 {
+            let a = Circle2D::new((random(20.0) - 5.0, random(20.0) - 5.0), random(4.0) + 0.5);
+            let b = Circle2D::new((random(20.0) - 5.0, random(20.0) - 5.0), random(4.0) + 0.5);
+
+            for (expected, actual) in [
+                (a.penetration(&b), separating_axis_test(&a, &b)),
+                (a.penetration(&bounds), separating_axis_test(&a, &bounds)),
+            ] {
+                assert_eq!(expected.is_some(), actual.is_some());
+
+                if let (Some(expected), Some(actual)) = (expected, actual) {
+                    assert!((expected.depth - actual.depth).abs() < 1e-9);
+                    assert!(expected.normal.euclidean_distance(actual.normal) < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn translation_separates() {
+        let mut random = random();
+
+        for _ in 0..500 {
+            let rect = RotatedRect2D::new(
+                (random(10.0), random(10.0)),
+                (random(6.0) + 0.5, random(6.0) + 0.5),
+                Angle::degrees(random(360.0)),
+            );
+
+            let hull = convex_hull((0..8).map(|_| (random(10.0), random(10.0))));
+            let circle = Circle2D::new((random(10.0), random(10.0)), random(3.0) + 0.5);
+
+            let moved = |translation: Offset2D<f64>| {
+                let hull =
+                    Polygon2D::new(hull.vertices().iter().map(|&vertex| vertex + translation));
+                let circle = Circle2D::new(circle.center + translation, circle.radius);
+
+                (hull, circle)
+            };
+
+            if let Some(penetration) = separating_axis_test(&rect, &hull) {
+                let (separated, _) = moved(penetration.translation() * 1.000001);
+                let (overlapping, _) = moved(penetration.translation() * 0.99);
+
+                assert!(separating_axis_test(&rect, &separated).is_none());
+                assert!(separating_axis_test(&rect, &overlapping).is_some());
+            }
+
+            if let Some(penetration) = separating_axis_test(&hull, &circle) {
+                let (_, separated) = moved(penetration.translation() * 1.000001);
+                let (_, overlapping) = moved(penetration.translation() * 0.99);
+
+                assert!(separating_axis_test(&hull, &separated).is_none());
+                assert!(separating_axis_test(&hull, &overlapping).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn touching_shapes() {
+        let square = polygon![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)];
+        let rect = RotatedRect2D::from(bounds!(2.0, 0.0, 2.0, 2.0));
+
+        assert!(separating_axis_test(&square, &rect).is_none());
+        assert!(separating_axis_test(&rect, &Circle2D::new((5.0, 1.0), 1.0)).is_none());
+
+        let circle = Circle2D::new((1.0, 1.0), 0.5);
+        let penetration = separating_axis_test(&circle, &circle).unwrap();
+
+        assert_eq!(penetration.normal, offset!(1.0, 0.0));
+        assert_eq!(penetration.depth, 1.0);
+    }
+
+    #[test]
+    fn contained_and_identical_shapes() {
+        let square = polygon![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)];
+        let circle = Circle2D::new((1.0, 2.0), 0.5);
+        let penetration = separating_axis_test(&square, &circle).unwrap();
+
+        assert_eq!(penetration.normal, offset!(-1.0, 0.0));
+        assert_eq!(penetration.depth, 1.5);
+
+        let rect = RotatedRect2D::new((3.0, 1.0), size!(4.0f64, 2.0), Angle::degrees(45.0));
+        let penetration = separating_axis_test(&rect, &rect).unwrap();
+        let half = 0.5f64.sqrt();
+
+        assert!((penetration.depth - 2.0).abs() < 1e-10);
+        assert!(penetration.normal.euclidean_distance(offset!(-half, half)) < 1e-10);
+
+        let empty = Polygon2D::<f64>::new(Vec::<(f64, f64)>::new());
+        assert!(separating_axis_test(&empty, &square).is_none());
+        assert!(separating_axis_test(&circle, &empty).is_none());
+    }
+}
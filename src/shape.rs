@@ -0,0 +1,872 @@
+use num_traits::{Float, FloatConst};
+
+use crate::{Bounds2D, Offset2D, Point2D, Segment2D, Size2D, ToPoint2D, ToSize2D, Vector};
+
+/// How far two shapes overlap, and in which direction.
+///
+/// The normal is of unit length and points from the first shape towards the second,
+/// so moving the second shape by `normal * depth` separates them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Penetration<T> {
+    pub normal: Offset2D<T>,
+    pub depth: T,
+}
+
+impl<T> Penetration<T>
+where
+    T: Float,
+{
+    /// Returns the penetration as seen from the second shape.
+    pub fn reverse(self) -> Self {
+        Self {
+            normal: self.normal * -T::one(),
+            depth: self.depth,
+        }
+    }
+
+    /// Returns the smallest offset that moves the second shape out of the first.
+    pub fn translation(&self) -> Offset2D<T> {
+        self.normal * self.depth
+    }
+}
+
+/// Tests whether two shapes overlap.
+///
+/// Shapes that only touch do not overlap.
+pub trait Overlaps<Rhs = Self> {
+    /// Returns `true` if `self` and `other` overlap.
+    fn overlaps(&self, other: &Rhs) -> bool;
+}
+
+/// Finds how far two shapes overlap.
+pub trait Penetrates<T, Rhs = Self>: Overlaps<Rhs> {
+    /// Returns how far `self` and `other` overlap, or [`None`] if they do not.
+    fn penetration(&self, other: &Rhs) -> Option<Penetration<T>>;
+}
+
+/// A circle around `center`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let a = Circle2D::new((0.0, 0.0), 5.0);
+/// let b = Circle2D::new((8.0, 0.0), 5.0);
+///
+/// assert_eq!(a.bounds(), bounds!(-5.0, -5.0, 10.0, 10.0));
+/// assert!(a.contains_point((3.0, 4.0)));
+///
+/// let penetration = a.penetration(&b).unwrap();
+/// assert_eq!(penetration.normal, offset!(1.0, 0.0));
+/// assert_eq!(penetration.depth, 2.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Circle2D<T> {
+    pub center: Point2D<T>,
+    pub radius: T,
+}
+
+impl<T> Circle2D<T>
+where
+    T: Float + FloatConst,
+{
+    /// Creates a new [Circle2D] around `center`.
+    pub fn new<P: ToPoint2D<T>>(center: P, radius: T) -> Self {
+        Self {
+            center: center.to_vector(),
+            radius,
+        }
+    }
+
+    /// Returns the area of the circle.
+    pub fn area(&self) -> T {
+        T::PI() * self.radius * self.radius
+    }
+
+    /// Returns the perimeter of the circle.
+    pub fn perimeter(&self) -> T {
+        T::TAU() * self.radius
+    }
+
+    /// Returns the smallest bounds containing the circle.
+    pub fn bounds(&self) -> Bounds2D<T> {
+        around(self.center, self.radius, self.radius)
+    }
+
+    /// Returns `true` if `point` is inside the circle, including its edge.
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P) -> bool {
+        self.center.euclidean_distance(point) <= self.radius
+    }
+}
+
+/// An ellipse around `center`, with its axes aligned to the X and Y axes.
+///
+/// The width of `radii` is the horizontal radius, and the height is the vertical radius.
+///
+/// The [Penetration] of an ellipse with anything but a circle has no closed form,
+/// so it is found with [`epa_penetration()`](crate::epa_penetration) and is approximate.
+/// An ellipse with a radius of zero is treated as the line between its ends.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let ellipse = Ellipse2D::new((0.0, 0.0), size!(10.0, 5.0));
+///
+/// assert_eq!(ellipse.bounds(), bounds!(-10.0, -5.0, 20.0, 10.0));
+/// assert!(ellipse.contains_point((9.0, 1.0)));
+/// assert!(!ellipse.contains_point((9.0, 4.0)));
+///
+/// assert!(ellipse.overlaps(&bounds!(8.0, 1.0, 10.0, 10.0)));
+/// assert!(!ellipse.overlaps(&bounds!(9.0, 4.0, 10.0, 10.0)));
+///
+/// let penetration = ellipse.penetration(&bounds!(8.0, -1.0, 10.0, 2.0)).unwrap();
+///
+/// assert!(penetration.normal.euclidean_distance((1.0, 0.0)) < 1e-6);
+/// assert!((penetration.depth - 2.0f64).abs() < 1e-6);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Ellipse2D<T> {
+    pub center: Point2D<T>,
+    pub radii: Size2D<T>,
+}
+
+impl<T> Ellipse2D<T>
+where
+    T: Float + FloatConst,
+{
+    /// Creates a new [Ellipse2D] around `center`.
+    pub fn new<P, S>(center: P, radii: S) -> Self
+    where
+        P: ToPoint2D<T>,
+        S: ToSize2D<T>,
+    {
+        Self {
+            center: center.to_vector(),
+            radii: radii.to_size(),
+        }
+    }
+
+    /// Returns the area of the ellipse.
+    pub fn area(&self) -> T {
+        T::PI() * self.radii.width * self.radii.height
+    }
+
+    /// Returns the perimeter of the ellipse.
+    ///
+    /// There is no closed form for this, so it is approximated using
+    /// Ramanujan's second formula, which is exact for circles.
+    pub fn perimeter(&self) -> T {
+        let (a, b) = (self.radii.width, self.radii.height);
+
+        if a + b == T::zero() {
+            return T::zero();
+        }
+
+        let h = ((a - b) / (a + b)).powi(2);
+        let three_h = number::<T>(3.0) * h;
+
+        T::PI()
+            * (a + b)
+            * (T::one() + three_h / (number::<T>(10.0) + (number::<T>(4.0) - three_h).sqrt()))
+    }
+
+    /// Returns the smallest bounds containing the ellipse.
+    pub fn bounds(&self) -> Bounds2D<T> {
+        around(self.center, self.radii.width, self.radii.height)
+    }
+
+    /// Returns `true` if `point` is inside the ellipse, including its edge.
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P) -> bool {
+        let point = self.center.offset(point.to_vector());
+
+        if self.flattened().is_some() {
+            return point.x.abs() <= self.radii.width && point.y.abs() <= self.radii.height;
+        }
+
+        let (x, y) = (point.x / self.radii.width, point.y / self.radii.height);
+
+        x * x + y * y <= T::one()
+    }
+
+    /// Returns the point on the edge of the ellipse closest to `point`.
+    pub fn closest_point<P: ToPoint2D<T>>(&self, point: P) -> Point2D<T> {
+        if let Some(capsule) = self.flattened() {
+            return capsule.segment.closest_point(point);
+        }
+
+        let point = self.center.offset(point.to_vector());
+        let (x, y) = closest_on_ellipse(
+            self.radii.width,
+            self.radii.height,
+            point.x.abs(),
+            point.y.abs(),
+        );
+
+        self.center + Offset2D::new(x.copysign(point.x), y.copysign(point.y))
+    }
+
+    /// Returns the distance from `point` to the ellipse, which is zero inside of it.
+    fn distance_to<P: ToPoint2D<T>>(&self, point: P) -> T {
+        let point = point.to_vector();
+
+        if self.contains_point(point) {
+            T::zero()
+        } else {
+            self.closest_point(point).euclidean_distance(point)
+        }
+    }
+
+    /// Returns the ellipse as a capsule without a radius, if one of its radii is zero.
+    pub(crate) fn flattened(&self) -> Option<Capsule2D<T>> {
+        if self.radii.width > T::zero() && self.radii.height > T::zero() {
+            return None;
+        }
+
+        let (a, b) = (
+            self.radii.width.max(T::zero()),
+            self.radii.height.max(T::zero()),
+        );
+
+        Some(Capsule2D::new(
+            (self.center.x - a, self.center.y - b),
+            (self.center.x + a, self.center.y + b),
+            T::zero(),
+        ))
+    }
+}
+
+/// A rounded shape made of every point within `radius` of `segment`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let capsule = Capsule2D::new((0.0, 0.0), (10.0, 0.0), 2.0);
+///
+/// assert_eq!(capsule.bounds(), bounds!(-2.0, -2.0, 14.0, 4.0));
+/// assert!(capsule.contains_point((11.0, 1.0)));
+///
+/// let circle = Circle2D::new((5.0, 4.0), 3.0);
+/// let penetration = capsule.penetration(&circle).unwrap();
+///
+/// assert_eq!(penetration.normal, offset!(0.0, 1.0));
+/// assert_eq!(penetration.depth, 1.0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Capsule2D<T> {
+    pub segment: Segment2D<T>,
+    pub radius: T,
+}
+
+impl<T> Capsule2D<T>
+where
+    T: Float + FloatConst,
+{
+    /// Creates a new [Capsule2D] from `start` to `end`.
+    pub fn new<P, Q>(start: P, end: Q, radius: T) -> Self
+    where
+        P: ToPoint2D<T>,
+        Q: ToPoint2D<T>,
+    {
+        Self {
+            segment: Segment2D::new(start, end),
+            radius,
+        }
+    }
+
+    /// Returns the area of the capsule.
+    pub fn area(&self) -> T {
+        let two = T::one() + T::one();
+        T::PI() * self.radius * self.radius + two * self.radius * self.segment.length()
+    }
+
+    /// Returns the perimeter of the capsule.
+    pub fn perimeter(&self) -> T {
+        let two = T::one() + T::one();
+        T::TAU() * self.radius + two * self.segment.length()
+    }
+
+    /// Returns the smallest bounds containing the capsule.
+    pub fn bounds(&self) -> Bounds2D<T> {
+        let Segment2D { start, end } = self.segment;
+
+        Bounds2D::from_corners(
+            (
+                start.x.min(end.x) - self.radius,
+                start.y.min(end.y) - self.radius,
+            ),
+            (
+                start.x.max(end.x) + self.radius,
+                start.y.max(end.y) + self.radius,
+            ),
+        )
+    }
+
+    /// Returns `true` if `point` is inside the capsule, including its edge.
+    pub fn contains_point<P: ToPoint2D<T>>(&self, point: P) -> bool {
+        let point = point.to_vector();
+        self.segment.closest_point(point).euclidean_distance(point) <= self.radius
+    }
+}
+
+fn number<T: Float>(value: f64) -> T {
+    T::from(value).expect("Number must fit in the float type")
+}
+
+fn around<T: Float>(center: Point2D<T>, horizontal: T, vertical: T) -> Bounds2D<T> {
+    let two = T::one() + T::one();
+
+    Bounds2D::new(
+        center.x - horizontal,
+        center.y - vertical,
+        horizontal * two,
+        vertical * two,
+    )
+}
+
+/// Returns the penetration of two circles, using `fallback` as the normal if they share a center.
+fn circles<T: Float>(
+    a: Point2D<T>,
+    a_radius: T,
+    b: Point2D<T>,
+    b_radius: T,
+    fallback: Offset2D<T>,
+) -> Option<Penetration<T>> {
+    let offset = a.offset(b);
+    let distance = offset.length();
+    let depth = a_radius + b_radius - distance;
+
+    if depth <= T::zero() {
+        return None;
+    }
+
+    let normal = if distance > T::zero() {
+        offset * distance.recip()
+    } else {
+        fallback
+    };
+
+    Some(Penetration { normal, depth })
+}
+
+/// Returns the point on the edge of an ellipse at the origin closest to `(x, y)`,
+/// where both the radii and the point are in the first quadrant.
+fn closest_on_ellipse<T: Float>(a: T, b: T, x: T, y: T) -> (T, T) {
+    let (a2, b2) = (a * a, b * b);
+
+    // Points on an axis may be closest to a point off the axis,
+    // when they are inside the ellipse and close enough to the center
+    if y == T::zero() {
+        return if a > b && x < (a2 - b2) / a {
+            let x = a2 * x / (a2 - b2);
+            (x, b * (T::one() - (x / a).powi(2)).sqrt())
+        } else {
+            (a, T::zero())
+        };
+    }
+
+    if x == T::zero() {
+        return if b > a && y < (b2 - a2) / b {
+            let y = b2 * y / (b2 - a2);
+            (a * (T::one() - (y / b).powi(2)).sqrt(), y)
+        } else {
+            (T::zero(), b)
+        };
+    }
+
+    // The closest point is `(a²x / (t + a²), b²y / (t + b²))` for the `t` that puts
+    // it on the edge, which is found by bisecting the function below
+    let edge = |t: T| (a * x / (t + a2)).powi(2) + (b * y / (t + b2)).powi(2) - T::one();
+
+    let mut low = -a2.min(b2);
+    let mut high = a.max(b) * x.hypot(y);
+
+    loop {
+        let middle = (low + high) / (T::one() + T::one());
+
+        if middle <= low || middle >= high {
+            break;
+        }
+
+        if edge(middle) > T::zero() {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    (a2 * x / (high + a2), b2 * y / (high + b2))
+}
+
+/// Implements [Overlaps] in terms of [Penetrates] for a pair of shapes,
+/// and both traits for the reversed pair if the shapes are different.
+macro_rules! impl_penetrates {
+    ($a:ident) => {
+        impl<T: Float + FloatConst> Overlaps for $a<T> {
+            fn overlaps(&self, other: &Self) -> bool {
+                self.penetration(other).is_some()
+            }
+        }
+    };
+    ($a:ident, $b:ident) => {
+        impl<T: Float + FloatConst> Overlaps<$b<T>> for $a<T> {
+            fn overlaps(&self, other: &$b<T>) -> bool {
+                self.penetration(other).is_some()
+            }
+        }
+
+        impl<T: Float + FloatConst> Overlaps<$a<T>> for $b<T> {
+            fn overlaps(&self, other: &$a<T>) -> bool {
+                other.overlaps(self)
+            }
+        }
+
+        impl<T: Float + FloatConst> Penetrates<T, $a<T>> for $b<T> {
+            fn penetration(&self, other: &$a<T>) -> Option<Penetration<T>> {
+                other.penetration(self).map(Penetration::reverse)
+            }
+        }
+    };
+}
+
+impl<T: Float + FloatConst> Penetrates<T> for Circle2D<T> {
+    fn penetration(&self, other: &Self) -> Option<Penetration<T>> {
+        circles(
+            self.center,
+            self.radius,
+            other.center,
+            other.radius,
+            Offset2D::new(T::one(), T::zero()),
+        )
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Bounds2D<T>> for Circle2D<T> {
+    fn penetration(&self, other: &Bounds2D<T>) -> Option<Penetration<T>> {
+        let closest = other.clamp_point(self.center);
+
+        if closest != self.center {
+            return circles(
+                self.center,
+                self.radius,
+                closest,
+                T::zero(),
+                Offset2D::new(T::one(), T::zero()),
+            );
+        }
+
+        // The center is inside, so the bounds are pushed out past the closest edge
+        let edges = [
+            (
+                self.center.x - other.left(),
+                Offset2D::new(T::one(), T::zero()),
+            ),
+            (
+                other.right() - self.center.x,
+                Offset2D::new(-T::one(), T::zero()),
+            ),
+            (
+                self.center.y - other.top(),
+                Offset2D::new(T::zero(), T::one()),
+            ),
+            (
+                other.bottom() - self.center.y,
+                Offset2D::new(T::zero(), -T::one()),
+            ),
+        ];
+
+        let (distance, normal) = edges
+            .into_iter()
+            .reduce(|best, edge| if edge.0 < best.0 { edge } else { best })
+            .expect("There must be an edge");
+
+        Some(Penetration {
+            normal,
+            depth: distance + self.radius,
+        })
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Capsule2D<T>> for Circle2D<T> {
+    fn penetration(&self, other: &Capsule2D<T>) -> Option<Penetration<T>> {
+        let closest = other.segment.closest_point(self.center);
+        let fallback = other.segment.direction().perpendicular();
+
+        circles(
+            self.center,
+            self.radius,
+            closest,
+            other.radius,
+            fallback
+                .try_normalize()
+                .unwrap_or(Offset2D::new(T::one(), T::zero())),
+        )
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Ellipse2D<T>> for Circle2D<T> {
+    fn penetration(&self, other: &Ellipse2D<T>) -> Option<Penetration<T>> {
+        if let Some(capsule) = other.flattened() {
+            return self.penetration(&capsule);
+        }
+
+        let closest = other.closest_point(self.center);
+
+        if !other.contains_point(self.center) {
+            return circles(
+                self.center,
+                self.radius,
+                closest,
+                T::zero(),
+                Offset2D::new(T::one(), T::zero()),
+            );
+        }
+
+        // The center is inside, so the ellipse is pushed out along its normal at the closest point
+        let local = other.center.offset(closest);
+        let outward = Offset2D::new(
+            local.x / (other.radii.width * other.radii.width),
+            local.y / (other.radii.height * other.radii.height),
+        );
+
+        Some(Penetration {
+            normal: outward.try_normalize()? * -T::one(),
+            depth: closest.euclidean_distance(self.center) + self.radius,
+        })
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T> for Capsule2D<T> {
+    fn penetration(&self, other: &Self) -> Option<Penetration<T>> {
+        let (a, b) = self.segment.closest_points(other.segment);
+
+        // Crossing segments are pushed apart sideways
+        let fallback = self.segment.direction().perpendicular();
+        let fallback = if fallback.dot(self.segment.start.offset(other.segment.start)) < T::zero() {
+            fallback * -T::one()
+        } else {
+            fallback
+        };
+
+        circles(
+            a,
+            self.radius,
+            b,
+            other.radius,
+            fallback
+                .try_normalize()
+                .unwrap_or(Offset2D::new(T::one(), T::zero())),
+        )
+    }
+}
+
+impl<T: Float + FloatConst> Penetrates<T, Bounds2D<T>> for Capsule2D<T> {
+    fn penetration(&self, other: &Bounds2D<T>) -> Option<Penetration<T>> {
+        let (left, right) = (other.left(), other.right());
+        let (top, bottom) = (other.top(), other.bottom());
+
+        if self.segment.clip(*other).is_none() {
+            let edges = [
+                Segment2D::new((left, top), (right, top)),
+                Segment2D::new((right, top), (right, bottom)),
+                Segment2D::new((right, bottom), (left, bottom)),
+                Segment2D::new((left, bottom), (left, top)),
+            ];
+
+            let (a, b) = edges
+                .into_iter()
+                .map(|edge| self.segment.closest_points(edge))
+                .reduce(|best, pair| {
+                    if pair.0.euclidean_distance(pair.1) < best.0.euclidean_distance(best.1) {
+                        pair
+                    } else {
+                        best
+                    }
+                })
+                .expect("There must be an edge");
+
+            return circles(
+                a,
+                self.radius,
+                b,
+                T::zero(),
+                Offset2D::new(T::one(), T::zero()),
+            );
+        }
+
+        // The segment is inside, so the shapes are separated along the axis they overlap the least on
+        let corners = [
+            Point2D::new(left, top),
+            Point2D::new(right, top),
+            Point2D::new(right, bottom),
+            Point2D::new(left, bottom),
+        ];
+
+        let mut axes = vec![
+            Offset2D::new(T::one(), T::zero()),
+            Offset2D::new(T::zero(), T::one()),
+        ];
+
+        axes.extend(self.segment.direction().perpendicular().try_normalize());
+
+        let project =
+            |axis: Offset2D<T>, point: Point2D<T>| axis.dot(Offset2D::new(point.x, point.y));
+        let (start, end) = (self.segment.start, self.segment.end);
+        let center = other.center();
+        let middle = self.segment.at(T::one() / (T::one() + T::one()));
+
+        axes.into_iter()
+            .map(|axis| {
+                let (a, b) = (project(axis, start), project(axis, end));
+                let (a_min, a_max) = (a.min(b) - self.radius, a.max(b) + self.radius);
+
+                let projected = corners.map(|corner| project(axis, corner));
+                let b_min = projected.into_iter().fold(T::infinity(), T::min);
+                let b_max = projected.into_iter().fold(T::neg_infinity(), T::max);
+
+                let depth = (a_max - b_min).min(b_max - a_min);
+                let normal = if project(axis, center) >= project(axis, middle) {
+                    axis
+                } else {
+                    axis * -T::one()
+                };
+
+                Penetration { normal, depth }
+            })
+            .reduce(|best, axis| if axis.depth < best.depth { axis } else { best })
+            .filter(|penetration| penetration.depth > T::zero())
+    }
+}
+
+impl<T: Float + FloatConst> Overlaps for Ellipse2D<T> {
+    fn overlaps(&self, other: &Self) -> bool {
+        if let Some(capsule) = self.flattened() {
+            return other.overlaps(&capsule);
+        }
+
+        if let Some(capsule) = other.flattened() {
+            return self.overlaps(&capsule);
+        }
+
+        // Scaling space so that `self` is a unit circle keeps `other` an aligned ellipse
+        let scaled = Ellipse2D {
+            center: Point2D::new(
+                (other.center.x - self.center.x) / self.radii.width,
+                (other.center.y - self.center.y) / self.radii.height,
+            ),
+            radii: Size2D::new(
+                other.radii.width / self.radii.width,
+                other.radii.height / self.radii.height,
+            ),
+        };
+
+        scaled.contains_point((T::zero(), T::zero()))
+            || scaled.distance_to((T::zero(), T::zero())) < T::one()
+    }
+}
+
+impl<T: Float + FloatConst> Overlaps<Bounds2D<T>> for Ellipse2D<T> {
+    fn overlaps(&self, other: &Bounds2D<T>) -> bool {
+        if let Some(capsule) = self.flattened() {
+            return capsule.overlaps(other);
+        }
+
+        // Scaling space so that `self` is a unit circle keeps `other` aligned bounds
+        let scaled = Bounds2D::from_corners(
+            (
+                (other.left() - self.center.x) / self.radii.width,
+                (other.top() - self.center.y) / self.radii.height,
+            ),
+            (
+                (other.right() - self.center.x) / self.radii.width,
+                (other.bottom() - self.center.y) / self.radii.height,
+            ),
+        );
+
+        Circle2D::new((T::zero(), T::zero()), T::one()).overlaps(&scaled)
+    }
+}
+
+impl<T: Float + FloatConst> Overlaps<Capsule2D<T>> for Ellipse2D<T> {
+    fn overlaps(&self, other: &Capsule2D<T>) -> bool {
+        if let Some(capsule) = self.flattened() {
+            return capsule.overlaps(other);
+        }
+
+        // Scaling space so that `self` is a unit circle keeps the segment a segment,
+        // so whether it reaches the ellipse can be found exactly
+        let scale = |point: Point2D<T>| {
+            Point2D::new(
+                (point.x - self.center.x) / self.radii.width,
+                (point.y - self.center.y) / self.radii.height,
+            )
+        };
+
+        let origin = Point2D::new(T::zero(), T::zero());
+        let scaled = Segment2D::new(scale(other.segment.start), scale(other.segment.end));
+        let reach = scaled.closest_point(origin).euclidean_distance(origin);
+
+        // A segment touching the ellipse overlaps if it has any width, or goes inside
+        if reach <= T::one() {
+            return other.radius > T::zero() || reach < T::one();
+        }
+
+        if other.radius <= T::zero() {
+            return false;
+        }
+
+        // The distance to a convex shape is convex, so the closest point
+        // along the segment can be found with a ternary search, which stops
+        // as soon as any point is close enough
+        let segment = other.segment;
+        let (mut low, mut high) = (T::zero(), T::one());
+        let three = number::<T>(3.0);
+
+        for _ in 0..100 {
+            let a = low + (high - low) / three;
+            let b = high - (high - low) / three;
+
+            if a >= b {
+                break;
+            }
+
+            let (distance_a, distance_b) = (
+                self.distance_to(segment.at(a)),
+                self.distance_to(segment.at(b)),
+            );
+
+            if distance_a.min(distance_b) < other.radius {
+                return true;
+            }
+
+            if distance_a < distance_b {
+                high = b;
+            } else {
+                low = a;
+            }
+        }
+
+        self.distance_to(segment.at(low)) < other.radius
+    }
+}
+
+impl_penetrates!(Circle2D);
+impl_penetrates!(Circle2D, Bounds2D);
+impl_penetrates!(Circle2D, Capsule2D);
+impl_penetrates!(Circle2D, Ellipse2D);
+impl_penetrates!(Capsule2D);
+impl_penetrates!(Capsule2D, Bounds2D);
+
+impl<T: Float + FloatConst> Overlaps<Ellipse2D<T>> for Bounds2D<T> {
+    fn overlaps(&self, other: &Ellipse2D<T>) -> bool {
+        other.overlaps(self)
+    }
+}
+
+impl<T: Float + FloatConst> Overlaps<Ellipse2D<T>> for Capsule2D<T> {
+    fn overlaps(&self, other: &Ellipse2D<T>) -> bool {
+        other.overlaps(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    #[test]
+    fn closest_point_on_ellipse() {
+        let ellipse = Ellipse2D::new((3.0, -2.0), size!(8.0, 3.0));
+
+        for (x, y) in [
+            (20.0, 5.0),
+            (4.0, -1.0),
+            (3.0, 10.0),
+            (5.0, -2.0),
+            (-10.0, -2.0),
+            (3.0, -2.0),
+        ] {
+            let closest = ellipse.closest_point((x, y));
+            let local = ellipse.center.offset(closest);
+
+            // The point must be on the edge
+            let edge = (local.x / 8.0f64).powi(2) + (local.y / 3.0f64).powi(2);
+            assert!((edge - 1.0).abs() < 1e-9);
+
+            // And no point on the edge may be closer
+            let distance = closest.euclidean_distance((x, y));
+
+            for step in 0..3600 {
+                let angle = Angle::degrees(step as f64 / 10.0);
+                let (sin, cos) = angle.sin_cos();
+                let other = point!(3.0 + 8.0 * cos, -2.0 + 3.0 * sin);
+
+                assert!(other.euclidean_distance((x, y)) >= distance - 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn penetration_separates() {
+        let bounds = bounds!(0.0, 0.0, 10.0, 6.0);
+        let circles = [
+            Circle2D::new((-1.0, 3.0), 2.0),
+            Circle2D::new((2.0, 3.0), 4.0),
+            Circle2D::new((12.0, 8.0), 3.0),
+        ];
+
+        for circle in circles {
+            let penetration = circle.penetration(&bounds).unwrap();
+            let moved = bounds + penetration.normal * penetration.depth;
+
+            assert!(circle.overlaps(&bounds));
+            assert!(!circle.overlaps(&(moved + penetration.normal * 1e-9)));
+            assert!(circle.overlaps(&(bounds + penetration.normal * (penetration.depth * 0.99))));
+        }
+
+        let capsule = Capsule2D::new((-5.0, 3.0), (3.0, 3.0), 1.0);
+        let penetration = capsule.penetration(&bounds).unwrap();
+
+        assert_eq!(penetration.normal, offset!(1.0, 0.0));
+        assert_eq!(penetration.depth, 4.0);
+        assert_eq!(
+            bounds.penetration(&capsule).unwrap().normal,
+            offset!(-1.0, 0.0)
+        );
+
+        assert!(!Capsule2D::new((-5.0, -2.0), (20.0, -2.0), 1.0).overlaps(&bounds));
+        assert!(Ellipse2D::new((-4.0, 3.0), size!(5.0, 1.0)).overlaps(&capsule));
+        assert!(!Ellipse2D::new((-4.0, 6.0), size!(5.0, 1.0)).overlaps(&capsule));
+    }
+
+    #[test]
+    fn flat_ellipse() {
+        let ellipse = Ellipse2D::new((0.0, 0.0), size!(0.0f64, 5.0));
+
+        assert!(ellipse.contains_point((0.0, 3.0)));
+        assert!(!ellipse.contains_point((0.1, 0.0)));
+        assert!(!ellipse.contains_point((0.0, 5.1)));
+        assert_eq!(ellipse.closest_point((3.0, 1.0)), point!(0.0, 1.0));
+        assert_eq!(ellipse.closest_point((3.0, 9.0)), point!(0.0, 5.0));
+
+        assert!(ellipse.overlaps(&bounds!(-1.0, -1.0, 2.0, 2.0)));
+        assert!(!ellipse.overlaps(&bounds!(1.0, -1.0, 2.0, 2.0)));
+        assert!(ellipse.overlaps(&Ellipse2D::new((1.0, 0.0), size!(2.0, 1.0))));
+        assert!(!ellipse.overlaps(&Ellipse2D::new((3.0, 0.0), size!(2.0, 1.0))));
+        assert!(ellipse.overlaps(&Capsule2D::new((-1.0, 0.0), (1.0, 0.0), 0.5)));
+
+        let circle = Circle2D::new((1.0, 0.0), 2.0);
+        let penetration = circle.penetration(&ellipse).unwrap();
+
+        assert_eq!(penetration.normal, offset!(-1.0, 0.0));
+        assert_eq!(penetration.depth, 1.0);
+
+        // Without any area, a point does not overlap anything it only touches
+        let point = Ellipse2D::new((0.0, 0.0), size!(0.0, 0.0));
+
+        assert!(point.contains_point((0.0, 0.0)));
+        assert_eq!(point.perimeter(), 0.0);
+        assert!(!point.overlaps(&Ellipse2D::new((2.0, 0.0), size!(2.0, 1.0))));
+        assert!(point.overlaps(&Ellipse2D::new((1.0, 0.0), size!(2.0, 1.0))));
+    }
+}
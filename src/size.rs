@@ -1,19 +1,26 @@
-use num_traits::{AsPrimitive, Num, NumAssign};
+use num_traits::{AsPrimitive, Bounded, Num, NumAssign};
 use std::{
     cmp::Ordering,
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use crate::{Bounds2D, ToPoint2D};
+use crate::{
+    Align2D, AspectRatio, Bounds2D, Bounds3D, ByArea, FitMode, IntoBounds2D, SizeOrder, ToPoint2D,
+    ToPoint3D, UnknownUnit, Widen,
+};
 
 /// A vector describing a two-dimensional size.
-#[derive(Debug, Default, PartialEq, Clone, Copy, Hash)]
-pub struct Size2D<T> {
+pub struct Size2D<T, U = UnknownUnit> {
     pub width: T,
     pub height: T,
+
+    _unit: PhantomData<U>,
 }
 
-impl<T> Size2D<T>
+impl<T, U> Size2D<T, U>
 where
     T: Copy,
 {
@@ -27,10 +34,14 @@ where
     /// let size = Size2D::new(20, 50);
     ///
     /// // Prefer doing this instead
-    /// let size = size!(20, 50);
+    /// assert_eq!(size, size!(20, 50));
     /// ```
     pub fn new(width: T, height: T) -> Self {
-        Self { width, height }
+        Self {
+            width,
+            height,
+            _unit: PhantomData,
+        }
     }
 
     /// Returns a new [Size2D] where `width` and `height` are equal.
@@ -44,10 +55,10 @@ where
     /// #
     /// // This is acceptable, but...
     /// let size = Size2D::square(200);
+    /// assert_eq!(size, size!(200, 200));
     ///
     /// // ...this is the preferred way
     /// let size = size!(200; 2);
-    ///
     /// assert_eq!(size, size!(200, 200));
     /// ```
     pub fn square(size: T) -> Self {
@@ -83,15 +94,19 @@ where
     ///
     /// assert_eq!(b, size!(200, 400));
     /// ```
-    pub fn cast<C>(&self) -> Size2D<C>
+    pub fn cast<C>(&self) -> Size2D<C, U>
     where
         C: Copy + 'static,
         T: AsPrimitive<C>,
     {
-        Size2D {
-            width: self.width.as_(),
-            height: self.height.as_(),
-        }
+        Size2D::new(self.width.as_(), self.height.as_())
+    }
+
+    /// Reinterprets `self` as a size in another unit, keeping the components as they are.
+    ///
+    /// See [`VectorN::cast_unit()`](crate::VectorN::cast_unit) for more information.
+    pub fn cast_unit<V>(&self) -> Size2D<T, V> {
+        Size2D::new(self.width, self.height)
     }
 
     /// Returns a new [Bounds2D] using `self` as size,
@@ -105,12 +120,12 @@ where
     ///
     /// assert_eq!(bounds, bounds!(2, 4, 10, 15));
     /// ```
-    pub fn with_position<P: ToPoint2D<T>>(self, position: P) -> Bounds2D<T> {
+    pub fn with_position<P: ToPoint2D<T, U>>(self, position: P) -> Bounds2D<T, U> {
         Bounds2D::from_position_and_size(position, self)
     }
 }
 
-impl<T> Size2D<T>
+impl<T, U> Size2D<T, U>
 where
     T: Num + Copy + PartialOrd,
 {
@@ -128,7 +143,7 @@ where
     ///
     /// assert_eq!(inner.grow(outer), size!(500, 900));
     /// ```
-    pub fn grow<S: ToSize2D<T>>(&self, size: S) -> Size2D<T> {
+    pub fn grow<S: ToSize2D<T, U>>(&self, size: S) -> Size2D<T, U> {
         let size = size.to_size();
 
         let bigger_width = if size.width > self.width {
@@ -160,7 +175,7 @@ where
     ///
     /// assert_eq!(outer.shrink(inner), size!(100, 200));
     /// ```
-    pub fn shrink<S: ToSize2D<T>>(&self, size: S) -> Size2D<T> {
+    pub fn shrink<S: ToSize2D<T, U>>(&self, size: S) -> Size2D<T, U> {
         let size = size.to_size();
 
         let smaller_width = if size.width < self.width {
@@ -191,22 +206,202 @@ where
     /// assert_eq!(size!(100, 400).constrain(min, max), size!(200, 400));
     /// assert_eq!(size!(600, 300).constrain(min, max), size!(500, 300));
     /// ```
-    pub fn constrain<S: ToSize2D<T>>(&self, min: S, max: S) -> Size2D<T> {
+    pub fn constrain<S: ToSize2D<T, U>>(&self, min: S, max: S) -> Size2D<T, U> {
         let min = min.to_size();
         let max = max.to_size();
 
         self.shrink(max).grow(min)
     }
-}
 
-impl<T> Size2D<T>
-where
-    T: Copy,
-    Self: PartialOrd,
-{
+    /// Returns bounds of this size placed inside `container` according to `align`.
+    ///
+    /// See [`Align::place()`](crate::Align::place) for how integers are rounded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let screen = bounds!(0, 0, 1920, 1080);
+    /// let dialog = size!(400, 300).align_within(screen, Align2D::CENTER);
+    ///
+    /// assert_eq!(dialog, bounds!(760, 390, 400, 300));
+    /// ```
+    pub fn align_within<B: IntoBounds2D<T, U>>(
+        &self,
+        container: B,
+        align: Align2D,
+    ) -> Bounds2D<T, U>
+    where
+        T: Bounded,
+    {
+        let container = container.to_bounds();
+
+        let (x, width) = align
+            .horizontal
+            .place(self.width, container.left(), container.width());
+        let (y, height) = align
+            .vertical
+            .place(self.height, container.top(), container.height());
+
+        Bounds2D::new(x, y, width, height)
+    }
+
+    /// Returns the aspect ratio of the size.
+    pub fn aspect_ratio(&self) -> AspectRatio<T> {
+        AspectRatio::new(self.width, self.height)
+    }
+
+    /// Returns `self` resized to fit inside `container` according to `mode`.
+    ///
+    /// When keeping the aspect ratio with integers, the side that is scaled is rounded down,
+    /// except when covering, where it is never rounded below the container.
+    /// Sizes without area cannot be scaled, so they are only resized by [`FitMode::Fill`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let image = size!(4000u32, 3000);
+    /// let thumbnail = size!(200, 200);
+    ///
+    /// assert_eq!(image.fit(thumbnail, FitMode::Contain), size!(200, 150));
+    /// assert_eq!(image.fit(thumbnail, FitMode::Cover), size!(266, 200));
+    /// assert_eq!(image.fit(thumbnail, FitMode::Fill), size!(200, 200));
+    /// assert_eq!(image.fit(thumbnail, FitMode::None), size!(4000, 3000));
+    ///
+    /// // Small images are not scaled up
+    /// assert_eq!(size!(100, 50).fit(thumbnail, FitMode::ScaleDown), size!(100, 50));
+    /// assert_eq!(image.fit(thumbnail, FitMode::ScaleDown), size!(200, 150));
+    /// ```
+    pub fn fit<S: ToSize2D<T, U>>(&self, container: S, mode: FitMode) -> Size2D<T, U>
+    where
+        T: Widen,
+    {
+        let container = container.to_size();
+        let ratio = self.aspect_ratio();
+
+        let has_area = self.width > T::zero() && self.height > T::zero();
+        let is_wider = ratio.is_wider_than(container.aspect_ratio());
+
+        match mode {
+            FitMode::Fill => container,
+            FitMode::None => *self,
+            _ if !has_area => *self,
+            FitMode::Contain if is_wider => ratio.size_for_width(container.width),
+            FitMode::Contain => ratio.size_for_height(container.height),
+            FitMode::Cover if is_wider => ratio.size_for_height(container.height),
+            FitMode::Cover => ratio.size_for_width(container.width),
+            FitMode::ScaleDown => {
+                if self.width <= container.width && self.height <= container.height {
+                    *self
+                } else {
+                    self.fit(container, FitMode::Contain)
+                }
+            }
+        }
+    }
+
+    /// Returns bounds of this size resized to fit inside `container` according to `mode`,
+    /// and then placed inside it according to `align`.
+    ///
+    /// See [`fit()`](crate::Size2D::fit) and [`align_within()`](crate::Size2D::align_within) for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let frame = bounds!(0, 0, 200, 200);
+    /// let placed = size!(4000, 3000).fit_within(frame, FitMode::Contain, Align2D::CENTER);
+    ///
+    /// assert_eq!(placed, bounds!(0, 25, 200, 150));
+    ///
+    /// // Covering with unsigned sizes places the overflow past the end instead of underflowing
+    /// let frame = bounds!(0u32, 0, 200, 200);
+    /// let placed = size!(4000u32, 3000).fit_within(frame, FitMode::Cover, Align2D::CENTER);
+    ///
+    /// assert_eq!(placed, bounds!(0, 0, 266, 200));
+    /// ```
+    pub fn fit_within<B: IntoBounds2D<T, U>>(
+        &self,
+        container: B,
+        mode: FitMode,
+        align: Align2D,
+    ) -> Bounds2D<T, U>
+    where
+        T: Bounded + Widen,
+    {
+        let container = container.to_bounds();
+
+        self.fit(container.size(), mode)
+            .align_within(container, align)
+    }
+
+    /// Returns `true` if `self` is not wider or taller than `size`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert!(size!(10, 20).fits_in(size!(10, 30)));
+    /// assert!(!size!(10, 20).fits_in(size!(30, 10)));
+    /// ```
+    pub fn fits_in<S: ToSize2D<T, U>>(&self, size: S) -> bool {
+        let size = size.to_size();
+        self.width <= size.width && self.height <= size.height
+    }
+
+    /// Returns `true` if `size` is not wider or taller than `self`.
+    /// This is the opposite of [`fits_in()`](crate::Size2D::fits_in).
+    pub fn contains_size<S: ToSize2D<T, U>>(&self, size: S) -> bool {
+        size.to_size().fits_in(*self)
+    }
+
+    /// Returns the greater size between `self` and `size`, as ordered by `order`.
+    /// If they are equal or cannot be ordered, `self` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = size!(100, 20);
+    /// let b = size!(50, 50);
+    ///
+    /// assert_eq!(a.max_by(ByWidth, b), a);
+    /// assert_eq!(a.max_by(ByHeight, b), b);
+    /// ```
+    pub fn max_by<O, S>(&self, order: O, size: S) -> Size2D<T, U>
+    where
+        O: SizeOrder<T>,
+        S: ToSize2D<T, U>,
+    {
+        let size = size.to_size();
+
+        match order.compare(&size, self) {
+            Some(Ordering::Greater) => size,
+            _ => *self,
+        }
+    }
+
+    /// Returns the lesser size between `self` and `size`, as ordered by `order`.
+    /// If they are equal or cannot be ordered, `self` is returned.
+    pub fn min_by<O, S>(&self, order: O, size: S) -> Size2D<T, U>
+    where
+        O: SizeOrder<T>,
+        S: ToSize2D<T, U>,
+    {
+        let size = size.to_size();
+
+        match order.compare(&size, self) {
+            Some(Ordering::Less) => size,
+            _ => *self,
+        }
+    }
+
     /// Returns the bigger size area between `self` and `size`.
     /// If you need to max individual components, use [`grow()`](crate::Size2D::grow)
     ///
+    /// This is a shorthand for `self.max_by(ByArea, size)`.
+    ///
     /// # Examples
     /// ```
     /// # use geologic::*;
@@ -216,20 +411,15 @@ where
     ///
     /// assert_eq!(a.max_area(b), size!(200, 400));
     /// ```
-    pub fn max_area<S: ToSize2D<T>>(&self, size: S) -> Size2D<T> {
-        let this = *self;
-        let size = size.to_size();
-
-        if size > this {
-            size
-        } else {
-            this
-        }
+    pub fn max_area<S: ToSize2D<T, U>>(&self, size: S) -> Size2D<T, U> {
+        self.max_by(ByArea, size)
     }
 
     /// Returns the smaller size area between `self` and `size`.
     /// If you need to min individual components, use [`shrink()`](crate::Size2D::shrink)
     ///
+    /// This is a shorthand for `self.min_by(ByArea, size)`.
+    ///
     /// # Examples
     /// ```
     /// # use geologic::*;
@@ -239,15 +429,8 @@ where
     ///
     /// assert_eq!(a.min_area(b), size!(100, 300));
     /// ```
-    pub fn min_area<S: ToSize2D<T>>(&self, size: S) -> Size2D<T> {
-        let this = *self;
-        let size = size.to_size();
-
-        if size < this {
-            size
-        } else {
-            this
-        }
+    pub fn min_area<S: ToSize2D<T, U>>(&self, size: S) -> Size2D<T, U> {
+        self.min_by(ByArea, size)
     }
 
     /// Clamps the size area between `min` and `max`.
@@ -262,21 +445,18 @@ where
     ///
     /// assert_eq!(a.clamp_area(b, size!(300, 300)), size!(300, 300));
     /// ```
-    pub fn clamp_area<S: ToSize2D<T>>(&self, min: S, max: S) -> Size2D<T> {
-        let min = min.to_size();
-        let max = max.to_size();
-
+    pub fn clamp_area<S: ToSize2D<T, U>>(&self, min: S, max: S) -> Size2D<T, U> {
         self.min_area(max).max_area(min)
     }
 }
 
 /// Implements adding two sizes together.
-impl<T, R> Add<R> for Size2D<T>
+impl<T, U, R> Add<R> for Size2D<T, U>
 where
     T: Num + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
-    type Output = Size2D<T>;
+    type Output = Size2D<T, U>;
 
     fn add(self, rhs: R) -> Self::Output {
         let rhs = rhs.to_size();
@@ -284,10 +464,10 @@ where
     }
 }
 
-impl<T, R> AddAssign<R> for Size2D<T>
+impl<T, U, R> AddAssign<R> for Size2D<T, U>
 where
     T: Num + NumAssign + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
     fn add_assign(&mut self, rhs: R) {
         let rhs = rhs.to_size();
@@ -298,12 +478,12 @@ where
 }
 
 /// Implements subtracting two sizes
-impl<T, R> Sub<R> for Size2D<T>
+impl<T, U, R> Sub<R> for Size2D<T, U>
 where
     T: Num + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
-    type Output = Size2D<T>;
+    type Output = Size2D<T, U>;
 
     fn sub(self, rhs: R) -> Self::Output {
         let rhs = rhs.to_size();
@@ -311,10 +491,10 @@ where
     }
 }
 
-impl<T, R> SubAssign<R> for Size2D<T>
+impl<T, U, R> SubAssign<R> for Size2D<T, U>
 where
     T: Num + NumAssign + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
     fn sub_assign(&mut self, rhs: R) {
         let rhs = rhs.to_size();
@@ -325,12 +505,12 @@ where
 }
 
 /// Implements multiplying two points
-impl<T, R> Mul<R> for Size2D<T>
+impl<T, U, R> Mul<R> for Size2D<T, U>
 where
     T: Num + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
-    type Output = Size2D<T>;
+    type Output = Size2D<T, U>;
 
     fn mul(self, rhs: R) -> Self::Output {
         let rhs = rhs.to_size();
@@ -338,10 +518,10 @@ where
     }
 }
 
-impl<T, R> MulAssign<R> for Size2D<T>
+impl<T, U, R> MulAssign<R> for Size2D<T, U>
 where
     T: Num + NumAssign + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
     fn mul_assign(&mut self, rhs: R) {
         let rhs = rhs.to_size();
@@ -352,12 +532,12 @@ where
 }
 
 /// Implements dividing two points
-impl<T, R> Div<R> for Size2D<T>
+impl<T, U, R> Div<R> for Size2D<T, U>
 where
     T: Num + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
-    type Output = Size2D<T>;
+    type Output = Size2D<T, U>;
 
     fn div(self, rhs: R) -> Self::Output {
         let rhs = rhs.to_size();
@@ -365,10 +545,10 @@ where
     }
 }
 
-impl<T, R> DivAssign<R> for Size2D<T>
+impl<T, U, R> DivAssign<R> for Size2D<T, U>
 where
     T: Num + NumAssign + Copy,
-    R: ToSize2D<T>,
+    R: ToSize2D<T, U>,
 {
     fn div_assign(&mut self, rhs: R) {
         let rhs = rhs.to_size();
@@ -378,33 +558,13 @@ where
     }
 }
 
-impl<T> PartialOrd for Size2D<T>
-where
-    T: Num + Copy + PartialEq + PartialOrd,
-{
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        let area = self.area();
-        let other = other.area();
-
-        if area == other {
-            return Some(Ordering::Equal);
-        }
-
-        if area > other {
-            Some(Ordering::Greater)
-        } else {
-            Some(Ordering::Less)
-        }
-    }
-}
-
-impl<T> From<Size2D<T>> for (T, T) {
-    fn from(size: Size2D<T>) -> Self {
+impl<T, U> From<Size2D<T, U>> for (T, T) {
+    fn from(size: Size2D<T, U>) -> Self {
         (size.width, size.height)
     }
 }
 
-impl<T> From<(T, T)> for Size2D<T>
+impl<T, U> From<(T, T)> for Size2D<T, U>
 where
     T: Num + Copy,
 {
@@ -428,7 +588,7 @@ where
 /// // But we can also pass a tuple
 /// size.grow((400, 200));
 /// ```
-pub trait ToSize2D<T> {
+pub trait ToSize2D<T, U = UnknownUnit> {
     /// Creates a new [Size2D] from `self`
     ///
     /// # Examples
@@ -439,21 +599,483 @@ pub trait ToSize2D<T> {
     ///
     /// assert_eq!(size, size!(200, 100));
     /// ```
-    fn to_size(self) -> Size2D<T>;
+    fn to_size(self) -> Size2D<T, U>;
 }
 
-impl<T> ToSize2D<T> for Size2D<T> {
-    fn to_size(self) -> Size2D<T> {
+impl<T, U> ToSize2D<T, U> for Size2D<T, U> {
+    fn to_size(self) -> Size2D<T, U> {
         self
     }
 }
 
 // Allows passing a tuple to functions that expect IntoSize2D
-impl<T> ToSize2D<T> for (T, T)
+impl<T, U> ToSize2D<T, U> for (T, T)
 where
     T: Num + Copy,
 {
-    fn to_size(self) -> Size2D<T> {
+    fn to_size(self) -> Size2D<T, U> {
         Size2D::new(self.0, self.1)
     }
 }
+
+// These are implemented by hand, so that the unit marker
+// is not required to implement the traits as well.
+impl<T, U> Clone for Size2D<T, U>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            width: self.width.clone(),
+            height: self.height.clone(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Copy for Size2D<T, U> where T: Copy {}
+
+impl<T, U> PartialEq for Size2D<T, U>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height
+    }
+}
+
+impl<T, U> Eq for Size2D<T, U> where T: Eq {}
+
+impl<T, U> Hash for Size2D<T, U>
+where
+    T: Hash,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<T, U> Default for Size2D<T, U>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self {
+            width: T::default(),
+            height: T::default(),
+            _unit: PhantomData,
+        }
+    }
+}
+
+impl<T, U> Debug for Size2D<T, U>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Size2D")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+/// A vector describing a three-dimensional size.
+#[derive(Debug, Default, PartialEq, Clone, Copy, Hash)]
+pub struct Size3D<T> {
+    pub width: T,
+    pub height: T,
+    pub depth: T,
+}
+
+impl<T> Size3D<T>
+where
+    T: Copy,
+{
+    /// Create a new [Size3D]. In most cases you should use
+    /// the `size!()` macro instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let size = Size3D::new(20, 50, 10);
+    ///
+    /// // Prefer doing this instead
+    /// let size = size!(20, 50, 10);
+    /// ```
+    pub fn new(width: T, height: T, depth: T) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+        }
+    }
+
+    /// Returns a new [Size3D] where `width`, `height` and `depth` are equal.
+    ///
+    /// Prefer using the splat syntax with the `size()` macro instead
+    /// of calling this directly.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// // This is acceptable, but...
+    /// let size = Size3D::cube(200);
+    ///
+    /// // ...this is the preferred way
+    /// let size = size!(200; 3);
+    ///
+    /// assert_eq!(size, size!(200, 200, 200));
+    /// ```
+    pub fn cube(size: T) -> Self {
+        Self::new(size, size, size)
+    }
+
+    /// Gets the volume of the size. This is a shorthand for `size.width * size.height * size.depth`
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let size = size!(10, 20, 30);
+    ///
+    /// assert_eq!(size.volume(), 6000);
+    /// ```
+    pub fn volume(&self) -> T
+    where
+        T: Mul<Output = T>,
+    {
+        self.width * self.height * self.depth
+    }
+
+    /// Casts `self` into a new [`Size3D<C>`](crate::Size3D)
+    /// where `C` is the (usually inferred) input type.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = size!(200.24, 400.90, 2.5);
+    /// let b: Size3D<u32> = a.cast();
+    ///
+    /// assert_eq!(b, size!(200, 400, 2));
+    /// ```
+    pub fn cast<C>(&self) -> Size3D<C>
+    where
+        C: Copy + 'static,
+        T: AsPrimitive<C>,
+    {
+        Size3D {
+            width: self.width.as_(),
+            height: self.height.as_(),
+            depth: self.depth.as_(),
+        }
+    }
+
+    /// Returns a new [Bounds3D] using `self` as size,
+    /// and `position` as the position.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let bounds = size!(10i32, 15, 20).with_position(point!(2, 4, 6));
+    ///
+    /// assert_eq!(bounds, bounds!(2, 4, 6, 10, 15, 20));
+    /// ```
+    pub fn with_position<P: ToPoint3D<T>>(self, position: P) -> Bounds3D<T> {
+        Bounds3D::from_position_and_size(position, self)
+    }
+}
+
+impl<T> Size3D<T>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Compares the components in `size` and `self`, creating a new size
+    /// where the components are the greater values.
+    ///
+    /// See [`Size2D::grow()`](crate::Size2D::grow) for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let inner = size!(500, 700, 100);
+    /// let outer = size!(400, 900, 200);
+    ///
+    /// assert_eq!(inner.grow(outer), size!(500, 900, 200));
+    /// ```
+    pub fn grow<S: ToSize3D<T>>(&self, size: S) -> Size3D<T> {
+        let size = size.to_size();
+        let bigger = |a: T, b: T| if a > b { a } else { b };
+
+        Size3D::new(
+            bigger(size.width, self.width),
+            bigger(size.height, self.height),
+            bigger(size.depth, self.depth),
+        )
+    }
+
+    /// Compares the components in `size` and `self`, creating a new size
+    /// where the components are the lesser values.
+    ///
+    /// See [`Size2D::shrink()`](crate::Size2D::shrink) for more information.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let outer = size!(500, 200, 100);
+    /// let inner = size!(100, 300, 50);
+    ///
+    /// assert_eq!(outer.shrink(inner), size!(100, 200, 50));
+    /// ```
+    pub fn shrink<S: ToSize3D<T>>(&self, size: S) -> Size3D<T> {
+        let size = size.to_size();
+        let smaller = |a: T, b: T| if a < b { a } else { b };
+
+        Size3D::new(
+            smaller(size.width, self.width),
+            smaller(size.height, self.height),
+            smaller(size.depth, self.depth),
+        )
+    }
+
+    /// Returns a new size constrained within the `min` and `max`.
+    /// This is a shorthand for `self.shrink(max).grow(min)`
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let min = size!(200, 300, 10);
+    /// let max = size!(500, 500, 20);
+    ///
+    /// assert_eq!(size!(100, 400, 30).constrain(min, max), size!(200, 400, 20));
+    /// ```
+    pub fn constrain<S: ToSize3D<T>>(&self, min: S, max: S) -> Size3D<T> {
+        let min = min.to_size();
+        let max = max.to_size();
+
+        self.shrink(max).grow(min)
+    }
+}
+
+/// Implements adding two sizes together.
+impl<T, R> Add<R> for Size3D<T>
+where
+    T: Num + Copy,
+    R: ToSize3D<T>,
+{
+    type Output = Size3D<T>;
+
+    fn add(self, rhs: R) -> Self::Output {
+        let rhs = rhs.to_size();
+        Size3D::new(
+            self.width + rhs.width,
+            self.height + rhs.height,
+            self.depth + rhs.depth,
+        )
+    }
+}
+
+impl<T, R> AddAssign<R> for Size3D<T>
+where
+    T: Num + NumAssign + Copy,
+    R: ToSize3D<T>,
+{
+    fn add_assign(&mut self, rhs: R) {
+        let rhs = rhs.to_size();
+
+        self.width += rhs.width;
+        self.height += rhs.height;
+        self.depth += rhs.depth;
+    }
+}
+
+/// Implements subtracting two sizes
+impl<T, R> Sub<R> for Size3D<T>
+where
+    T: Num + Copy,
+    R: ToSize3D<T>,
+{
+    type Output = Size3D<T>;
+
+    fn sub(self, rhs: R) -> Self::Output {
+        let rhs = rhs.to_size();
+        Size3D::new(
+            self.width - rhs.width,
+            self.height - rhs.height,
+            self.depth - rhs.depth,
+        )
+    }
+}
+
+impl<T, R> SubAssign<R> for Size3D<T>
+where
+    T: Num + NumAssign + Copy,
+    R: ToSize3D<T>,
+{
+    fn sub_assign(&mut self, rhs: R) {
+        let rhs = rhs.to_size();
+
+        self.width -= rhs.width;
+        self.height -= rhs.height;
+        self.depth -= rhs.depth;
+    }
+}
+
+/// Implements multiplying two sizes
+impl<T, R> Mul<R> for Size3D<T>
+where
+    T: Num + Copy,
+    R: ToSize3D<T>,
+{
+    type Output = Size3D<T>;
+
+    fn mul(self, rhs: R) -> Self::Output {
+        let rhs = rhs.to_size();
+        Size3D::new(
+            self.width * rhs.width,
+            self.height * rhs.height,
+            self.depth * rhs.depth,
+        )
+    }
+}
+
+impl<T, R> MulAssign<R> for Size3D<T>
+where
+    T: Num + NumAssign + Copy,
+    R: ToSize3D<T>,
+{
+    fn mul_assign(&mut self, rhs: R) {
+        let rhs = rhs.to_size();
+
+        self.width *= rhs.width;
+        self.height *= rhs.height;
+        self.depth *= rhs.depth;
+    }
+}
+
+/// Implements dividing two sizes
+impl<T, R> Div<R> for Size3D<T>
+where
+    T: Num + Copy,
+    R: ToSize3D<T>,
+{
+    type Output = Size3D<T>;
+
+    fn div(self, rhs: R) -> Self::Output {
+        let rhs = rhs.to_size();
+        Size3D::new(
+            self.width / rhs.width,
+            self.height / rhs.height,
+            self.depth / rhs.depth,
+        )
+    }
+}
+
+impl<T, R> DivAssign<R> for Size3D<T>
+where
+    T: Num + NumAssign + Copy,
+    R: ToSize3D<T>,
+{
+    fn div_assign(&mut self, rhs: R) {
+        let rhs = rhs.to_size();
+
+        self.width /= rhs.width;
+        self.height /= rhs.height;
+        self.depth /= rhs.depth;
+    }
+}
+
+impl<T> From<Size3D<T>> for (T, T, T) {
+    fn from(size: Size3D<T>) -> Self {
+        (size.width, size.height, size.depth)
+    }
+}
+
+impl<T> From<(T, T, T)> for Size3D<T>
+where
+    T: Num + Copy,
+{
+    fn from(tuple: (T, T, T)) -> Self {
+        Size3D::new(tuple.0, tuple.1, tuple.2)
+    }
+}
+
+/// A trait to aid in the ergonomics of creating a [Size3D]
+/// and usage of interfaces expecting [Size3D].
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let size = size!(200, 400, 100);
+///
+/// // We can pass a tuple instead of a Size3D
+/// assert_eq!(size.grow((400, 200, 100)), size!(400, 400, 100));
+/// ```
+pub trait ToSize3D<T> {
+    /// Creates a new [Size3D] from `self`
+    fn to_size(self) -> Size3D<T>;
+}
+
+impl<T> ToSize3D<T> for Size3D<T> {
+    fn to_size(self) -> Size3D<T> {
+        self
+    }
+}
+
+// Allows passing a tuple to functions that expect ToSize3D
+impl<T> ToSize3D<T> for (T, T, T)
+where
+    T: Num + Copy,
+{
+    fn to_size(self) -> Size3D<T> {
+        Size3D::new(self.0, self.1, self.2)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn equal_area_keeps_self() {
+        let tall = size!(1, 100);
+        let wide = size!(50, 2);
+
+        assert_eq!(tall.max_area(wide), tall);
+        assert_eq!(wide.max_area(tall), wide);
+        assert_eq!(tall.min_area(wide), tall);
+        assert_eq!(wide.min_area(tall), wide);
+    }
+
+    #[test]
+    fn area() {
+        let small = size!(1, 100);
+        let large = size!(20, 20);
+
+        assert_eq!(small.max_area(large), large);
+        assert_eq!(large.max_area(small), large);
+        assert_eq!(small.min_area(large), small);
+        assert_eq!(large.min_area(small), small);
+    }
+
+    #[test]
+    fn clamp_area() {
+        let min = size!(10, 10);
+        let max = size!(20, 20);
+
+        assert_eq!(size!(1, 50).clamp_area(min, max), min);
+        assert_eq!(size!(1, 500).clamp_area(min, max), max);
+        assert_eq!(size!(1, 200).clamp_area(min, max), size!(1, 200));
+
+        // Equal areas are kept as they are, even if the size differs
+        assert_eq!(size!(1, 100).clamp_area(min, max), size!(1, 100));
+        assert_eq!(size!(400, 1).clamp_area(min, max), size!(400, 1));
+    }
+}
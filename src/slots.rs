@@ -0,0 +1,130 @@
+use std::ops::{Index, IndexMut};
+
+/// Refers to a value in [Slots], and the generation of its slot at the time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct Key {
+    pub(crate) index: usize,
+    generation: u32,
+}
+
+#[derive(Debug, Clone)]
+struct Slot<V> {
+    value: Option<V>,
+    generation: u32,
+}
+
+/// Storage for values referred to by [Key], where the slots of removed values are reused.
+///
+/// Removing a value bumps the generation of its slot, so keys of removed values
+/// are never mistaken for values inserted into the same slot later on.
+#[derive(Debug, Clone)]
+pub(crate) struct Slots<V> {
+    slots: Vec<Slot<V>>,
+    vacant: Vec<usize>,
+    len: usize,
+}
+
+impl<V> Slots<V> {
+    pub(crate) fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            vacant: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn insert(&mut self, value: V) -> Key {
+        let index = match self.vacant.pop() {
+            Some(index) => {
+                self.slots[index].value = Some(value);
+                index
+            }
+            None => {
+                self.slots.push(Slot {
+                    value: Some(value),
+                    generation: 0,
+                });
+                self.slots.len() - 1
+            }
+        };
+
+        self.len += 1;
+        self.key(index)
+    }
+
+    pub(crate) fn remove(&mut self, key: Key) -> Option<V> {
+        let slot = self.slots.get_mut(key.index)?;
+
+        if slot.generation != key.generation {
+            return None;
+        }
+
+        let value = slot.value.take()?;
+
+        slot.generation = slot.generation.wrapping_add(1);
+        self.vacant.push(key.index);
+        self.len -= 1;
+
+        Some(value)
+    }
+
+    pub(crate) fn get(&self, key: Key) -> Option<&V> {
+        self.slots
+            .get(key.index)
+            .filter(|slot| slot.generation == key.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+
+    pub(crate) fn get_mut(&mut self, key: Key) -> Option<&mut V> {
+        self.slots
+            .get_mut(key.index)
+            .filter(|slot| slot.generation == key.generation)
+            .and_then(|slot| slot.value.as_mut())
+    }
+
+    /// Returns the key of the value in the slot at `index`.
+    pub(crate) fn key(&self, index: usize) -> Key {
+        Key {
+            index,
+            generation: self.slots[index].generation,
+        }
+    }
+
+    /// Returns the indices and values of every filled slot.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, &V)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| Some((index, slot.value.as_ref()?)))
+    }
+
+    /// Removes every value, without forgetting the generations.
+    pub(crate) fn clear(&mut self) {
+        for slot in &mut self.slots {
+            if slot.value.take().is_some() {
+                slot.generation = slot.generation.wrapping_add(1);
+            }
+        }
+
+        self.vacant = (0..self.slots.len()).rev().collect();
+        self.len = 0;
+    }
+}
+
+impl<V> Index<usize> for Slots<V> {
+    type Output = V;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.slots[index].value.as_ref().expect("Item must exist")
+    }
+}
+
+impl<V> IndexMut<usize> for Slots<V> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.slots[index].value.as_mut().expect("Item must exist")
+    }
+}
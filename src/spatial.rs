@@ -0,0 +1,542 @@
+use std::collections::HashMap;
+
+use num_traits::Float;
+
+use crate::{
+    slots::{Key, Slots},
+    Bounds2D, IntoBounds2D, Point2D, Size2D, ToPoint2D, ToSize2D,
+};
+
+/// The furthest a cell can be from the origin on either axis.
+/// Bounds further away share the cells at the edge, so ranges of cells never overflow.
+const MAX_CELL: i64 = 1 << 52;
+
+/// Items touching more cells than this are kept in a list of their own
+/// instead of in every cell, and paired with every item they share a cell with.
+const MAX_ITEM_CELLS: i64 = 1024;
+
+/// Refers to an item in a [SpatialHash2D].
+///
+/// Handles of removed items no longer refer to anything,
+/// even after another item is inserted in their place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SpatialHandle(Key);
+
+#[derive(Debug, Clone)]
+struct Entry<T, V> {
+    bounds: Bounds2D<T>,
+    cells: Bounds2D<i64>,
+    value: V,
+}
+
+/// A spatial index that divides space into cells of the same size,
+/// and keeps track of which items touch which cells.
+///
+/// This is meant as a broad phase for collision detection, where items move every frame
+/// and are roughly the same size as a cell. Only cells that have items take up memory,
+/// so the space is unbounded. Items much larger than a cell are kept aside,
+/// so they do not have to be added to every cell they touch.
+///
+/// A cell covers its top and left edges, so an item touching a cell on its right
+/// or bottom edge is not in that cell. Points are in exactly one cell.
+/// Positions and bounds must be finite.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut hash = SpatialHash2D::new(size!(10.0, 10.0));
+///
+/// let ball = hash.insert(bounds!(5.0, 5.0, 4.0, 4.0), "ball");
+/// let wall = hash.insert(bounds!(8.0, 0.0, 4.0, 40.0), "wall");
+/// hash.insert_point((95.0, 95.0), "spark");
+///
+/// assert_eq!(hash.cell_range(bounds!(5.0, 5.0, 10.0, 10.0)), bounds!(0, 0, 2, 2));
+/// assert_eq!(hash.query_cells(bounds!(1, 0, 1, 1)).count(), 1);
+///
+/// let pairs: Vec<_> = hash.pairs().collect();
+/// assert_eq!(pairs, vec![(ball, wall)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpatialHash2D<T, V> {
+    cell_size: Size2D<T>,
+    cells: HashMap<Point2D<i64>, Vec<usize>>,
+    large: Vec<usize>,
+    entries: Slots<Entry<T, V>>,
+}
+
+impl<T, V> SpatialHash2D<T, V>
+where
+    T: Float,
+{
+    /// Creates a new, empty [SpatialHash2D] with cells of `cell_size`.
+    ///
+    /// # Panics
+    /// If the width or height of `cell_size` is not greater than zero.
+    pub fn new<S: ToSize2D<T>>(cell_size: S) -> Self {
+        let cell_size = cell_size.to_size();
+
+        assert!(
+            cell_size.width > T::zero() && cell_size.height > T::zero(),
+            "SpatialHash2D cell size must be greater than zero"
+        );
+
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+            large: Vec::new(),
+            entries: Slots::new(),
+        }
+    }
+
+    /// Returns the size of a cell.
+    pub fn cell_size(&self) -> Size2D<T> {
+        self.cell_size
+    }
+
+    /// Returns the number of items in the hash.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the hash has no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of the item referred to by `handle`.
+    pub fn get(&self, handle: SpatialHandle) -> Option<&V> {
+        self.entries.get(handle.0).map(|entry| &entry.value)
+    }
+
+    /// Returns a mutable reference to the value of the item referred to by `handle`.
+    pub fn get_mut(&mut self, handle: SpatialHandle) -> Option<&mut V> {
+        self.entries.get_mut(handle.0).map(|entry| &mut entry.value)
+    }
+
+    /// Returns the bounds of the item referred to by `handle`.
+    pub fn bounds(&self, handle: SpatialHandle) -> Option<Bounds2D<T>> {
+        self.entries.get(handle.0).map(|entry| entry.bounds)
+    }
+
+    /// Returns the position of the cell containing `point`.
+    ///
+    /// # Panics
+    /// If `point` is not finite.
+    pub fn cell_at<P: ToPoint2D<T>>(&self, point: P) -> Point2D<i64> {
+        let point = point.to_vector();
+
+        assert!(
+            point.x.is_finite() && point.y.is_finite(),
+            "SpatialHash2D positions must be finite"
+        );
+
+        Point2D::new(
+            to_cell((point.x / self.cell_size.width).floor()),
+            to_cell((point.y / self.cell_size.height).floor()),
+        )
+    }
+
+    /// Returns the range of cells touched by `bounds`, where the position is the first cell
+    /// and the size is the number of cells on each axis. This is never empty.
+    ///
+    /// # Panics
+    /// If `bounds` is not finite.
+    pub fn cell_range<B: IntoBounds2D<T>>(&self, bounds: B) -> Bounds2D<i64> {
+        let bounds = bounds.to_bounds();
+        let start = self.cell_at(bounds.position());
+
+        assert!(
+            bounds.right().is_finite() && bounds.bottom().is_finite(),
+            "SpatialHash2D bounds must be finite"
+        );
+
+        let end = |edge: T, cell: T, start: i64| to_cell((edge / cell).ceil()).max(start + 1);
+
+        Bounds2D::from_corners(
+            start,
+            (
+                end(bounds.right(), self.cell_size.width, start.x),
+                end(bounds.bottom(), self.cell_size.height, start.y),
+            ),
+        )
+    }
+
+    /// Adds an item with `bounds` to the hash, returning a handle to it.
+    pub fn insert<B: IntoBounds2D<T>>(&mut self, bounds: B, value: V) -> SpatialHandle {
+        let bounds = bounds.to_bounds();
+        let cells = self.cell_range(bounds);
+
+        let entry = Entry {
+            bounds,
+            cells,
+            value,
+        };
+
+        let key = self.entries.insert(entry);
+        self.attach(key.index, cells);
+
+        SpatialHandle(key)
+    }
+
+    /// Adds an item at `point` to the hash, returning a handle to it.
+    pub fn insert_point<P: ToPoint2D<T>>(&mut self, point: P, value: V) -> SpatialHandle {
+        let point = point.to_vector();
+        self.insert(Bounds2D::new(point.x, point.y, T::zero(), T::zero()), value)
+    }
+
+    /// Removes the item referred to by `handle` from the hash, returning its value.
+    pub fn remove(&mut self, handle: SpatialHandle) -> Option<V> {
+        let entry = self.entries.remove(handle.0)?;
+        self.detach(handle.0.index, entry.cells);
+
+        Some(entry.value)
+    }
+
+    /// Moves the item referred to by `handle` to `bounds`, keeping the same handle.
+    ///
+    /// Returns `false` if there is no such item.
+    pub fn update<B: IntoBounds2D<T>>(&mut self, handle: SpatialHandle, bounds: B) -> bool {
+        let bounds = bounds.to_bounds();
+        let cells = self.cell_range(bounds);
+
+        let Some(entry) = self.entries.get_mut(handle.0) else {
+            return false;
+        };
+
+        let old = entry.cells;
+        entry.bounds = bounds;
+        entry.cells = cells;
+
+        if old != cells {
+            self.detach(handle.0.index, old);
+            self.attach(handle.0.index, cells);
+        }
+
+        true
+    }
+
+    /// Removes every item from the hash, keeping its cell size.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.large.clear();
+        self.entries.clear();
+    }
+
+    /// Returns every item touching any of the cells in `cells`, each of them once.
+    ///
+    /// Use [`cell_range()`](crate::SpatialHash2D::cell_range) to get the cells around some bounds.
+    pub fn query_cells<B: IntoBounds2D<i64>>(
+        &self,
+        cells: B,
+    ) -> impl Iterator<Item = (SpatialHandle, &V)> {
+        let range = cells.to_bounds();
+
+        // Looking through the cells with items is quicker than through a range with more cells
+        let occupied: Vec<(Point2D<i64>, &Vec<usize>)> =
+            if cell_count(range) <= self.cells.len() as i64 {
+                cells_in(range)
+                    .filter_map(|cell| self.cells.get(&cell).map(|items| (cell, items)))
+                    .collect()
+            } else {
+                self.cells
+                    .iter()
+                    .filter(|(cell, _)| range.contains_point(**cell))
+                    .map(|(&cell, items)| (cell, items))
+                    .collect()
+            };
+
+        let large = self
+            .large
+            .iter()
+            .copied()
+            .filter(move |&index| self.entries[index].cells.intersects(range));
+
+        occupied
+            .into_iter()
+            .flat_map(move |(cell, items)| {
+                // An item touching several cells is only yielded from the first one
+                items
+                    .iter()
+                    .copied()
+                    .filter(move |&index| first_shared(self.entries[index].cells, range) == cell)
+            })
+            .chain(large)
+            .map(|index| {
+                (
+                    SpatialHandle(self.entries.key(index)),
+                    &self.entries[index].value,
+                )
+            })
+    }
+
+    /// Returns every item that intersects `region`.
+    ///
+    /// See [`Bounds2D::intersects()`](crate::Bounds2D::intersects) for how edges are treated.
+    pub fn query_region<B: IntoBounds2D<T>>(
+        &self,
+        region: B,
+    ) -> impl Iterator<Item = (SpatialHandle, &V)> {
+        let region = region.to_bounds();
+
+        self.query_cells(self.cell_range(region))
+            .filter(move |(handle, _)| self.entries[handle.0.index].bounds.intersects(region))
+    }
+
+    /// Returns every pair of items that share a cell, each pair once.
+    ///
+    /// The items in a pair may not actually overlap, so this is meant to narrow down
+    /// which items to test against each other. The first handle of a pair is always the smaller one.
+    pub fn pairs(&self) -> impl Iterator<Item = (SpatialHandle, SpatialHandle)> + '_ {
+        let cells = self.cells.iter().flat_map(move |(&cell, items)| {
+            items.iter().enumerate().flat_map(move |(position, &a)| {
+                items[position + 1..].iter().filter_map(move |&b| {
+                    let (first, second) = (self.entries[a].cells, self.entries[b].cells);
+
+                    // Items sharing several cells are only paired in the first one
+                    (first_shared(first, second) == cell).then(|| self.pair(a, b))
+                })
+            })
+        });
+
+        let large = self.large.iter().flat_map(move |&a| {
+            let cells = self.entries[a].cells;
+
+            self.entries.iter().filter_map(move |(b, entry)| {
+                // Two large items are only paired from the first one
+                let is_pair =
+                    b != a && entry.cells.intersects(cells) && (b > a || !is_large(entry.cells));
+
+                is_pair.then(|| self.pair(a, b))
+            })
+        });
+
+        cells.chain(large)
+    }
+
+    fn pair(&self, a: usize, b: usize) -> (SpatialHandle, SpatialHandle) {
+        (
+            SpatialHandle(self.entries.key(a.min(b))),
+            SpatialHandle(self.entries.key(a.max(b))),
+        )
+    }
+
+    fn attach(&mut self, index: usize, cells: Bounds2D<i64>) {
+        if is_large(cells) {
+            self.large.push(index);
+            return;
+        }
+
+        for cell in cells_in(cells) {
+            self.cells.entry(cell).or_default().push(index);
+        }
+    }
+
+    fn detach(&mut self, index: usize, cells: Bounds2D<i64>) {
+        if is_large(cells) {
+            if let Some(position) = self.large.iter().position(|&item| item == index) {
+                self.large.swap_remove(position);
+            }
+
+            return;
+        }
+
+        for cell in cells_in(cells) {
+            let Some(items) = self.cells.get_mut(&cell) else {
+                continue;
+            };
+
+            if let Some(position) = items.iter().position(|&item| item == index) {
+                items.swap_remove(position);
+            }
+
+            if items.is_empty() {
+                self.cells.remove(&cell);
+            }
+        }
+    }
+}
+
+/// Converts a rounded value to a cell, clamping it to [MAX_CELL].
+fn to_cell<T: Float>(value: T) -> i64 {
+    let max = T::from(MAX_CELL).expect("Cell limit must fit in T");
+
+    value
+        .max(-max)
+        .min(max)
+        .to_i64()
+        .expect("Cell must fit in i64")
+}
+
+fn cell_count(range: Bounds2D<i64>) -> i64 {
+    range.width().saturating_mul(range.height())
+}
+
+fn is_large(range: Bounds2D<i64>) -> bool {
+    cell_count(range) > MAX_ITEM_CELLS
+}
+
+fn cells_in(range: Bounds2D<i64>) -> impl Iterator<Item = Point2D<i64>> {
+    (range.top()..range.bottom())
+        .flat_map(move |y| (range.left()..range.right()).map(move |x| Point2D::new(x, y)))
+}
+
+/// Returns the top left cell that two ranges have in common.
+fn first_shared(a: Bounds2D<i64>, b: Bounds2D<i64>) -> Point2D<i64> {
+    Point2D::new(a.left().max(b.left()), a.top().max(b.top()))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::random::Random;
+    use crate::*;
+
+    #[test]
+    fn pairs_match_brute_force() {
+        let mut random = Random::new(246813579);
+        let mut next = move |max: f64| random.float(max);
+
+        let mut hash = SpatialHash2D::new(size!(8.0, 8.0));
+        let mut items = Vec::new();
+
+        for index in 0..500 {
+            let bounds = Bounds2D::new(
+                next(200.0) - 100.0,
+                next(200.0) - 100.0,
+                next(12.0),
+                next(12.0),
+            );
+            items.push((hash.insert(bounds, index), bounds));
+        }
+
+        // Move some items around, and remove some others
+        for (handle, bounds) in items.iter_mut().step_by(5) {
+            *bounds = *bounds + offset!(-30.0, 45.0);
+            assert!(hash.update(*handle, *bounds));
+        }
+
+        for (handle, _) in items.iter().skip(2).step_by(9) {
+            hash.remove(*handle);
+        }
+
+        items.retain(|(handle, _)| hash.get(*handle).is_some());
+
+        let pairs: Vec<_> = hash.pairs().collect();
+        let unique: HashSet<_> = pairs.iter().copied().collect();
+
+        assert_eq!(pairs.len(), unique.len());
+
+        for (i, (a, a_bounds)) in items.iter().enumerate() {
+            for (b, b_bounds) in &items[i + 1..] {
+                let pair = (*a.min(b), *a.max(b));
+
+                // Every overlapping pair must be a candidate
+                if a_bounds.intersects(*b_bounds) {
+                    assert!(unique.contains(&pair));
+                }
+            }
+        }
+
+        let region = bounds!(-20.0, -20.0, 35.0, 50.0);
+        let mut found: Vec<_> = hash
+            .query_region(region)
+            .map(|(handle, _)| handle)
+            .collect();
+        let mut expected: Vec<_> = items
+            .iter()
+            .filter(|(_, bounds)| bounds.intersects(region))
+            .map(|(handle, _)| *handle)
+            .collect();
+
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "cell size must be greater than zero")]
+    fn zero_cell_size() {
+        SpatialHash2D::<f64, ()>::new(size!(0.0, 0.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "cell size must be greater than zero")]
+    fn negative_cell_size() {
+        SpatialHash2D::<f64, ()>::new(size!(8.0, -8.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "bounds must be finite")]
+    fn infinite_bounds() {
+        let mut hash = SpatialHash2D::new(size!(1.0, 1.0));
+        hash.insert(bounds!(0.0, 0.0, f64::INFINITY, 1.0), ());
+    }
+
+    #[test]
+    #[should_panic(expected = "positions must be finite")]
+    fn nan_point() {
+        let mut hash = SpatialHash2D::new(size!(1.0, 1.0));
+        hash.insert_point((f64::NAN, 0.0), ());
+    }
+
+    #[test]
+    fn huge_and_far_bounds() {
+        let mut hash = SpatialHash2D::new(size!(1.0f32, 1.0));
+
+        let huge = hash.insert(bounds!(-1e12, -1e12, 2e12, 2e12), "huge");
+        let small = hash.insert(bounds!(5.0, 5.0, 1.0, 1.0), "small");
+        let far = hash.insert(bounds!(1e30, 1e30, 1.0, 1.0), "far");
+        let wide = hash.insert(bounds!(-1e9, 5.5, 2e9, 1.0), "wide");
+
+        // Cells far from the origin are clamped instead of overflowing
+        let cells = hash.cell_range(bounds!(1e30, 1e30, 1.0, 1.0));
+        assert_eq!(cells.size(), size!(1, 1));
+
+        let mut pairs: Vec<_> = hash.pairs().collect();
+        pairs.sort();
+        assert_eq!(pairs, vec![(huge, small), (huge, wide), (small, wide)]);
+
+        let mut found: Vec<_> = hash
+            .query_region(bounds!(0.0, 0.0, 10.0, 10.0))
+            .map(|(_, value)| *value)
+            .collect();
+        found.sort();
+        assert_eq!(found, vec!["huge", "small", "wide"]);
+
+        // A region larger than the occupied cells finds the same items
+        let found = hash.query_region(bounds!(-1e20, -1e20, 2e20, 2e20));
+        assert_eq!(found.count(), 3);
+
+        assert_eq!(hash.remove(huge), Some("huge"));
+        assert_eq!(hash.pairs().collect::<Vec<_>>(), vec![(small, wide)]);
+        assert_eq!(hash.get(far), Some(&"far"));
+    }
+
+    #[test]
+    fn stale_handles_are_rejected() {
+        let mut hash = SpatialHash2D::new(size!(10.0, 10.0));
+
+        let a = hash.insert(bounds!(0.0, 0.0, 5.0, 5.0), "a");
+        let b = hash.insert(bounds!(2.0, 2.0, 5.0, 5.0), "b");
+
+        assert_eq!(hash.remove(a), Some("a"));
+        assert_eq!(hash.pairs().count(), 0);
+
+        // The next item takes the place of the removed one, but not its handle
+        let c = hash.insert(bounds!(4.0, 4.0, 5.0, 5.0), "c");
+
+        assert_ne!(c, a);
+        assert_eq!(hash.get(a), None);
+        assert_eq!(hash.get_mut(a), None);
+        assert_eq!(hash.bounds(a), None);
+        assert_eq!(hash.remove(a), None);
+        assert!(!hash.update(a, bounds!(50.0, 50.0, 1.0, 1.0)));
+
+        assert_eq!(hash.len(), 2);
+        assert_eq!(hash.get(c), Some(&"c"));
+        assert_eq!(hash.pairs().collect::<Vec<_>>(), vec![(c, b)]);
+        assert_eq!(hash.query_cells(bounds!(0, 0, 1, 1)).count(), 2);
+    }
+}
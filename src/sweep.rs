@@ -0,0 +1,429 @@
+use std::collections::BTreeSet;
+
+use num_traits::Num;
+
+use crate::{
+    slots::{Key, Slots},
+    Bounds2D, IntoBounds2D,
+};
+
+/// Refers to an item in a [SweepAndPrune].
+///
+/// Handles of removed items no longer refer to anything,
+/// even after another item is inserted in their place.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SweepHandle(Key);
+
+/// A change in whether two items in a [SweepAndPrune] overlap.
+///
+/// The first handle is always the smaller one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverlapEvent {
+    /// The items started overlapping.
+    Begin(SweepHandle, SweepHandle),
+    /// The items stopped overlapping, or one of them was removed.
+    End(SweepHandle, SweepHandle),
+}
+
+#[derive(Debug, Clone)]
+struct Entry<T, V> {
+    bounds: Bounds2D<T>,
+    value: V,
+    /// The items this one overlapped as of the last sweep.
+    partners: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Endpoint {
+    index: usize,
+    start: bool,
+}
+
+/// Keeps track of which items overlap, for items that move a little at a time.
+///
+/// The start and end of every item is kept sorted on both axes. Since items barely move
+/// between sweeps, the lists are almost sorted already, so sorting them again is close
+/// to linear, and only items whose endpoints pass each other can change their overlap.
+///
+/// Overlaps follow [`Bounds2D::intersects()`](crate::Bounds2D::intersects),
+/// so items without area never overlap anything.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut sap = SweepAndPrune::new();
+///
+/// let player = sap.insert(bounds!(0.0, 0.0, 10.0, 10.0), "player");
+/// let coin = sap.insert(bounds!(20.0, 0.0, 5.0, 5.0), "coin");
+///
+/// assert_eq!(sap.sweep().count(), 0);
+///
+/// sap.update(player, bounds!(12.0, 0.0, 10.0, 10.0));
+/// assert_eq!(sap.sweep().collect::<Vec<_>>(), vec![OverlapEvent::Begin(player, coin)]);
+/// assert_eq!(sap.pairs().collect::<Vec<_>>(), vec![(player, coin)]);
+///
+/// sap.remove(coin);
+/// assert_eq!(sap.sweep().collect::<Vec<_>>(), vec![OverlapEvent::End(player, coin)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SweepAndPrune<T, V> {
+    entries: Slots<Entry<T, V>>,
+    axes: [Vec<Endpoint>; 2],
+    pairs: BTreeSet<(usize, usize)>,
+    events: Vec<OverlapEvent>,
+    changed: Vec<usize>,
+}
+
+impl<T, V> SweepAndPrune<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    /// Creates a new, empty [SweepAndPrune].
+    pub fn new() -> Self {
+        Self {
+            entries: Slots::new(),
+            axes: [Vec::new(), Vec::new()],
+            pairs: BTreeSet::new(),
+            events: Vec::new(),
+            changed: Vec::new(),
+        }
+    }
+
+    /// Returns the number of items.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the value of the item referred to by `handle`.
+    pub fn get(&self, handle: SweepHandle) -> Option<&V> {
+        self.entries.get(handle.0).map(|entry| &entry.value)
+    }
+
+    /// Returns a mutable reference to the value of the item referred to by `handle`.
+    pub fn get_mut(&mut self, handle: SweepHandle) -> Option<&mut V> {
+        self.entries.get_mut(handle.0).map(|entry| &mut entry.value)
+    }
+
+    /// Returns the bounds of the item referred to by `handle`.
+    pub fn bounds(&self, handle: SweepHandle) -> Option<Bounds2D<T>> {
+        self.entries.get(handle.0).map(|entry| entry.bounds)
+    }
+
+    /// Adds an item with `bounds`, returning a handle to it.
+    ///
+    /// Its overlaps are found on the next [`sweep()`](crate::SweepAndPrune::sweep).
+    pub fn insert<B: IntoBounds2D<T>>(&mut self, bounds: B, value: V) -> SweepHandle {
+        let key = self.entries.insert(Entry {
+            bounds: bounds.to_bounds(),
+            value,
+            partners: Vec::new(),
+        });
+
+        let index = key.index;
+
+        // New endpoints start at the end, and are sorted into place by the next sweep
+        for axis in &mut self.axes {
+            axis.push(Endpoint { index, start: true });
+            axis.push(Endpoint {
+                index,
+                start: false,
+            });
+        }
+
+        SweepHandle(key)
+    }
+
+    /// Removes the item referred to by `handle`, returning its value.
+    ///
+    /// The end of its overlaps is reported by the next [`sweep()`](crate::SweepAndPrune::sweep).
+    pub fn remove(&mut self, handle: SweepHandle) -> Option<V> {
+        let entry = self.entries.remove(handle.0)?;
+        let index = handle.0.index;
+
+        for axis in &mut self.axes {
+            axis.retain(|endpoint| endpoint.index != index);
+        }
+
+        for other in entry.partners {
+            let pair = (index.min(other), index.max(other));
+            let other = SweepHandle(self.entries.key(other));
+
+            self.pairs.remove(&pair);
+            self.forget_partner(other.0.index, index);
+
+            self.events.push(if index < other.0.index {
+                OverlapEvent::End(handle, other)
+            } else {
+                OverlapEvent::End(other, handle)
+            });
+        }
+
+        self.changed.retain(|&changed| changed != index);
+
+        Some(entry.value)
+    }
+
+    /// Moves the item referred to by `handle` to `bounds`, keeping the same handle.
+    ///
+    /// Its overlaps are updated on the next [`sweep()`](crate::SweepAndPrune::sweep).
+    /// Returns `false` if there is no such item.
+    pub fn update<B: IntoBounds2D<T>>(&mut self, handle: SweepHandle, bounds: B) -> bool {
+        let bounds = bounds.to_bounds();
+
+        let Some(entry) = self.entries.get_mut(handle.0) else {
+            return false;
+        };
+
+        // An item gaining or losing its area can change its overlaps
+        // without any endpoints passing each other
+        if entry.bounds.is_empty() != bounds.is_empty() {
+            self.changed.push(handle.0.index);
+        }
+
+        entry.bounds = bounds;
+        true
+    }
+
+    /// Brings the overlaps up to date with the current bounds,
+    /// returning every change since the last sweep.
+    pub fn sweep(&mut self) -> impl Iterator<Item = OverlapEvent> + '_ {
+        let mut candidates = Vec::new();
+
+        for axis in 0..2 {
+            self.sort_axis(axis, &mut candidates);
+        }
+
+        for index in std::mem::take(&mut self.changed) {
+            let others = self
+                .entries
+                .iter()
+                .map(|(other, _)| other)
+                .filter(|&other| other != index);
+
+            candidates.extend(others.map(|other| (index, other)));
+        }
+
+        for (a, b) in candidates {
+            self.refresh(a, b);
+        }
+
+        self.events.drain(..)
+    }
+
+    /// Returns every pair of overlapping items as of the last sweep, ordered by their handles.
+    ///
+    /// The first handle of a pair is always the smaller one.
+    pub fn pairs(&self) -> impl Iterator<Item = (SweepHandle, SweepHandle)> + '_ {
+        self.pairs.iter().map(|&(a, b)| self.handles(a, b))
+    }
+
+    /// Returns `true` if the two items overlapped as of the last sweep.
+    pub fn overlaps(&self, a: SweepHandle, b: SweepHandle) -> bool {
+        let (a, b) = (a.0, b.0);
+        let pair = (a.index.min(b.index), a.index.max(b.index));
+
+        self.entries.get(a).is_some() && self.entries.get(b).is_some() && self.pairs.contains(&pair)
+    }
+
+    fn handles(&self, a: usize, b: usize) -> (SweepHandle, SweepHandle) {
+        (
+            SweepHandle(self.entries.key(a)),
+            SweepHandle(self.entries.key(b)),
+        )
+    }
+
+    fn forget_partner(&mut self, index: usize, partner: usize) {
+        let partners = &mut self.entries[index].partners;
+
+        if let Some(position) = partners.iter().position(|&other| other == partner) {
+            partners.swap_remove(position);
+        }
+    }
+
+    /// Insertion sorts the endpoints on `axis`, collecting every pair
+    /// where the start of one item passed the end of another.
+    fn sort_axis(&mut self, axis: usize, candidates: &mut Vec<(usize, usize)>) {
+        let entries = &self.entries;
+        let endpoints = &mut self.axes[axis];
+
+        let key = |endpoint: &Endpoint| {
+            let bounds = entries[endpoint.index].bounds;
+
+            let (start, end) = match axis {
+                0 => (bounds.left(), bounds.right()),
+                _ => (bounds.top(), bounds.bottom()),
+            };
+
+            if endpoint.start {
+                (start, true)
+            } else {
+                (end, false)
+            }
+        };
+
+        // Ends come before starts at the same position, since touching edges do not overlap
+        let less = |a: (T, bool), b: (T, bool)| a.0 < b.0 || (a.0 == b.0 && !a.1 && b.1);
+
+        for i in 1..endpoints.len() {
+            let mut j = i;
+
+            while j > 0 && less(key(&endpoints[j]), key(&endpoints[j - 1])) {
+                let (a, b) = (endpoints[j], endpoints[j - 1]);
+
+                if a.start != b.start && a.index != b.index {
+                    candidates.push((a.index, b.index));
+                }
+
+                endpoints.swap(j, j - 1);
+                j -= 1;
+            }
+        }
+    }
+
+    fn refresh(&mut self, a: usize, b: usize) {
+        let pair = (a.min(b), a.max(b));
+        let overlaps = self.entries[a].bounds.intersects(self.entries[b].bounds);
+
+        if overlaps && self.pairs.insert(pair) {
+            self.entries[a].partners.push(b);
+            self.entries[b].partners.push(a);
+
+            let (a, b) = self.handles(pair.0, pair.1);
+            self.events.push(OverlapEvent::Begin(a, b));
+        } else if !overlaps && self.pairs.remove(&pair) {
+            self.forget_partner(a, b);
+            self.forget_partner(b, a);
+
+            let (a, b) = self.handles(pair.0, pair.1);
+            self.events.push(OverlapEvent::End(a, b));
+        }
+    }
+}
+
+impl<T, V> Default for SweepAndPrune<T, V>
+where
+    T: Num + Copy + PartialOrd,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use crate::random::Random;
+    use crate::*;
+
+    #[test]
+    fn events_match_brute_force() {
+        let mut random = Random::new(135792468);
+        let mut next = move |max: i32| random.below(max as u32) as i32;
+
+        let mut sap = SweepAndPrune::new();
+        let mut items: Vec<_> = (0..200)
+            .map(|index| {
+                let bounds = Bounds2D::new(next(500), next(500), next(30), next(30));
+                (sap.insert(bounds, index), bounds)
+            })
+            .collect();
+
+        let mut pairs = BTreeSet::new();
+
+        for frame in 0..30 {
+            for (handle, bounds) in items.iter_mut() {
+                *bounds = Bounds2D::new(
+                    bounds.left() + next(11) - 5,
+                    bounds.top() + next(11) - 5,
+                    (bounds.width() + next(5) - 2).max(0),
+                    (bounds.height() + next(5) - 2).max(0),
+                );
+                sap.update(*handle, *bounds);
+            }
+
+            if frame % 10 == 5 {
+                let (handle, _) = items.swap_remove(frame);
+                sap.remove(handle);
+            }
+
+            for event in sap.sweep() {
+                match event {
+                    OverlapEvent::Begin(a, b) => assert!(pairs.insert((a, b))),
+                    OverlapEvent::End(a, b) => assert!(pairs.remove(&(a, b))),
+                }
+            }
+
+            let mut expected = BTreeSet::new();
+
+            for (i, (a, a_bounds)) in items.iter().enumerate() {
+                for (b, b_bounds) in &items[i + 1..] {
+                    if a_bounds.intersects(*b_bounds) {
+                        expected.insert((*a.min(b), *a.max(b)));
+                    }
+                }
+            }
+
+            assert_eq!(pairs, expected);
+            assert_eq!(sap.pairs().collect::<BTreeSet<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn removing_ends_only_its_own_overlaps() {
+        let mut sap = SweepAndPrune::new();
+
+        let a = sap.insert(bounds!(0, 0, 10, 10), "a");
+        let b = sap.insert(bounds!(5, 5, 10, 10), "b");
+        let c = sap.insert(bounds!(8, 8, 10, 10), "c");
+        let far = sap.insert(bounds!(50, 50, 10, 10), "far");
+
+        assert_eq!(sap.sweep().count(), 3);
+
+        assert_eq!(sap.remove(b), Some("b"));
+        let ended: Vec<_> = sap.sweep().collect();
+
+        assert_eq!(ended.len(), 2);
+        assert!(ended.contains(&OverlapEvent::End(a, b)));
+        assert!(ended.contains(&OverlapEvent::End(b, c)));
+        assert_eq!(sap.pairs().collect::<Vec<_>>(), vec![(a, c)]);
+
+        // The next item takes the place of the removed one, but not its handle
+        let d = sap.insert(bounds!(55, 55, 10, 10), "d");
+
+        assert_ne!(d, b);
+        assert_eq!(
+            sap.sweep().collect::<Vec<_>>(),
+            vec![OverlapEvent::Begin(d, far)]
+        );
+        assert!(sap.overlaps(d, far));
+        assert!(!sap.overlaps(b, far));
+        assert_eq!(sap.get(b), None);
+        assert_eq!(sap.remove(b), None);
+        assert!(!sap.update(b, bounds!(0, 0, 10, 10)));
+        assert_eq!(sap.sweep().count(), 0);
+    }
+
+    #[test]
+    fn touching_and_empty() {
+        let mut sap = SweepAndPrune::new();
+
+        let a = sap.insert(bounds!(0, 0, 10, 10), ());
+        let b = sap.insert(bounds!(10, 0, 10, 10), ());
+        let c = sap.insert(bounds!(5, 5, 0, 0), ());
+
+        // Touching edges and items without area do not overlap
+        assert_eq!(sap.sweep().count(), 0);
+
+        sap.update(c, bounds!(5, 5, 10, 1));
+
+        assert_eq!(sap.sweep().count(), 2);
+        assert_eq!(sap.pairs().collect::<Vec<_>>(), vec![(a, c), (b, c)]);
+    }
+}
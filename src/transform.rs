@@ -0,0 +1,305 @@
+use std::ops::Mul;
+
+use num_traits::{Float, FloatConst};
+
+use crate::{
+    Angle, Bounds2D, IntoBounds2D, Offset2D, Point2D, Size2D, ToOffset2D, ToPoint2D, ToSize2D,
+};
+
+/// A two-dimensional affine transformation, represented as a 2x3 matrix.
+///
+/// Vectors are treated as rows, so a point is transformed like this:
+/// ```text
+/// x' = x * m11 + y * m21 + m31
+/// y' = x * m12 + y * m22 + m32
+/// ```
+///
+/// The transformation understands what it is applied to,
+/// so points are translated, while offsets and sizes are not.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let transform = Transform2D::scale(2.0, 2.0).then_translate((10.0, 0.0));
+///
+/// assert_eq!(transform.transform_point((1.0, 1.0)), point!(12.0, 2.0));
+/// assert_eq!(transform.transform_offset((1.0, 1.0)), offset!(2.0, 2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2D<T> {
+    pub m11: T,
+    pub m12: T,
+    pub m21: T,
+    pub m22: T,
+    pub m31: T,
+    pub m32: T,
+}
+
+impl<T> Transform2D<T>
+where
+    T: Float + FloatConst,
+{
+    /// Creates a new [Transform2D] from its matrix components.
+    pub fn new(m11: T, m12: T, m21: T, m22: T, m31: T, m32: T) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+        }
+    }
+
+    /// Returns a transformation that does nothing.
+    pub fn identity() -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, zero, zero, one, zero, zero)
+    }
+
+    /// Returns a transformation that moves by `offset`.
+    pub fn translation<O: ToOffset2D<T>>(offset: O) -> Self {
+        let offset = offset.to_vector();
+        let (zero, one) = (T::zero(), T::one());
+
+        Self::new(one, zero, zero, one, offset.x, offset.y)
+    }
+
+    /// Returns a transformation that rotates counter-clockwise by `angle` around the origin.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let transform = Transform2D::rotation(Angle::degrees(90.0));
+    /// let point = transform.transform_point((1.0, 0.0));
+    ///
+    /// assert!(point.euclidean_distance((0.0, 1.0)) < 1e-10);
+    /// ```
+    pub fn rotation(angle: Angle<T>) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(cos, sin, -sin, cos, T::zero(), T::zero())
+    }
+
+    /// Returns a transformation that scales by `x` and `y` from the origin.
+    pub fn scale(x: T, y: T) -> Self {
+        let zero = T::zero();
+        Self::new(x, zero, zero, y, zero, zero)
+    }
+
+    /// Returns a transformation that skews by `x` along the X axis,
+    /// and by `y` along the Y axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let transform = Transform2D::skew(Angle::degrees(45.0), Angle::zero());
+    /// let point = transform.transform_point((0.0, 2.0));
+    ///
+    /// assert!(point.euclidean_distance((2.0, 2.0)) < 1e-10);
+    /// ```
+    pub fn skew(x: Angle<T>, y: Angle<T>) -> Self {
+        let (zero, one) = (T::zero(), T::one());
+        Self::new(one, y.radians.tan(), x.radians.tan(), one, zero, zero)
+    }
+
+    /// Returns a transformation that applies `self`, and then `other`.
+    ///
+    /// This is the same as `self * other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let move_then_scale = Transform2D::translation((1.0, 0.0)).then(&Transform2D::scale(3.0, 3.0));
+    /// let scale_then_move = Transform2D::scale(3.0, 3.0).then(&Transform2D::translation((1.0, 0.0)));
+    ///
+    /// assert_eq!(move_then_scale.transform_point((0.0, 0.0)), point!(3.0, 0.0));
+    /// assert_eq!(scale_then_move.transform_point((0.0, 0.0)), point!(1.0, 0.0));
+    /// ```
+    pub fn then(&self, other: &Self) -> Self {
+        Self::new(
+            self.m11 * other.m11 + self.m12 * other.m21,
+            self.m11 * other.m12 + self.m12 * other.m22,
+            self.m21 * other.m11 + self.m22 * other.m21,
+            self.m21 * other.m12 + self.m22 * other.m22,
+            self.m31 * other.m11 + self.m32 * other.m21 + other.m31,
+            self.m31 * other.m12 + self.m32 * other.m22 + other.m32,
+        )
+    }
+
+    /// Returns a transformation that applies `other`, and then `self`.
+    ///
+    /// This is the same as `other * self`.
+    pub fn pre_then(&self, other: &Self) -> Self {
+        other.then(self)
+    }
+
+    /// Returns a transformation that moves by `offset` before applying `self`.
+    pub fn pre_translate<O: ToOffset2D<T>>(&self, offset: O) -> Self {
+        self.pre_then(&Self::translation(offset))
+    }
+
+    /// Returns a transformation that moves by `offset` after applying `self`.
+    pub fn then_translate<O: ToOffset2D<T>>(&self, offset: O) -> Self {
+        self.then(&Self::translation(offset))
+    }
+
+    /// Returns a transformation that rotates by `angle` before applying `self`.
+    pub fn pre_rotate(&self, angle: Angle<T>) -> Self {
+        self.pre_then(&Self::rotation(angle))
+    }
+
+    /// Returns a transformation that rotates by `angle` after applying `self`.
+    pub fn then_rotate(&self, angle: Angle<T>) -> Self {
+        self.then(&Self::rotation(angle))
+    }
+
+    /// Returns a transformation that scales by `x` and `y` before applying `self`.
+    pub fn pre_scale(&self, x: T, y: T) -> Self {
+        self.pre_then(&Self::scale(x, y))
+    }
+
+    /// Returns a transformation that scales by `x` and `y` after applying `self`.
+    pub fn then_scale(&self, x: T, y: T) -> Self {
+        self.then(&Self::scale(x, y))
+    }
+
+    /// Returns the determinant of the linear part of the matrix.
+    pub fn determinant(&self) -> T {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// Returns the transformation that undoes `self`,
+    /// or [`None`] if `self` collapses space and cannot be undone.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let transform = Transform2D::scale(2.0, 4.0).then_translate((5.0, 5.0));
+    /// let inverse = transform.inverse().unwrap();
+    ///
+    /// let point = transform.transform_point((1.0, 1.0));
+    /// assert_eq!(inverse.transform_point(point), point!(1.0, 1.0));
+    ///
+    /// assert_eq!(Transform2D::scale(0.0, 1.0).inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Self> {
+        let determinant = self.determinant();
+
+        if determinant.is_zero() || !determinant.is_finite() {
+            return None;
+        }
+
+        let inverse = determinant.recip();
+
+        Some(Self::new(
+            self.m22 * inverse,
+            -self.m12 * inverse,
+            -self.m21 * inverse,
+            self.m11 * inverse,
+            (self.m21 * self.m32 - self.m22 * self.m31) * inverse,
+            (self.m31 * self.m12 - self.m11 * self.m32) * inverse,
+        ))
+    }
+
+    /// Applies the transformation to a point, including translation.
+    pub fn transform_point<P: ToPoint2D<T>>(&self, point: P) -> Point2D<T> {
+        let point = point.to_vector();
+
+        Point2D::new(
+            point.x * self.m11 + point.y * self.m21 + self.m31,
+            point.x * self.m12 + point.y * self.m22 + self.m32,
+        )
+    }
+
+    /// Applies the transformation to an offset, ignoring translation.
+    pub fn transform_offset<O: ToOffset2D<T>>(&self, offset: O) -> Offset2D<T> {
+        let offset = offset.to_vector();
+
+        Offset2D::new(
+            offset.x * self.m11 + offset.y * self.m21,
+            offset.x * self.m12 + offset.y * self.m22,
+        )
+    }
+
+    /// Applies the transformation to a size, ignoring translation.
+    ///
+    /// Since a size has no orientation, the result is the size of
+    /// the axis-aligned bounds around the transformed rectangle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let transform = Transform2D::scale(-2.0, 1.0);
+    ///
+    /// assert_eq!(transform.transform_size((3.0, 3.0)), size!(6.0, 3.0));
+    /// ```
+    pub fn transform_size<S: ToSize2D<T>>(&self, size: S) -> Size2D<T> {
+        let size = size.to_size();
+        let zero = T::zero();
+
+        self.transform_bounds(Bounds2D::new(zero, zero, size.width, size.height))
+            .size()
+    }
+
+    /// Applies the transformation to the corners of a bounding box,
+    /// returning the axis-aligned bounds around them.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let transform = Transform2D::rotation(Angle::degrees(90.0));
+    /// let bounds = transform.transform_bounds((0.0f64, 0.0, 4.0, 2.0));
+    ///
+    /// assert!((bounds.left() + 2.0).abs() < 1e-10);
+    /// assert!((bounds.width() - 2.0).abs() < 1e-10);
+    /// assert!((bounds.height() - 4.0).abs() < 1e-10);
+    /// ```
+    pub fn transform_bounds<B: IntoBounds2D<T>>(&self, bounds: B) -> Bounds2D<T> {
+        let bounds = bounds.to_bounds();
+
+        let corners = [
+            Point2D::new(bounds.left(), bounds.top()),
+            Point2D::new(bounds.right(), bounds.top()),
+            Point2D::new(bounds.left(), bounds.bottom()),
+            Point2D::new(bounds.right(), bounds.bottom()),
+        ]
+        .map(|corner| self.transform_point(corner));
+
+        let (mut min, mut max) = (corners[0], corners[0]);
+
+        for corner in &corners[1..] {
+            min = Point2D::new(min.x.min(corner.x), min.y.min(corner.y));
+            max = Point2D::new(max.x.max(corner.x), max.y.max(corner.y));
+        }
+
+        Bounds2D::new(min.x, min.y, max.x - min.x, max.y - min.y)
+    }
+}
+
+impl<T> Default for Transform2D<T>
+where
+    T: Float + FloatConst,
+{
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Composes two transformations, where the left-hand side is applied first.
+impl<T> Mul for Transform2D<T>
+where
+    T: Float + FloatConst,
+{
+    type Output = Transform2D<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.then(&rhs)
+    }
+}
@@ -0,0 +1,431 @@
+use std::cmp::Ordering;
+
+use num_traits::Num;
+
+use crate::{
+    hull::{difference, orientation},
+    widen::wide_mul,
+    Point2D, Polygon2D, Widen, Winding,
+};
+
+/// Splits a simple polygon with `holes` into triangles using ear clipping.
+///
+/// The triangles are indices into the vertices of `outline` followed by the vertices of
+/// each hole in order, and go around [`Winding::CounterClockwise`]. The outline and holes
+/// may go around in either direction, but they must not cross themselves or each other,
+/// and holes must be inside the outline. Otherwise some of the area may be left out.
+///
+/// Cross products are found in the [wider type](Widen) of `T`, so this is exact
+/// for integers as long as the difference between any two coordinates fits in `T`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let outline = polygon![(0, 0), (10, 0), (10, 10), (0, 10)];
+/// let hole = polygon![(4, 4), (6, 4), (6, 6), (4, 6)];
+///
+/// let triangles = triangulate(&outline, &[hole]);
+///
+/// assert_eq!(triangles.len(), 8);
+/// assert_eq!(triangulate(&outline, &[]), vec![[3, 0, 1], [1, 2, 3]]);
+/// ```
+pub fn triangulate<T>(outline: &Polygon2D<T>, holes: &[Polygon2D<T>]) -> Vec<[usize; 3]>
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let points: Vec<Point2D<T>> = std::iter::once(outline)
+        .chain(holes)
+        .flat_map(|polygon| polygon.vertices().iter().copied())
+        .collect();
+
+    // The outline goes counter-clockwise, and the holes clockwise
+    let mut ring = indices(outline, 0, Winding::CounterClockwise);
+    let mut offset = outline.len();
+
+    let mut holes: Vec<Vec<usize>> = holes
+        .iter()
+        .map(|hole| {
+            let ring = indices(hole, offset, Winding::Clockwise);
+            offset += hole.len();
+            ring
+        })
+        .filter(|hole| hole.len() >= 3)
+        .collect();
+
+    // Holes are joined to the outline from right to left, so that bridges do not cross
+    holes.sort_by(|a, b| {
+        let x = |hole: &Vec<usize>| points[rightmost(&points, hole)].x;
+        x(b).partial_cmp(&x(a)).unwrap_or(Ordering::Equal)
+    });
+
+    for index in 0..holes.len() {
+        let (hole, rest) = holes[index..].split_first().expect("Hole must exist");
+        bridge(&points, &mut ring, hole, rest);
+    }
+
+    clip_ears(&points, ring)
+}
+
+/// Returns the indices of the vertices of `polygon`, ordered to go around in `winding`.
+fn indices<T>(polygon: &Polygon2D<T>, offset: usize, winding: Winding) -> Vec<usize>
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let mut indices: Vec<usize> = (offset..offset + polygon.len()).collect();
+
+    if winding_of(polygon.vertices()).is_some_and(|current| current != winding) {
+        indices.reverse();
+    }
+
+    indices
+}
+
+/// Returns the direction `vertices` go around in, from the turn at the lowest vertex,
+/// which is always convex. Unlike the signed area, this cannot overflow.
+fn winding_of<T>(vertices: &[Point2D<T>]) -> Option<Winding>
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let lowest = (0..vertices.len()).reduce(|best, index| {
+        let (a, b) = (vertices[index], vertices[best]);
+
+        if a.y < b.y || (a.y == b.y && a.x < b.x) {
+            index
+        } else {
+            best
+        }
+    })?;
+
+    let vertex = vertices[lowest];
+    let len = vertices.len();
+
+    // Duplicates of the lowest vertex make no turn, so they are skipped
+    let previous = (1..len)
+        .map(|step| vertices[(lowest + len - step) % len])
+        .find(|&point| point != vertex)?;
+    let next = (1..len)
+        .map(|step| vertices[(lowest + step) % len])
+        .find(|&point| point != vertex)?;
+
+    match orientation(previous, vertex, next) {
+        Ordering::Greater => Some(Winding::CounterClockwise),
+        Ordering::Less => Some(Winding::Clockwise),
+        Ordering::Equal => None,
+    }
+}
+
+fn rightmost<T>(points: &[Point2D<T>], ring: &[usize]) -> usize
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    ring.iter()
+        .copied()
+        .reduce(|best, index| {
+            let (a, b) = (points[index], points[best]);
+
+            if a.x > b.x || (a.x == b.x && a.y < b.y) {
+                index
+            } else {
+                best
+            }
+        })
+        .expect("Ring must have vertices")
+}
+
+/// Joins `hole` to `ring` with a pair of edges between the rightmost vertex of the hole
+/// and the closest vertex of the ring it can see, turning them into a single ring.
+fn bridge<T>(points: &[Point2D<T>], ring: &mut Vec<usize>, hole: &[usize], others: &[Vec<usize>])
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let from = rightmost(points, hole);
+    let start = points[from];
+
+    let edges = || {
+        std::iter::once(&ring[..])
+            .chain(std::iter::once(hole))
+            .chain(others.iter().map(Vec::as_slice))
+            .flat_map(|ring| (0..ring.len()).map(move |i| (ring[i], ring[(i + 1) % ring.len()])))
+    };
+
+    // Squared distances are kept apart as the squares of each axis, so they cannot overflow
+    let distance = |index: usize| {
+        let (x, _) = difference(start.x, points[index].x);
+        let (y, _) = difference(start.y, points[index].y);
+        (wide_mul(x, x), wide_mul(y, y))
+    };
+
+    let visible = |to: usize| {
+        let end = points[to];
+
+        end.x >= start.x
+            && edges().all(|(a, b)| {
+                let (a, b) = (points[a], points[b]);
+                let touches = |point: Point2D<T>| point == start || point == end;
+
+                !crosses(start, end, a, b)
+                    && (touches(a) || !on_segment(start, end, a))
+                    && (touches(b) || !on_segment(start, end, b))
+            })
+    };
+
+    let mut candidates: Vec<usize> = (0..ring.len()).collect();
+    candidates.sort_by(|&a, &b| compare_sums(distance(ring[a]), distance(ring[b])));
+
+    // A vertex may be in the ring twice because of an earlier bridge,
+    // so the bridge has to leave from the copy facing the hole
+    let position = candidates
+        .iter()
+        .copied()
+        .filter(|&position| visible(ring[position]))
+        .find(|&position| {
+            let previous = points[ring[(position + ring.len() - 1) % ring.len()]];
+            let next = points[ring[(position + 1) % ring.len()]];
+
+            faces(previous, points[ring[position]], next, start)
+        })
+        .or_else(|| {
+            candidates
+                .iter()
+                .copied()
+                .find(|&position| visible(ring[position]))
+        });
+
+    let Some(position) = position else {
+        return;
+    };
+
+    let turn = hole
+        .iter()
+        .position(|&index| index == from)
+        .expect("Vertex must be in the hole");
+    let joined = hole[turn..]
+        .iter()
+        .chain(&hole[..=turn])
+        .copied()
+        .chain(std::iter::once(ring[position]));
+
+    ring.splice(position + 1..position + 1, joined.collect::<Vec<_>>());
+}
+
+fn clip_ears<T>(points: &[Point2D<T>], mut ring: Vec<usize>) -> Vec<[usize; 3]>
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let mut triangles = Vec::with_capacity(ring.len().saturating_sub(2));
+    let mut current = 0;
+    let mut stalled = 0;
+
+    while ring.len() > 3 {
+        let len = ring.len();
+        let (previous, next) = ((current + len - 1) % len, (current + 1) % len);
+        let [a, b, c] = [ring[previous], ring[current], ring[next]].map(|index| points[index]);
+
+        let turn = orientation(a, b, c);
+
+        if turn == Ordering::Equal {
+            // Vertices in a line add no area, so they are dropped
+            ring.remove(current);
+            stalled = 0;
+        } else if turn == Ordering::Greater && is_ear(points, &ring, [a, b, c]) {
+            triangles.push([ring[previous], ring[current], ring[next]]);
+            ring.remove(current);
+            stalled = 0;
+        } else {
+            stalled += 1;
+
+            // Every vertex was tried without finding an ear, so the polygon is not simple
+            if stalled > len {
+                return triangles;
+            }
+
+            current += 1;
+        }
+
+        current %= ring.len();
+    }
+
+    if ring.len() == 3 {
+        let [a, b, c] = [ring[0], ring[1], ring[2]];
+
+        if orientation(points[a], points[b], points[c]) == Ordering::Greater {
+            triangles.push([a, b, c]);
+        }
+    }
+
+    triangles
+}
+
+/// Compares `a.0 + a.1` with `b.0 + b.1` without adding, where every value is positive or zero.
+fn compare_sums<T>(a: (T, T), b: (T, T)) -> Ordering
+where
+    T: Num + Copy + PartialOrd,
+{
+    if a.0 < b.0 {
+        return compare_sums(b, a).reverse();
+    }
+
+    // `a.0 - b.0 + a.1` is compared with `b.1`
+    if a.1 >= b.1 {
+        if a.0 == b.0 && a.1 == b.1 {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    } else {
+        (a.0 - b.0)
+            .partial_cmp(&(b.1 - a.1))
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Returns `true` if no other vertex of `ring` is inside the triangle.
+fn is_ear<T>(points: &[Point2D<T>], ring: &[usize], [a, b, c]: [Point2D<T>; 3]) -> bool
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    ring.iter().map(|&index| points[index]).all(|point| {
+        point == a
+            || point == b
+            || point == c
+            || orientation(a, b, point) == Ordering::Less
+            || orientation(b, c, point) == Ordering::Less
+            || orientation(c, a, point) == Ordering::Less
+    })
+}
+
+/// Returns `true` if `point` is in the corner at `vertex` on the inside of a counter-clockwise ring.
+fn faces<T>(previous: Point2D<T>, vertex: Point2D<T>, next: Point2D<T>, point: Point2D<T>) -> bool
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let left_of_next = orientation(vertex, next, point) == Ordering::Greater;
+    let left_of_previous = orientation(previous, vertex, point) == Ordering::Greater;
+
+    if orientation(previous, vertex, next) != Ordering::Less {
+        left_of_next && left_of_previous
+    } else {
+        left_of_next || left_of_previous
+    }
+}
+
+/// Returns `true` if the segments cross at a single point inside both of them.
+fn crosses<T>(a: Point2D<T>, b: Point2D<T>, c: Point2D<T>, d: Point2D<T>) -> bool
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let opposite =
+        |x: Ordering, y: Ordering| x != Ordering::Equal && y != Ordering::Equal && x != y;
+
+    opposite(orientation(a, b, c), orientation(a, b, d))
+        && opposite(orientation(c, d, a), orientation(c, d, b))
+}
+
+/// Returns `true` if `point` is on the segment from `a` to `b`, excluding its ends.
+fn on_segment<T>(a: Point2D<T>, b: Point2D<T>, point: Point2D<T>) -> bool
+where
+    T: Num + Copy + PartialOrd + Widen,
+{
+    let between = |value: T, a: T, b: T| (a < value && value < b) || (b < value && value < a);
+
+    orientation(a, b, point) == Ordering::Equal
+        && point != a
+        && point != b
+        && (between(point.x, a.x, b.x) || between(point.y, a.y, b.y))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::*;
+
+    fn area(points: &[Point2D<i64>], triangles: &[[usize; 3]]) -> i64 {
+        triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                Polygon2D::new([points[a], points[b], points[c]]).doubled_signed_area()
+            })
+            .inspect(|&area| assert!(area > 0))
+            .sum()
+    }
+
+    #[test]
+    fn comb_with_holes() {
+        // A comb with five teeth pointing up, with a hole in the spine below each tooth
+        let mut outline = vec![(0, 0), (100, 0), (100, 40)];
+
+        for tooth in (0..5).rev() {
+            let x = tooth * 20;
+            outline.extend([(x + 15, 40), (x + 15, 100), (x + 5, 100), (x + 5, 40)]);
+        }
+
+        outline.push((0, 40));
+
+        let outline = Polygon2D::new(outline);
+        let holes: Vec<_> = (0..5)
+            .map(|tooth| {
+                let x = tooth * 20;
+                polygon![(x + 5, 10), (x + 15, 10), (x + 15, 30), (x + 5, 30)]
+            })
+            .collect();
+
+        let triangles = triangulate(&outline, &holes);
+        let points: Vec<_> = std::iter::once(&outline)
+            .chain(&holes)
+            .flat_map(|polygon| polygon.vertices().iter().copied())
+            .collect();
+
+        let expected = outline.doubled_signed_area()
+            - holes
+                .iter()
+                .map(|hole| hole.doubled_signed_area())
+                .sum::<i64>();
+
+        assert_eq!(area(&points, &triangles), expected);
+    }
+
+    #[test]
+    fn clockwise_outline() {
+        let outline = polygon![(0, 0), (0, 10), (5, 5), (10, 10), (10, 0)];
+        let triangles = triangulate(&outline, &[]);
+
+        // The notch puts the middle vertex in line with two others once an ear is clipped
+        assert_eq!(triangles.len(), 2);
+        assert_eq!(
+            area(outline.vertices(), &triangles),
+            -outline.doubled_signed_area()
+        );
+    }
+
+    #[test]
+    fn unsigned_outline() {
+        let outline = polygon![(0u32, 0), (0, 10), (10, 10), (10, 0)];
+        let hole = polygon![(4u32, 4), (6, 4), (6, 6), (4, 6)];
+
+        assert_eq!(triangulate(&outline, &[]).len(), 2);
+        assert_eq!(triangulate(&outline, &[hole]).len(), 8);
+    }
+
+    #[test]
+    fn large_integers() {
+        // The signed areas and cross products of these are far beyond `i32`
+        let far = 1_000_000_000;
+        let outline = polygon![(-far, -far), (-far, far), (far, far), (far, -far)];
+        let holes = [polygon![
+            (-far / 2, -far / 2),
+            (far / 2, -far / 2),
+            (0, far / 2)
+        ]];
+
+        let triangles = triangulate(&outline, &holes);
+        let points: Vec<_> = std::iter::once(&outline)
+            .chain(&holes)
+            .flat_map(|polygon| polygon.vertices())
+            .map(|point| point!(point.x as i64, point.y as i64))
+            .collect();
+
+        // Twice the area of the square, less twice the area of the triangle
+        let far = far as i64;
+        assert_eq!(area(&points, &triangles), 8 * far * far - far * far);
+    }
+}
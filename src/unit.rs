@@ -0,0 +1,221 @@
+use std::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+use num_traits::One;
+
+use crate::{Bounds2D, Size2D, VectorN};
+
+/// The unit of values that have not been given one.
+///
+/// Every type with a unit parameter defaults to this, so units
+/// are entirely opt-in. Values in different units cannot be mixed,
+/// so to prevent mixing up different coordinate spaces,
+/// declare a marker type for each space and use it as the unit.
+///
+/// The macros always create values in this unit, so use
+/// [`cast_unit()`](crate::VectorN::cast_unit) or the constructors
+/// to create values in another unit.
+///
+/// # Examples
+/// ```compile_fail
+/// # use geologic::*;
+/// #
+/// struct ScreenSpace;
+/// struct WorldSpace;
+///
+/// let screen: Point2D<f32, ScreenSpace> = Point2D::new(10.0, 10.0);
+/// let world: Offset2D<f32, WorldSpace> = Offset2D::new(2.0, 2.0);
+///
+/// // This does not compile, as the units are different
+/// let moved = screen + world;
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownUnit;
+
+/// A scaling factor for converting values from the `Src` unit to the `Dst` unit.
+///
+/// Multiplying a value in `Src` by the scale results in a value in `Dst`,
+/// and dividing a value in `Dst` by the scale results in a value in `Src`.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// struct LogicalPixels;
+/// struct DevicePixels;
+///
+/// let dpi: Scale<f32, LogicalPixels, DevicePixels> = Scale::new(2.0);
+///
+/// let logical: Point2D<f32, LogicalPixels> = Point2D::new(10.0, 20.0);
+/// let device = logical * dpi;
+///
+/// assert_eq!(device, Point2D::new(20.0, 40.0));
+/// assert_eq!(device / dpi, logical);
+/// ```
+pub struct Scale<T, Src, Dst> {
+    pub factor: T,
+
+    _unit: PhantomData<(Src, Dst)>,
+}
+
+impl<T, Src, Dst> Scale<T, Src, Dst> {
+    /// Creates a new [Scale] with the given `factor`.
+    pub fn new(factor: T) -> Self {
+        Self {
+            factor,
+            _unit: PhantomData,
+        }
+    }
+
+    /// Returns the scaling factor.
+    pub fn get(self) -> T {
+        self.factor
+    }
+
+    /// Returns a scale with a factor of `1`, which does not change values.
+    pub fn identity() -> Self
+    where
+        T: One,
+    {
+        Self::new(T::one())
+    }
+
+    /// Returns the scale converting from `Dst` back to `Src`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// struct Meters;
+    /// struct Centimeters;
+    ///
+    /// let to_centimeters: Scale<f64, Meters, Centimeters> = Scale::new(100.0);
+    /// let to_meters = to_centimeters.inverse();
+    ///
+    /// assert_eq!(to_meters.get(), 0.01);
+    /// ```
+    pub fn inverse(self) -> Scale<T, Dst, Src>
+    where
+        T: One + Div<Output = T>,
+    {
+        Scale::new(T::one() / self.factor)
+    }
+
+    /// Returns a scale that converts from `Src` to `Dst`, and then with `other` from `Dst` to `Next`.
+    pub fn then<Next>(self, other: Scale<T, Dst, Next>) -> Scale<T, Src, Next>
+    where
+        T: Mul<Output = T>,
+    {
+        Scale::new(self.factor * other.factor)
+    }
+
+    /// Converts `size` from `Src` to `Dst`.
+    ///
+    /// Sizes cannot be multiplied by a scale like vectors and bounds can,
+    /// since multiplying a size already accepts anything that converts into one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// struct Tiles;
+    /// struct Pixels;
+    ///
+    /// let tile_size: Scale<u32, Tiles, Pixels> = Scale::new(16);
+    /// let map: Size2D<u32, Tiles> = Size2D::new(20, 10);
+    ///
+    /// assert_eq!(tile_size.transform_size(map), Size2D::new(320, 160));
+    /// ```
+    pub fn transform_size(self, size: Size2D<T, Src>) -> Size2D<T, Dst>
+    where
+        T: Copy + Mul<Output = T>,
+    {
+        Size2D::new(size.width * self.factor, size.height * self.factor)
+    }
+
+    /// Converts `size` from `Dst` back to `Src`.
+    pub fn untransform_size(self, size: Size2D<T, Dst>) -> Size2D<T, Src>
+    where
+        T: Copy + Div<Output = T>,
+    {
+        Size2D::new(size.width / self.factor, size.height / self.factor)
+    }
+}
+
+// These are implemented by hand, so that the unit markers
+// are not required to implement the traits as well.
+impl<T, Src, Dst> Clone for Scale<T, Src, Dst>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.factor.clone())
+    }
+}
+
+impl<T, Src, Dst> Copy for Scale<T, Src, Dst> where T: Copy {}
+
+impl<T, Src, Dst> PartialEq for Scale<T, Src, Dst>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.factor == other.factor
+    }
+}
+
+impl<T, Src, Dst> Debug for Scale<T, Src, Dst>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Scale").field(&self.factor).finish()
+    }
+}
+
+impl<T, const N: usize, K, Src, Dst> Mul<Scale<T, Src, Dst>> for VectorN<T, N, K, Src>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = VectorN<T, N, K, Dst>;
+
+    fn mul(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        self.map(|component| component * rhs.factor).cast_unit()
+    }
+}
+
+impl<T, const N: usize, K, Src, Dst> Div<Scale<T, Src, Dst>> for VectorN<T, N, K, Dst>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = VectorN<T, N, K, Src>;
+
+    fn div(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        self.map(|component| component / rhs.factor).cast_unit()
+    }
+}
+
+impl<T, Src, Dst> Mul<Scale<T, Src, Dst>> for Bounds2D<T, Src>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Bounds2D<T, Dst>;
+
+    fn mul(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        Bounds2D::from_position_and_size(self.position() * rhs, rhs.transform_size(self.size()))
+    }
+}
+
+impl<T, Src, Dst> Div<Scale<T, Src, Dst>> for Bounds2D<T, Dst>
+where
+    T: Copy + Div<Output = T>,
+{
+    type Output = Bounds2D<T, Src>;
+
+    fn div(self, rhs: Scale<T, Src, Dst>) -> Self::Output {
+        Bounds2D::from_position_and_size(self.position() / rhs, rhs.untransform_size(self.size()))
+    }
+}
@@ -1,17 +1,24 @@
 use std::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
     marker::PhantomData,
-    ops::{Add, Mul, Sub},
+    ops::{Add, Deref, DerefMut, Index, IndexMut, Mul, Neg, Sub},
 };
 
-use num_traits::{AsPrimitive, Signed};
+use num_traits::{AsPrimitive, Float, FloatConst, Signed, Zero};
+
+use crate::{Angle, Metric, UnknownUnit};
 
 /// A trait defining common helper methods
 /// to aid in the usage of a vector, or types with underlying vectors.
 pub trait Vector<T, ToVector> {
+    /// Vectors can have any number of components, so the sum starts from zero
+    /// and `T` has to implement [Zero], where it used to only need [Add].
     fn dot(&self, rhs: ToVector) -> T
     where
-        T: Copy + Mul<Output = T> + Add<Output = T>;
+        T: Copy + Zero + Mul<Output = T>;
 
+    #[deprecated(note = "use the inherent `cross` of `Vector2D` or `Vector3D` instead")]
     fn cross(&self, rhs: ToVector) -> T
     where
         T: Copy + Mul<Output = T> + Sub<Output = T>;
@@ -21,46 +28,61 @@ pub trait Vector<T, ToVector> {
         T: Signed + Copy + Add<Output = T> + Sub<Output = T>;
 }
 
-/// A generic vector with an X and Y component.
-#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
-pub struct Vector2D<T, Kind> {
-    pub x: T,
-    pub y: T,
+/// A generic vector with `N` components.
+///
+/// This is the core that all vector types are built upon,
+/// you will usually use one of the specialized aliases like
+/// [`Point2D`](crate::Point2D) or [`Offset3D`](crate::Offset3D) instead.
+///
+/// Vectors with two, three or four components dereference
+/// to their named components, so `x`, `y`, `z` and `w` can be used directly.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let mut point = point!(10, 20);
+/// point.x += 5;
+///
+/// assert_eq!(point.x, 15);
+/// assert_eq!(point[1], 20);
+/// ```
+pub struct VectorN<T, const N: usize, Kind, Unit = UnknownUnit> {
+    components: [T; N],
 
     _kind: PhantomData<Kind>,
+    _unit: PhantomData<Unit>,
 }
 
-impl<T, K> Vector2D<T, K> {
-    /// Returns a new [Vector2D] with `x` and `y` components.
-    ///
-    /// In most cases you should not call this directly, but rather use
-    /// the macros to get the specialized variants.
+/// A generic vector with an X and Y component.
+pub type Vector2D<T, Kind, Unit = UnknownUnit> = VectorN<T, 2, Kind, Unit>;
+
+/// A generic vector with an X, Y and Z component.
+pub type Vector3D<T, Kind, Unit = UnknownUnit> = VectorN<T, 3, Kind, Unit>;
+
+/// A generic vector with an X, Y, Z and W component.
+pub type Vector4D<T, Kind, Unit = UnknownUnit> = VectorN<T, 4, Kind, Unit>;
+
+impl<T, const N: usize, K, U> VectorN<T, N, K, U> {
+    /// Returns a new [VectorN] from an array of components.
     ///
     /// # Examples
     /// ```
     /// # use geologic::*;
     /// #
-    /// // Avoid doing this
-    /// let point: Vector2D<_, Point> = Vector2D::new(20, 40);
-    ///
-    /// // This is better, but not great
-    /// let point: Point2D<_> = Vector2D::new(20, 40);
+    /// let point = Point3D::from_array([1, 2, 3]);
     ///
-    /// // This is acceptable, but...
-    /// let point = Point2D::new(20, 40);
-    ///
-    /// // ...this is the preferred way
-    /// let point = point!(20, 40);
+    /// assert_eq!(point, point!(1, 2, 3));
     /// ```
-    pub fn new(x: T, y: T) -> Self {
+    pub fn from_array(components: [T; N]) -> Self {
         Self {
-            x,
-            y,
+            components,
             _kind: PhantomData,
+            _unit: PhantomData,
         }
     }
 
-    /// Returns a new [Vector2D] where both components are set to `value`.
+    /// Returns a new [VectorN] where all components are set to `value`.
     ///
     /// Prefer using the splat syntax with the specialized macros instead of
     /// calling this directly.
@@ -71,103 +93,511 @@ impl<T, K> Vector2D<T, K> {
     /// #
     /// // This is acceptable, but...
     /// let offset = Offset2D::splat(5);
+    /// assert_eq!(offset, offset!(5, 5));
     ///
     /// // ...this is the preferred way
     /// let offset = offset!(5; 2);
-    ///
     /// assert_eq!(offset, offset!(5, 5));
     /// ```
     pub fn splat(value: T) -> Self
     where
         T: Copy,
     {
-        Self {
-            x: value,
-            y: value,
-            _kind: PhantomData,
-        }
+        Self::from_array([value; N])
     }
 
-    /// Casts `self` into a new [Vector2D]
+    /// Casts `self` into a new [VectorN]
     /// where components are the (usually inferred) input type.
-    pub fn cast<C>(self) -> Vector2D<C, K>
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = point!(2.5, 4.9, 1.0);
+    /// let b: Point3D<u32> = a.cast();
+    ///
+    /// assert_eq!(b, point!(2, 4, 1));
+    /// ```
+    pub fn cast<C>(self) -> VectorN<C, N, K, U>
     where
         C: Copy + 'static,
         T: AsPrimitive<C>,
     {
-        Vector2D {
-            x: self.x.as_(),
-            y: self.y.as_(),
-            _kind: PhantomData,
-        }
+        self.map(|component| component.as_())
+    }
+
+    /// Reinterprets `self` as a vector in another unit, keeping the components as they are.
+    ///
+    /// This is an escape hatch for when the unit of a vector is known
+    /// by other means, like when receiving untyped values from elsewhere.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// struct ScreenSpace;
+    ///
+    /// let untyped = point!(20.0, 10.0);
+    /// let screen: Point2D<f32, ScreenSpace> = untyped.cast_unit();
+    ///
+    /// assert_eq!(screen.x, 20.0);
+    /// ```
+    pub fn cast_unit<V>(self) -> VectorN<T, N, K, V> {
+        VectorN::from_array(self.components)
+    }
+
+    /// Returns a new [VectorN] with `f` applied to every component.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let offset = offset!(-2, 4).map(|c| c * 2);
+    ///
+    /// assert_eq!(offset, offset!(-4, 8));
+    /// ```
+    pub fn map<C, F>(self, f: F) -> VectorN<C, N, K, U>
+    where
+        F: FnMut(T) -> C,
+    {
+        VectorN::from_array(self.components.map(f))
+    }
+
+    /// Returns a reference to the underlying components.
+    pub fn components(&self) -> &[T; N] {
+        &self.components
+    }
+
+    /// Consumes `self`, returning the underlying components.
+    pub fn to_array(self) -> [T; N] {
+        self.components
+    }
+
+    #[doc(hidden)]
+    pub(crate) fn zip_map<R, RK, RU, C, F>(
+        self,
+        rhs: VectorN<R, N, RK, RU>,
+        mut f: F,
+    ) -> VectorN<C, N, K, U>
+    where
+        F: FnMut(T, R) -> C,
+    {
+        let mut rhs = rhs.components.into_iter();
+
+        // Both arrays have exactly N elements, so this never runs out.
+        self.map(|a| f(a, rhs.next().unwrap()))
     }
 
     #[doc(hidden)]
-    pub(crate) fn add_components<V: ToVector2D<T, U>, U>(self, rhs: V) -> Self
+    pub(crate) fn add_components<V: ToVectorN<T, N, RK, U>, RK>(self, rhs: V) -> Self
     where
         T: Add<Output = T>,
     {
-        let rhs = rhs.to_vector();
-        Self::new(self.x + rhs.x, self.y + rhs.y)
+        self.zip_map(rhs.to_vector(), |a, b| a + b)
     }
 
     #[doc(hidden)]
-    pub(crate) fn sub_components<V: ToVector2D<T, U>, U>(self, rhs: V) -> Self
+    pub(crate) fn sub_components<V: ToVectorN<T, N, RK, U>, RK>(self, rhs: V) -> Self
     where
         T: Sub<Output = T>,
+    {
+        self.zip_map(rhs.to_vector(), |a, b| a - b)
+    }
+}
+
+impl<T, K, U> Vector2D<T, K, U> {
+    /// Returns a new [Vector2D] with `x` and `y` components.
+    ///
+    /// In most cases you should not call this directly, but rather use
+    /// the macros to get the specialized variants.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// // Avoid doing this
+    /// let point: Vector2D<_, Point> = Vector2D::new(20, 40);
+    ///
+    /// // This is better, but not great
+    /// let point: Point2D<_> = Vector2D::new(20, 40);
+    ///
+    /// // This is acceptable, but...
+    /// let point = Point2D::new(20, 40);
+    ///
+    /// // ...this is the preferred way
+    /// assert_eq!(point, point!(20, 40));
+    /// ```
+    pub fn new(x: T, y: T) -> Self {
+        Self::from_array([x, y])
+    }
+
+    /// Returns the normal of the cross product between `self` and `rhs`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = offset!(0, 0);
+    /// let b = offset!(20, 20);
+    ///
+    /// assert_eq!(a.cross(b), 0);
+    /// ```
+    pub fn cross<V: ToVector2D<T, K, U>>(&self, rhs: V) -> T
+    where
+        T: Copy + Mul<Output = T> + Sub<Output = T>,
+    {
+        let rhs = rhs.to_vector();
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Returns a vector perpendicular to `self`, rotated
+    /// a quarter turn counter-clockwise.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let velocity = offset!(3, 1);
+    ///
+    /// assert_eq!(velocity.perpendicular(), offset!(-1, 3));
+    /// assert_eq!(velocity.perpendicular().dot(velocity), 0);
+    /// ```
+    pub fn perpendicular(&self) -> Self
+    where
+        T: Copy + Neg<Output = T>,
+    {
+        Self::new(-self.y, self.x)
+    }
+}
+
+impl<T, K, U> Vector3D<T, K, U> {
+    /// Returns a new [Vector3D] with `x`, `y` and `z` components.
+    ///
+    /// In most cases you should not call this directly, but rather use
+    /// the macros to get the specialized variants.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// // This is acceptable, but...
+    /// let point = Point3D::new(20, 40, 60);
+    ///
+    /// // ...this is the preferred way
+    /// assert_eq!(point, point!(20, 40, 60));
+    /// ```
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self::from_array([x, y, z])
+    }
+
+    /// Returns the cross product of `self` and `rhs`,
+    /// which is a vector perpendicular to both.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let x = offset!(1, 0, 0);
+    /// let y = offset!(0, 1, 0);
+    ///
+    /// assert_eq!(x.cross(y), offset!(0, 0, 1));
+    /// ```
+    pub fn cross<V: ToVector3D<T, K, U>>(&self, rhs: V) -> Self
+    where
+        T: Copy + Mul<Output = T> + Sub<Output = T>,
     {
         let rhs = rhs.to_vector();
-        Self::new(self.x - rhs.x, self.y - rhs.y)
+
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+}
+
+impl<T, K, U> Vector4D<T, K, U> {
+    /// Returns a new [Vector4D] with `x`, `y`, `z` and `w` components.
+    pub fn new(x: T, y: T, z: T, w: T) -> Self {
+        Self::from_array([x, y, z, w])
     }
 }
 
-impl<T, K, ToVector> Vector<T, ToVector> for Vector2D<T, K>
+impl<T, const N: usize, K, U> VectorN<T, N, K, U>
 where
-    ToVector: ToVector2D<T, K>,
+    T: Copy + Zero + Mul<Output = T>,
 {
-    /// Returns the dot product of `self` and `rhs`.
+    /// Returns the squared euclidean length of `self`.
+    ///
+    /// This avoids the square root needed by [`length()`](crate::VectorN::length),
+    /// so it is cheaper and works for integer components too.
     ///
     /// # Examples
     /// ```
     /// # use geologic::*;
     /// #
-    /// let a = offset!(5, 10);
-    /// let b = offset!(10, 5);
+    /// assert_eq!(offset!(3, 4).length_squared(), 25);
+    /// ```
+    pub fn length_squared(&self) -> T {
+        self.dot(*self)
+    }
+}
+
+impl<T, const N: usize, K, U> VectorN<T, N, K, U>
+where
+    T: Float,
+{
+    /// Returns the euclidean length of `self`.
     ///
-    /// assert_eq!(a.dot(b), 100);
+    /// # Examples
     /// ```
-    fn dot(&self, rhs: ToVector) -> T
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(offset!(3.0, 4.0).length(), 5.0);
+    /// ```
+    pub fn length(&self) -> T {
+        self.length_squared().sqrt()
+    }
+
+    /// Returns a vector with the same direction as `self`, but with a length of `1`.
+    ///
+    /// If `self` has a length of zero, the resulting components will be `NaN`.
+    /// Use [`try_normalize()`](crate::VectorN::try_normalize) if that can happen.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(offset!(0.0, 8.0).normalize(), offset!(0.0, 1.0));
+    /// ```
+    pub fn normalize(&self) -> Self {
+        let length = self.length();
+        self.map(|component| component / length)
+    }
+
+    /// Returns a vector with the same direction as `self`, but with a length of `1`,
+    /// or [`None`] if `self` has a length of zero or is not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// assert_eq!(offset!(3.0, 4.0).try_normalize(), Some(offset!(0.6, 0.8)));
+    /// assert_eq!(offset!(0.0, 0.0).try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Self> {
+        let length = self.length();
+
+        if length.is_zero() || !length.is_finite() {
+            return None;
+        }
+
+        Some(self.map(|component| component / length))
+    }
+
+    /// Returns the euclidean distance between `self` and `rhs`.
+    ///
+    /// Unlike [`Vector::distance()`](crate::Vector::distance),
+    /// this is the straight-line distance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = point!(1.0, 1.0);
+    /// let b = point!(4.0, 5.0);
+    ///
+    /// assert_eq!(a.euclidean_distance(b), 5.0);
+    /// ```
+    pub fn euclidean_distance<V: ToVectorN<T, N, K, U>>(&self, rhs: V) -> T {
+        let difference: Self = self.zip_map(rhs.to_vector(), |a, b| b - a);
+        difference.length()
+    }
+
+    /// Returns the projection of `self` onto `rhs`,
+    /// which is the part of `self` pointing in the direction of `rhs`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let velocity = offset!(3.0, 4.0);
+    /// let floor = offset!(10.0, 0.0);
+    ///
+    /// assert_eq!(velocity.project_onto(floor), offset!(3.0, 0.0));
+    /// ```
+    pub fn project_onto<V: ToVectorN<T, N, K, U>>(&self, rhs: V) -> Self {
+        let rhs = rhs.to_vector();
+        let scale = self.dot(rhs.components) / rhs.length_squared();
+
+        rhs.map(|component| component * scale)
+    }
+
+    /// Returns the rejection of `self` from `rhs`,
+    /// which is the part of `self` perpendicular to `rhs`.
+    ///
+    /// This is the opposite of [`project_onto()`](crate::VectorN::project_onto),
+    /// and the two always add up to `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let velocity = offset!(3.0, 4.0);
+    /// let floor = offset!(10.0, 0.0);
+    ///
+    /// assert_eq!(velocity.reject_from(floor), offset!(0.0, 4.0));
+    /// ```
+    pub fn reject_from<V: ToVectorN<T, N, K, U>>(&self, rhs: V) -> Self {
+        let projection = self.project_onto(rhs);
+        self.zip_map(projection, |a, b| a - b)
+    }
+
+    /// Returns `self` reflected about a surface with the given `normal`.
+    ///
+    /// The normal is expected to be normalized.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let velocity = offset!(2.0, -3.0);
+    /// let ground = offset!(0.0, 1.0);
+    ///
+    /// assert_eq!(velocity.reflect(ground), offset!(2.0, 3.0));
+    /// ```
+    pub fn reflect<V: ToVectorN<T, N, K, U>>(&self, normal: V) -> Self {
+        let normal = normal.to_vector();
+        let scale = self.dot(normal.components) * (T::one() + T::one());
+
+        self.zip_map(normal, |a, b| a - b * scale)
+    }
+}
+
+impl<T, const N: usize, K, U> VectorN<T, N, K, U>
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    /// Returns the distance between `self` and `rhs`, as measured by `metric`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = point!(2u32, 8);
+    /// let b = point!(5u32, 4);
+    ///
+    /// assert_eq!(a.distance_with(Manhattan, b), 7);
+    /// assert_eq!(a.distance_with(Chebyshev, b), 4);
+    /// assert_eq!(a.distance_with(SquaredEuclidean, b), 25);
+    /// ```
+    pub fn distance_with<M, V>(&self, metric: M, rhs: V) -> T
     where
-        T: Copy + Mul<Output = T> + Add<Output = T>,
+        M: Metric<T>,
+        V: ToVectorN<T, N, K, U>,
     {
-        let rhs = rhs.to_vector();
+        let deltas = self.zip_map(rhs.to_vector(), |a, b| if a > b { a - b } else { b - a });
+        metric.measure(&deltas.components)
+    }
+}
 
-        let a = self.x * rhs.x;
-        let b = self.y * rhs.y;
+impl<T, K, U> Vector2D<T, K, U>
+where
+    T: Float + FloatConst,
+{
+    /// Returns the angle of `self`, measured counter-clockwise from the positive X axis.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let heading = offset!(0.0, 2.0).angle();
+    ///
+    /// assert_eq!(heading, Angle::degrees(90.0));
+    /// ```
+    pub fn angle(&self) -> Angle<T> {
+        Angle::radians(self.y.atan2(self.x))
+    }
 
-        a + b
+    /// Returns the signed angle needed to rotate `self` onto the direction of `rhs`,
+    /// which is in the range `[-π, π]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = offset!(1.0, 0.0);
+    /// let b = offset!(0.0, -1.0);
+    ///
+    /// assert_eq!(a.angle_to(b), Angle::degrees(-90.0));
+    /// ```
+    pub fn angle_to<V: ToVector2D<T, K, U>>(&self, rhs: V) -> Angle<T> {
+        let rhs = rhs.to_vector();
+        Angle::radians(self.cross(rhs).atan2(self.dot(rhs)))
     }
 
-    /// Returns the normal of the cross product between `self` and `rhs`.
+    /// Returns `self` rotated counter-clockwise by `angle` around the origin.
     ///
     /// # Examples
     /// ```
     /// # use geologic::*;
     /// #
-    /// let a = offset!(0, 0);
-    /// let b = offset!(20, 20);
+    /// let rotated = offset!(2.0, 0.0).rotate(Angle::degrees(90.0));
     ///
-    /// assert_eq!(a.cross(b), 0);
+    /// assert!(rotated.euclidean_distance((0.0, 2.0)) < 1e-10);
+    /// ```
+    pub fn rotate(&self, angle: Angle<T>) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+}
+
+impl<T, const N: usize, K, U, ToVector> Vector<T, ToVector> for VectorN<T, N, K, U>
+where
+    ToVector: ToVectorN<T, N, K, U>,
+{
+    /// Returns the dot product of `self` and `rhs`.
+    ///
+    /// # Examples
     /// ```
+    /// # use geologic::*;
+    /// #
+    /// let a = offset!(5, 10);
+    /// let b = offset!(10, 5);
+    ///
+    /// assert_eq!(a.dot(b), 100);
+    /// assert_eq!(offset!(1, 2, 3).dot((4, 5, 6)), 32);
+    ///
+    /// // A vector without components is zero
+    /// let empty = VectorN::<i32, 0, Offset>::from_array([]);
+    /// assert_eq!(empty.dot(empty), 0);
+    /// ```
+    fn dot(&self, rhs: ToVector) -> T
+    where
+        T: Copy + Zero + Mul<Output = T>,
+    {
+        self.zip_map(rhs.to_vector(), |a, b| a * b)
+            .components
+            .into_iter()
+            .fold(T::zero(), |a, b| a + b)
+    }
+
+    /// Returns the normal of the cross product between the first two components
+    /// of `self` and `rhs`.
+    ///
+    /// # Panics
+    /// Panics if the vectors have less than two components.
     fn cross(&self, rhs: ToVector) -> T
     where
         T: Copy + Mul<Output = T> + Sub<Output = T>,
     {
         let rhs = rhs.to_vector();
-        self.x * rhs.y - self.y * rhs.x
+        self[0] * rhs[1] - self[1] * rhs[0]
     }
 
-    /// Returns the absolute distance between `self` and `rhs`.
+    /// Returns the [Manhattan](crate::Manhattan) distance between `self` and `rhs`.
+    ///
+    /// Use [`distance_with()`](crate::VectorN::distance_with) to measure with another [Metric].
     ///
     /// # Examples
     /// ```
@@ -177,69 +607,235 @@ where
     /// let b = offset!(0, 0);
     ///
     /// assert_eq!(a.distance(b), 20);
+    /// assert_eq!(point!(10, 10, 10).distance((0, 0, 0)), 30);
+    ///
+    /// let empty = VectorN::<i32, 0, Point>::from_array([]);
+    /// assert_eq!(empty.distance(empty), 0);
     /// ```
     fn distance(&self, rhs: ToVector) -> T
     where
         T: Signed + Copy + Add<Output = T> + Sub<Output = T>,
     {
-        let rhs = rhs.to_vector();
-        (rhs.x - self.x).abs() + (rhs.y - self.y).abs()
+        self.zip_map(rhs.to_vector(), |a, b| (b - a).abs())
+            .components
+            .into_iter()
+            .fold(T::zero(), |a, b| a + b)
     }
 }
 
-/// A helper trait to aid with the ergonomics of using a [`Vector2D`].
-pub trait ToVector2D<T, K> {
-    /// Converts this type into a [`Vector2D`].
-    fn to_vector(self) -> Vector2D<T, K>;
+impl<T, const N: usize, K, U> Default for VectorN<T, N, K, U>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::from_array(std::array::from_fn(|_| T::default()))
+    }
 }
 
-/// Makes it so that [`Vector2D`] itself can be used for interfaces expecting it.
-impl<T, K> ToVector2D<T, K> for Vector2D<T, K> {
-    fn to_vector(self) -> Vector2D<T, K> {
-        self
+// These are implemented by hand, so that the `Kind` marker
+// is not required to implement the traits as well.
+impl<T, const N: usize, K, U> Clone for VectorN<T, N, K, U>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::from_array(self.components.clone())
     }
 }
 
-/// Makes it so a tuple can be used for interfaces expecting [`Vector2D`].
-impl<T, K> ToVector2D<T, K> for (T, T) {
-    fn to_vector(self) -> Vector2D<T, K> {
-        Vector2D::new(self.0, self.1)
+impl<T, const N: usize, K, U> Copy for VectorN<T, N, K, U> where T: Copy {}
+
+impl<T, const N: usize, K, U> PartialEq for VectorN<T, N, K, U>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.components == other.components
     }
 }
 
-/// Makes it so an array can be used for interfaces expecting [`Vector2D`].
-impl<T, K> ToVector2D<T, K> for [T; 2]
+impl<T, const N: usize, K, U> Eq for VectorN<T, N, K, U> where T: Eq {}
+
+impl<T, const N: usize, K, U> Hash for VectorN<T, N, K, U>
 where
-    T: Copy,
+    T: Hash,
 {
-    fn to_vector(self) -> Vector2D<T, K> {
-        Vector2D::new(self[0], self[1])
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.components.hash(state);
     }
 }
 
-impl<T, K> From<Vector2D<T, K>> for (T, T) {
-    fn from(vector: Vector2D<T, K>) -> Self {
-        (vector.x, vector.y)
+impl<T, const N: usize, K, U> Debug for VectorN<T, N, K, U>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match N {
+            2 => "Vector2D",
+            3 => "Vector3D",
+            4 => "Vector4D",
+            _ => "VectorN",
+        };
+
+        let mut tuple = f.debug_tuple(name);
+
+        for component in &self.components {
+            tuple.field(component);
+        }
+
+        tuple.finish()
     }
 }
 
-impl<T, K> From<(T, T)> for Vector2D<T, K> {
-    fn from(tuple: (T, T)) -> Self {
-        Vector2D::new(tuple.0, tuple.1)
+impl<T, const N: usize, K, U> Index<usize> for VectorN<T, N, K, U> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.components[index]
     }
 }
 
-impl<T, K> From<[T; 2]> for Vector2D<T, K>
-where
-    T: Copy,
-{
-    fn from(arr: [T; 2]) -> Self {
-        Vector2D::new(arr[0], arr[1])
+impl<T, const N: usize, K, U> IndexMut<usize> for VectorN<T, N, K, U> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.components[index]
+    }
+}
+
+/// Named components of a [Vector2D].
+#[repr(C)]
+#[derive(Debug)]
+pub struct XY<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// Named components of a [Vector3D].
+#[repr(C)]
+#[derive(Debug)]
+pub struct XYZ<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// Named components of a [Vector4D].
+#[repr(C)]
+#[derive(Debug)]
+pub struct XYZW<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+    pub w: T,
+}
+
+/// Fails to compile when used with two types that differ in size or alignment.
+struct SameLayout<A, B>(PhantomData<(A, B)>);
+
+impl<A, B> SameLayout<A, B> {
+    const CHECK: () = assert!(
+        std::mem::size_of::<A>() == std::mem::size_of::<B>()
+            && std::mem::align_of::<A>() == std::mem::align_of::<B>(),
+        "Named components must have the same layout as the array"
+    );
+}
+
+macro_rules! impl_named_components {
+    ($n:literal, $named:ident) => {
+        impl<T, K, U> Deref for VectorN<T, $n, K, U> {
+            type Target = $named<T>;
+
+            fn deref(&self) -> &Self::Target {
+                let () = SameLayout::<$named<T>, [T; $n]>::CHECK;
+
+                // SAFETY: The named components are `repr(C)` with exactly `N` fields of type `T`,
+                // so they have the same layout as `[T; N]`, which is checked above when compiling.
+                // The pointer comes from a reference, so it is valid and aligned for the lifetime.
+                unsafe { &*(self.components.as_ptr() as *const $named<T>) }
+            }
+        }
+
+        impl<T, K, U> DerefMut for VectorN<T, $n, K, U> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                let () = SameLayout::<$named<T>, [T; $n]>::CHECK;
+
+                // SAFETY: See the `Deref` implementation above. The mutable borrow
+                // of the components is exclusive, so the named components are too.
+                unsafe { &mut *(self.components.as_mut_ptr() as *mut $named<T>) }
+            }
+        }
+    };
+}
+
+impl_named_components!(2, XY);
+impl_named_components!(3, XYZ);
+impl_named_components!(4, XYZW);
+
+/// A helper trait to aid with the ergonomics of using a [`VectorN`].
+pub trait ToVectorN<T, const N: usize, K, U = UnknownUnit> {
+    /// Converts this type into a [`VectorN`].
+    fn to_vector(self) -> VectorN<T, N, K, U>;
+}
+
+/// Trait alias for [ToVectorN] with two components.
+pub trait ToVector2D<T, K, U = UnknownUnit>: ToVectorN<T, 2, K, U> {}
+impl<T, K, U, V: ToVectorN<T, 2, K, U>> ToVector2D<T, K, U> for V {}
+
+/// Trait alias for [ToVectorN] with three components.
+pub trait ToVector3D<T, K, U = UnknownUnit>: ToVectorN<T, 3, K, U> {}
+impl<T, K, U, V: ToVectorN<T, 3, K, U>> ToVector3D<T, K, U> for V {}
+
+/// Makes it so that [`VectorN`] itself can be used for interfaces expecting it.
+impl<T, const N: usize, K, U> ToVectorN<T, N, K, U> for VectorN<T, N, K, U> {
+    fn to_vector(self) -> VectorN<T, N, K, U> {
+        self
+    }
+}
+
+/// Makes it so an array can be used for interfaces expecting [`VectorN`].
+impl<T, const N: usize, K, U> ToVectorN<T, N, K, U> for [T; N] {
+    fn to_vector(self) -> VectorN<T, N, K, U> {
+        VectorN::from_array(self)
     }
 }
 
-impl<T, K> From<Vector2D<T, K>> for [T; 2] {
-    fn from(vector: Vector2D<T, K>) -> Self {
-        [vector.x, vector.y]
+impl<T, const N: usize, K, U> From<[T; N]> for VectorN<T, N, K, U> {
+    fn from(arr: [T; N]) -> Self {
+        VectorN::from_array(arr)
     }
 }
+
+impl<T, const N: usize, K, U> From<VectorN<T, N, K, U>> for [T; N] {
+    fn from(vector: VectorN<T, N, K, U>) -> Self {
+        vector.components
+    }
+}
+
+macro_rules! impl_tuple_conversions {
+    (@component $x:ident) => { T };
+    ($n:literal, $($x:ident),*) => {
+        /// Makes it so a tuple can be used for interfaces expecting [`VectorN`].
+        impl<T, K, U> ToVectorN<T, $n, K, U> for ($(impl_tuple_conversions!(@component $x),)*) {
+            fn to_vector(self) -> VectorN<T, $n, K, U> {
+                let ($($x,)*) = self;
+                VectorN::from_array([$($x),*])
+            }
+        }
+
+        impl<T, K, U> From<($(impl_tuple_conversions!(@component $x),)*)> for VectorN<T, $n, K, U> {
+            fn from(tuple: ($(impl_tuple_conversions!(@component $x),)*)) -> Self {
+                tuple.to_vector()
+            }
+        }
+
+        impl<T, K, U> From<VectorN<T, $n, K, U>> for ($(impl_tuple_conversions!(@component $x),)*) {
+            fn from(vector: VectorN<T, $n, K, U>) -> Self {
+                let [$($x),*] = vector.components;
+                ($($x,)*)
+            }
+        }
+    };
+}
+
+impl_tuple_conversions!(2, x, y);
+impl_tuple_conversions!(3, x, y, z);
+impl_tuple_conversions!(4, x, y, z, w);
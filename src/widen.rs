@@ -0,0 +1,57 @@
+use num_traits::Num;
+
+/// A number that can be converted to a wider type, so that
+/// the product of two of them cannot overflow.
+///
+/// Integers are widened to the integer type of twice their size. [i128] and [u128]
+/// have no wider type, so they are not supported. Floats do not overflow,
+/// so they are kept as they are.
+///
+/// # Examples
+/// ```
+/// # use geologic::*;
+/// #
+/// let side = 70000u32;
+///
+/// assert_eq!(side.widen() * side.widen(), 4_900_000_000u64);
+/// assert_eq!(u32::narrow(side.widen() * 2 / 2), side);
+/// ```
+pub trait Widen: Copy {
+    /// The wider type.
+    type Wide: Num + Copy + PartialOrd;
+
+    /// Returns `self` as the wider type.
+    fn widen(self) -> Self::Wide;
+
+    /// Returns `wide` as the original type, which it is expected to fit in.
+    fn narrow(wide: Self::Wide) -> Self;
+}
+
+macro_rules! impl_widen {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl Widen for $t {
+                type Wide = $wide;
+
+                fn widen(self) -> Self::Wide {
+                    self as $wide
+                }
+
+                fn narrow(wide: Self::Wide) -> Self {
+                    wide as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(
+    i8 => i16, i16 => i32, i32 => i64, i64 => i128, isize => i128,
+    u8 => u16, u16 => u32, u32 => u64, u64 => u128, usize => u128,
+    f32 => f32, f64 => f64
+);
+
+/// Returns the product of `a` and `b` as the wider type.
+pub(crate) fn wide_mul<T: Widen>(a: T, b: T) -> T::Wide {
+    a.widen() * b.widen()
+}